@@ -52,13 +52,17 @@ pub fn create_full<C, P, B>(
 where
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
+    C: sc_client_api::BlockchainEvents<Block> + sc_client_api::StorageProvider<Block, B>,
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: BlockBuilder<Block>,
     C::Api: subtensor_custom_rpc_runtime_api::DelegateInfoRuntimeApi<Block>,
+    C::Api: subtensor_custom_rpc_runtime_api::DelegateReturnRuntimeApi<Block>,
     C::Api: subtensor_custom_rpc_runtime_api::NeuronInfoRuntimeApi<Block>,
     C::Api: subtensor_custom_rpc_runtime_api::SubnetInfoRuntimeApi<Block>,
+    C::Api: subtensor_custom_rpc_runtime_api::StakeInfoRuntimeApi<Block>,
+    C::Api: subtensor_custom_rpc_runtime_api::EpochInfoRuntimeApi<Block>,
     C::Api: subtensor_custom_rpc_runtime_api::SubnetRegistrationRuntimeApi<Block>,
     B: sc_client_api::Backend<Block> + Send + Sync + 'static,
     P: TransactionPool + 'static,
@@ -66,7 +70,10 @@ where
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
-    use subtensor_custom_rpc::{SubtensorCustom, SubtensorCustomApiServer};
+    use subtensor_custom_rpc::{
+        subscriptions::{SubtensorSubscriptionApiServer, SubtensorSubscriptions},
+        SubtensorCustom, SubtensorCustomApiServer, SubtensorJsonApiServer,
+    };
 
     let mut module = RpcModule::new(());
     let FullDeps {
@@ -78,10 +85,12 @@ where
     } = deps;
 
     // Custom RPC methods for Paratensor
-    module.merge(SubtensorCustom::new(client.clone()).into_rpc())?;
-
-    module.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
+    module.merge(SubtensorCustomApiServer::into_rpc(SubtensorCustom::new(
+        client.clone(),
+    )))?;
+    module.merge(SubtensorJsonApiServer::into_rpc(SubtensorCustom::new(
+        client.clone(),
+    )))?;
 
     let GrandpaDeps {
         shared_voter_state,
@@ -91,6 +100,15 @@ where
         finality_provider,
     } = grandpa;
 
+    // Subscriptions to subnet epochs and neuron changes, driven by block imports
+    module.merge(
+        SubtensorSubscriptions::<_, Block, B>::new(client.clone(), subscription_executor.clone())
+            .into_rpc(),
+    )?;
+
+    module.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
+    module.merge(TransactionPayment::new(client).into_rpc())?;
+
     module.merge(
         Grandpa::new(
             subscription_executor,
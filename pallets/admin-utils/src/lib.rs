@@ -69,6 +69,12 @@ pub mod pallet {
         MaxValidatorsLargerThanMaxUIds,
         /// The maximum number of subnet validators must be more than the current number of UIDs already in the subnet.
         MaxAllowedUIdsLessThanCurrentUIds,
+        /// The liquid alpha lower bound must not be greater than its upper bound.
+        AlphaLowGreaterThanAlphaHigh,
+        /// The liquid alpha bounds must lie within the bounds set by governance.
+        AlphaValuesOutOfBounds,
+        /// The number of epoch snapshots retained by a subnet must not exceed the maximum.
+        EpochSnapshotRetentionTooHigh,
     }
 
     /// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -996,6 +1002,280 @@ pub mod pallet {
             log::info!("ToggleSetWeightsCommitReveal( netuid: {:?} ) ", netuid);
             Ok(())
         }
+
+        /// The extrinsic sets the stake unbonding period for a subnet.
+        /// It is only callable by the root account.
+        /// The extrinsic will call the Subtensor pallet to set the unbonding period.
+        #[pallet::call_index(50)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_unbonding_period(
+            origin: OriginFor<T>,
+            netuid: u16,
+            unbonding_period: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            T::Subtensor::set_unbonding_period(netuid, unbonding_period);
+            log::info!(
+                "UnbondingPeriodSet( netuid: {:?}, unbonding_period: {:?} ) ",
+                netuid,
+                unbonding_period
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the weights density from which subnets run the dense epoch.
+        /// It is only callable by the root account.
+        /// The extrinsic will call the Subtensor pallet to set the dense epoch threshold.
+        #[pallet::call_index(51)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_dense_epoch_threshold(
+            origin: OriginFor<T>,
+            threshold: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_dense_epoch_threshold(threshold);
+            log::info!("DenseEpochThresholdSet( threshold: {:?} ) ", threshold);
+            Ok(())
+        }
+
+        /// The extrinsic enables/disables liquid alpha for a given subnet.
+        /// It is only callable by the root account or subnet owner.
+        /// The extrinsic will call the Subtensor pallet to set the value.
+        #[pallet::call_index(52)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_liquid_alpha_enabled(
+            origin: OriginFor<T>,
+            netuid: u16,
+            enabled: bool,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            T::Subtensor::set_liquid_alpha_enabled(netuid, enabled);
+            log::info!(
+                "LiquidAlphaEnabledSet( netuid: {:?}, enabled: {:?} ) ",
+                netuid,
+                enabled
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the liquid alpha bounds for a given subnet, within the bounds set
+        /// by governance.
+        /// It is only callable by the root account or subnet owner.
+        /// The extrinsic will call the Subtensor pallet to set the bounds.
+        #[pallet::call_index(53)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_alpha_values(
+            origin: OriginFor<T>,
+            netuid: u16,
+            alpha_low: u16,
+            alpha_high: u16,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                alpha_low <= alpha_high,
+                Error::<T>::AlphaLowGreaterThanAlphaHigh
+            );
+            let (min_alpha_low, max_alpha_high) = T::Subtensor::get_alpha_values_bounds();
+            ensure!(
+                alpha_low >= min_alpha_low && alpha_high <= max_alpha_high,
+                Error::<T>::AlphaValuesOutOfBounds
+            );
+
+            T::Subtensor::set_alpha_values(netuid, alpha_low, alpha_high);
+            log::info!(
+                "AlphaValuesSet( netuid: {:?}, alpha_low: {:?}, alpha_high: {:?} ) ",
+                netuid,
+                alpha_low,
+                alpha_high
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the steepness of the liquid alpha sigmoid for a given subnet.
+        /// It is only callable by the root account.
+        /// The extrinsic will call the Subtensor pallet to set the steepness.
+        #[pallet::call_index(54)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_alpha_sigmoid_steepness(
+            origin: OriginFor<T>,
+            netuid: u16,
+            steepness: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            T::Subtensor::set_alpha_sigmoid_steepness(netuid, steepness);
+            log::info!(
+                "AlphaSigmoidSteepnessSet( netuid: {:?}, steepness: {:?} ) ",
+                netuid,
+                steepness
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the maximum total share of the emission split of a subnet.
+        /// It is only callable by the root account.
+        /// The extrinsic will call the Subtensor pallet to set the maximum emission split share.
+        #[pallet::call_index(55)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_max_emission_split_share(
+            origin: OriginFor<T>,
+            max_share: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_max_emission_split_share(max_share);
+            log::info!("MaxEmissionSplitShareSet( max_share: {:?} ) ", max_share);
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of blocks a subnet is dissolving before it is removed.
+        /// It is only callable by the root account.
+        /// The extrinsic will call the Subtensor pallet to set the network dissolve period.
+        #[pallet::call_index(56)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_network_dissolve_period(
+            origin: OriginFor<T>,
+            dissolve_period: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            T::Subtensor::set_network_dissolve_period(dissolve_period);
+            log::info!(
+                "NetworkDissolvePeriodSet( dissolve_period: {:?} ) ",
+                dissolve_period
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the number of epoch snapshots retained by a subnet.
+        /// It is only callable by the root account.
+        /// The extrinsic will call the Subtensor pallet to set the epoch snapshot retention.
+        #[pallet::call_index(57)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_epoch_snapshot_retention(
+            origin: OriginFor<T>,
+            netuid: u16,
+            retention: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+            ensure!(
+                retention <= T::Subtensor::get_max_epoch_snapshot_retention(),
+                Error::<T>::EpochSnapshotRetentionTooHigh
+            );
+
+            T::Subtensor::set_epoch_snapshot_retention(netuid, retention);
+            log::info!(
+                "EpochSnapshotRetentionSet( netuid: {:?}, retention: {:?} ) ",
+                netuid,
+                retention
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets whether axons and prometheus endpoints of a subnet may be served
+        /// on private ip ranges.
+        /// It is only callable by the root account or subnet owner.
+        /// The extrinsic will call the Subtensor pallet to set the allow private ips flag.
+        #[pallet::call_index(58)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_allow_private_ips(
+            origin: OriginFor<T>,
+            netuid: u16,
+            allow_private_ips: bool,
+        ) -> DispatchResult {
+            T::Subtensor::ensure_subnet_owner_or_root(origin, netuid)?;
+
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            T::Subtensor::set_allow_private_ips(netuid, allow_private_ips);
+            log::info!(
+                "AllowPrivateIpsSet( netuid: {:?}, allow_private_ips: {:?} ) ",
+                netuid,
+                allow_private_ips
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the fraction of the registration burn refunded to a neuron that
+        /// deregisters from a subnet, normalized to u16::MAX.
+        /// It is only callable by the root account.
+        /// The extrinsic will call the Subtensor pallet to set the deregistration refund ratio.
+        #[pallet::call_index(59)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_deregistration_refund_ratio(
+            origin: OriginFor<T>,
+            netuid: u16,
+            ratio: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                T::Subtensor::if_subnet_exist(netuid),
+                Error::<T>::SubnetDoesNotExist
+            );
+
+            T::Subtensor::set_deregistration_refund_ratio(netuid, ratio);
+            log::info!(
+                "DeregistrationRefundRatioSet( netuid: {:?}, ratio: {:?} ) ",
+                netuid,
+                ratio
+            );
+            Ok(())
+        }
+
+        /// The extrinsic sets the governance bounds within which subnets set their liquid alpha bounds.
+        /// It is only callable by the root account.
+        /// The extrinsic will call the Subtensor pallet to set the liquid alpha bounds limits.
+        #[pallet::call_index(60)]
+        #[pallet::weight((0, DispatchClass::Operational, Pays::No))]
+        pub fn sudo_set_alpha_values_bounds(
+            origin: OriginFor<T>,
+            min_alpha_low: u16,
+            max_alpha_high: u16,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(
+                min_alpha_low <= max_alpha_high,
+                Error::<T>::AlphaLowGreaterThanAlphaHigh
+            );
+
+            T::Subtensor::set_alpha_values_bounds(min_alpha_low, max_alpha_high);
+            log::info!(
+                "AlphaValuesBoundsSet( min_alpha_low: {:?}, max_alpha_high: {:?} ) ",
+                min_alpha_low,
+                max_alpha_high
+            );
+            Ok(())
+        }
     }
 }
 
@@ -1091,4 +1371,17 @@ pub trait SubtensorInterface<AccountId, Balance, RuntimeOrigin> {
     fn set_target_stakes_per_interval(target_stakes_per_interval: u64);
     fn set_commit_reveal_weights_interval(netuid: u16, interval: u64);
     fn set_commit_reveal_weights_enabled(netuid: u16, enabled: bool);
+    fn set_unbonding_period(netuid: u16, unbonding_period: u64);
+    fn set_dense_epoch_threshold(threshold: u16);
+    fn set_liquid_alpha_enabled(netuid: u16, enabled: bool);
+    fn set_alpha_values(netuid: u16, alpha_low: u16, alpha_high: u16);
+    fn get_alpha_values_bounds() -> (u16, u16);
+    fn set_alpha_values_bounds(min_alpha_low: u16, max_alpha_high: u16);
+    fn set_alpha_sigmoid_steepness(netuid: u16, steepness: u16);
+    fn set_max_emission_split_share(max_share: u16);
+    fn set_network_dissolve_period(dissolve_period: u64);
+    fn get_max_epoch_snapshot_retention() -> u16;
+    fn set_epoch_snapshot_retention(netuid: u16, retention: u16);
+    fn set_allow_private_ips(netuid: u16, allow_private_ips: bool);
+    fn set_deregistration_refund_ratio(netuid: u16, ratio: u16);
 }
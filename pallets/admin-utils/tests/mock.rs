@@ -108,6 +108,17 @@ parameter_types! {
     pub const InitialSubnetLimit: u16 = 10; // Max 10 subnets.
     pub const InitialNetworkRateLimit: u64 = 0;
     pub const InitialTargetStakesPerInterval: u16 = 1;
+    pub const MaxUnbondingChunks: u32 = 32;
+    pub const MaxRebalanceTargets: u32 = 64;
+    pub const MaxPendingWeightCommits: u32 = 10;
+    pub const MaxTimelockedCiphertextLength: u32 = 1024;
+    pub const MaxTimelockedCommitsPerBlock: u32 = 16;
+    pub const ColdkeySwapAnnouncementDelay: u64 = 10;
+    pub const MaxEmissionLedgerEntries: u32 = 8;
+    pub const MaxEmissionSplitRecipients: u32 = 4;
+    pub const MaxEpochSnapshotRetention: u16 = 4;
+    pub const ExpectedBlockTime: u64 = 12_000;
+    pub const MaxServeAxonNetuids: u32 = 16;
 
 }
 
@@ -119,6 +130,8 @@ impl pallet_subtensor::Config for Test {
     type CouncilOrigin = EnsureNever<AccountId>;
     type SenateMembers = ();
     type TriumvirateInterface = ();
+    type OnHotkeySwapped = ();
+    type TimelockKeySource = ();
 
     type InitialMinAllowedWeights = InitialMinAllowedWeights;
     type InitialEmissionValue = InitialEmissionValue;
@@ -160,6 +173,18 @@ impl pallet_subtensor::Config for Test {
     type InitialSubnetLimit = InitialSubnetLimit;
     type InitialNetworkRateLimit = InitialNetworkRateLimit;
     type InitialTargetStakesPerInterval = InitialTargetStakesPerInterval;
+    type MaxUnbondingChunks = MaxUnbondingChunks;
+    type MaxRebalanceTargets = MaxRebalanceTargets;
+    type WeightInfo = ();
+    type MaxPendingWeightCommits = MaxPendingWeightCommits;
+    type MaxTimelockedCiphertextLength = MaxTimelockedCiphertextLength;
+    type MaxTimelockedCommitsPerBlock = MaxTimelockedCommitsPerBlock;
+    type ColdkeySwapAnnouncementDelay = ColdkeySwapAnnouncementDelay;
+    type MaxEmissionLedgerEntries = MaxEmissionLedgerEntries;
+    type MaxEmissionSplitRecipients = MaxEmissionSplitRecipients;
+    type MaxEpochSnapshotRetention = MaxEpochSnapshotRetention;
+    type ExpectedBlockTime = ExpectedBlockTime;
+    type MaxServeAxonNetuids = MaxServeAxonNetuids;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -462,6 +487,58 @@ impl pallet_admin_utils::SubtensorInterface<AccountId, Balance, RuntimeOrigin> f
     fn set_commit_reveal_weights_enabled(netuid: u16, enabled: bool) {
         SubtensorModule::set_commit_reveal_weights_enabled(netuid, enabled);
     }
+
+    fn set_unbonding_period(netuid: u16, unbonding_period: u64) {
+        SubtensorModule::set_unbonding_period(netuid, unbonding_period);
+    }
+
+    fn set_dense_epoch_threshold(threshold: u16) {
+        SubtensorModule::set_dense_epoch_threshold(threshold);
+    }
+
+    fn set_liquid_alpha_enabled(netuid: u16, enabled: bool) {
+        SubtensorModule::set_liquid_alpha_enabled(netuid, enabled);
+    }
+
+    fn set_alpha_values(netuid: u16, alpha_low: u16, alpha_high: u16) {
+        SubtensorModule::set_alpha_values(netuid, alpha_low, alpha_high);
+    }
+
+    fn get_alpha_values_bounds() -> (u16, u16) {
+        SubtensorModule::get_alpha_values_bounds()
+    }
+
+    fn set_alpha_values_bounds(min_alpha_low: u16, max_alpha_high: u16) {
+        SubtensorModule::set_alpha_values_bounds(min_alpha_low, max_alpha_high);
+    }
+
+    fn set_alpha_sigmoid_steepness(netuid: u16, steepness: u16) {
+        SubtensorModule::set_alpha_sigmoid_steepness(netuid, steepness);
+    }
+
+    fn set_max_emission_split_share(max_share: u16) {
+        SubtensorModule::set_max_emission_split_share(max_share);
+    }
+
+    fn set_network_dissolve_period(dissolve_period: u64) {
+        SubtensorModule::set_network_dissolve_period(dissolve_period);
+    }
+
+    fn get_max_epoch_snapshot_retention() -> u16 {
+        SubtensorModule::get_max_epoch_snapshot_retention()
+    }
+
+    fn set_epoch_snapshot_retention(netuid: u16, retention: u16) {
+        SubtensorModule::set_epoch_snapshot_retention(netuid, retention);
+    }
+
+    fn set_allow_private_ips(netuid: u16, allow_private_ips: bool) {
+        SubtensorModule::set_allow_private_ips(netuid, allow_private_ips);
+    }
+
+    fn set_deregistration_refund_ratio(netuid: u16, ratio: u16) {
+        SubtensorModule::set_deregistration_refund_ratio(netuid, ratio);
+    }
 }
 
 impl pallet_admin_utils::Config for Test {
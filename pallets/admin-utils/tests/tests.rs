@@ -1178,3 +1178,379 @@ fn test_sudo_set_target_stakes_per_interval() {
         assert_eq!(SubtensorModule::get_target_stakes_per_interval(), to_be_set);
     });
 }
+
+#[test]
+fn test_sudo_set_unbonding_period() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u64 = 7200;
+        add_network(netuid, 10);
+        let init_value: u64 = SubtensorModule::get_unbonding_period(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_unbonding_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_unbonding_period(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(SubtensorModule::get_unbonding_period(netuid), init_value);
+        assert_ok!(AdminUtils::sudo_set_unbonding_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_unbonding_period(netuid), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_dense_epoch_threshold() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = u16::MAX / 4;
+        let init_value: u16 = SubtensorModule::get_dense_epoch_threshold();
+        assert_eq!(
+            AdminUtils::sudo_set_dense_epoch_threshold(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_dense_epoch_threshold(), init_value);
+        assert_ok!(AdminUtils::sudo_set_dense_epoch_threshold(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_dense_epoch_threshold(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_liquid_alpha_enabled() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+
+        let to_be_set: bool = true;
+        let init_value: bool = SubtensorModule::get_liquid_alpha_enabled(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_liquid_alpha_enabled(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_liquid_alpha_enabled(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            SubtensorModule::get_liquid_alpha_enabled(netuid),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_liquid_alpha_enabled(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert!(init_value != to_be_set);
+        assert_eq!(SubtensorModule::get_liquid_alpha_enabled(netuid), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_alpha_values() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+
+        let (alpha_low, alpha_high): (u16, u16) = (1_000, 40_000);
+        let init_value: (u16, u16) = SubtensorModule::get_alpha_values(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_alpha_values(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                alpha_low,
+                alpha_high
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_alpha_values(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                alpha_high,
+                alpha_low
+            ),
+            Err(Error::<Test>::AlphaLowGreaterThanAlphaHigh.into())
+        );
+        assert_eq!(SubtensorModule::get_alpha_values(netuid), init_value);
+        assert_ok!(AdminUtils::sudo_set_alpha_values(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            alpha_low,
+            alpha_high
+        ));
+        assert_eq!(
+            SubtensorModule::get_alpha_values(netuid),
+            (alpha_low, alpha_high)
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_alpha_values_bounds() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+
+        let (min_alpha_low, max_alpha_high): (u16, u16) = (2_000, 30_000);
+        let init_value: (u16, u16) = SubtensorModule::get_alpha_values_bounds();
+        assert_eq!(
+            AdminUtils::sudo_set_alpha_values_bounds(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                min_alpha_low,
+                max_alpha_high
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_alpha_values_bounds(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                max_alpha_high,
+                min_alpha_low
+            ),
+            Err(Error::<Test>::AlphaLowGreaterThanAlphaHigh.into())
+        );
+        assert_eq!(SubtensorModule::get_alpha_values_bounds(), init_value);
+        assert_ok!(AdminUtils::sudo_set_alpha_values_bounds(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            min_alpha_low,
+            max_alpha_high
+        ));
+        assert_eq!(
+            SubtensorModule::get_alpha_values_bounds(),
+            (min_alpha_low, max_alpha_high)
+        );
+
+        // Subnet liquid alpha bounds outside the governance bounds are refused.
+        for (alpha_low, alpha_high) in [(1_000, 20_000), (3_000, 40_000)] {
+            assert_eq!(
+                AdminUtils::sudo_set_alpha_values(
+                    <<Test as Config>::RuntimeOrigin>::root(),
+                    netuid,
+                    alpha_low,
+                    alpha_high
+                ),
+                Err(Error::<Test>::AlphaValuesOutOfBounds.into())
+            );
+        }
+        assert_ok!(AdminUtils::sudo_set_alpha_values(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            min_alpha_low,
+            max_alpha_high
+        ));
+        assert_eq!(
+            SubtensorModule::get_alpha_values(netuid),
+            (min_alpha_low, max_alpha_high)
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_alpha_sigmoid_steepness() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+
+        let to_be_set: u16 = 25;
+        let init_value: u16 = SubtensorModule::get_alpha_sigmoid_steepness(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_alpha_sigmoid_steepness(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            SubtensorModule::get_alpha_sigmoid_steepness(netuid),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_alpha_sigmoid_steepness(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_alpha_sigmoid_steepness(netuid),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_max_emission_split_share() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u16 = u16::MAX / 4;
+        let init_value: u16 = SubtensorModule::get_max_emission_split_share();
+        assert_eq!(
+            AdminUtils::sudo_set_max_emission_split_share(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_max_emission_split_share(), init_value);
+        assert_ok!(AdminUtils::sudo_set_max_emission_split_share(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_max_emission_split_share(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_network_dissolve_period() {
+    new_test_ext().execute_with(|| {
+        let to_be_set: u64 = 100;
+        let init_value: u64 = SubtensorModule::get_network_dissolve_period();
+        assert_eq!(
+            AdminUtils::sudo_set_network_dissolve_period(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(SubtensorModule::get_network_dissolve_period(), init_value);
+        assert_ok!(AdminUtils::sudo_set_network_dissolve_period(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            to_be_set
+        ));
+        assert_eq!(SubtensorModule::get_network_dissolve_period(), to_be_set);
+    });
+}
+
+#[test]
+fn test_sudo_set_epoch_snapshot_retention() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u16 = 3;
+        add_network(netuid, 10);
+        let init_value: u16 = SubtensorModule::get_epoch_snapshot_retention(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_epoch_snapshot_retention(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_epoch_snapshot_retention(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid,
+                SubtensorModule::get_max_epoch_snapshot_retention() + 1
+            ),
+            Err(Error::<Test>::EpochSnapshotRetentionTooHigh.into())
+        );
+        assert_eq!(
+            SubtensorModule::get_epoch_snapshot_retention(netuid),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_epoch_snapshot_retention(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_epoch_snapshot_retention(netuid),
+            to_be_set
+        );
+    });
+}
+
+#[test]
+fn test_sudo_set_allow_private_ips() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 10);
+        assert!(!SubtensorModule::get_allow_private_ips(netuid));
+        assert_eq!(
+            AdminUtils::sudo_set_allow_private_ips(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                true
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_allow_private_ips(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                true
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert!(!SubtensorModule::get_allow_private_ips(netuid));
+        assert_ok!(AdminUtils::sudo_set_allow_private_ips(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            true
+        ));
+        assert!(SubtensorModule::get_allow_private_ips(netuid));
+    });
+}
+
+#[test]
+fn test_sudo_set_deregistration_refund_ratio() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let to_be_set: u16 = u16::MAX / 2;
+        add_network(netuid, 10);
+        let init_value: u16 = SubtensorModule::get_deregistration_refund_ratio(netuid);
+        assert_eq!(
+            AdminUtils::sudo_set_deregistration_refund_ratio(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+                netuid,
+                to_be_set
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            AdminUtils::sudo_set_deregistration_refund_ratio(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                netuid + 1,
+                to_be_set
+            ),
+            Err(Error::<Test>::SubnetDoesNotExist.into())
+        );
+        assert_eq!(
+            SubtensorModule::get_deregistration_refund_ratio(netuid),
+            init_value
+        );
+        assert_ok!(AdminUtils::sudo_set_deregistration_refund_ratio(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            netuid,
+            to_be_set
+        ));
+        assert_eq!(
+            SubtensorModule::get_deregistration_refund_ratio(netuid),
+            to_be_set
+        );
+    });
+}
@@ -172,6 +172,30 @@ pub mod pallet {
             Ok(())
         }
     }
+
+    impl<T: Config> Pallet<T> {
+        /// Moves the commitment of an account on a netuid to a new account.
+        /// The deposit is returned to the old account, the new account reserves
+        /// its own deposit the next time it sets the commitment.
+        pub fn swap_commitment(netuid: u16, old: &T::AccountId, new: &T::AccountId) -> Weight {
+            let mut weight = T::DbWeight::get().reads(2);
+
+            if let Some(mut id) = <CommitmentOf<T>>::take(netuid, old) {
+                let err_amount = T::Currency::unreserve(old, id.deposit);
+                debug_assert!(err_amount.is_zero());
+                id.deposit = Zero::zero();
+                <CommitmentOf<T>>::insert(netuid, new, id);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
+            }
+
+            if let Some(last_commit) = <LastCommitment<T>>::take(netuid, old) {
+                <LastCommitment<T>>::insert(netuid, new, last_commit);
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+
+            weight
+        }
+    }
 }
 
 // Interfaces to interact with other pallets
@@ -0,0 +1,345 @@
+//! JSON views of the SCALE-encoded info structs returned by the runtime APIs.
+//!
+//! Each view decodes from the same bytes as its runtime counterpart, field for field, and
+//! serializes accounts as SS58 addresses and axon and prometheus IPs as text. A field added
+//! to a runtime info struct has to be added here at the same position.
+
+use codec::Decode;
+use pallet_subtensor::{AxonAddress, AxonInfo, AxonInfoV2, PrometheusInfo};
+use serde::{Serialize, Serializer};
+use sp_core::{
+    crypto::{AccountId32, Ss58Codec},
+    hexdisplay::HexDisplay,
+};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// An account, serialized as its SS58 address.
+#[derive(Decode, Clone, Debug)]
+pub struct Ss58(pub AccountId32);
+
+impl Serialize for Ss58 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_ss58check())
+    }
+}
+
+/// Formats an u128 encoded ip address of type v4 or v6.
+fn ip_to_string(ip: u128, ip_type: u8) -> String {
+    match ip_type {
+        4 => Ipv4Addr::from(ip as u32).to_string(),
+        6 => Ipv6Addr::from(ip).to_string(),
+        _ => ip.to_string(),
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct AxonInfoJson {
+    pub block: u64,
+    pub version: u32,
+    pub ip: String,
+    pub port: u16,
+    pub ip_type: u8,
+    pub protocol: u8,
+    pub placeholder1: u8,
+    pub placeholder2: u8,
+}
+
+impl From<AxonInfo> for AxonInfoJson {
+    fn from(axon: AxonInfo) -> Self {
+        Self {
+            block: axon.block,
+            version: axon.version,
+            ip: ip_to_string(axon.ip, axon.ip_type),
+            port: axon.port,
+            ip_type: axon.ip_type,
+            protocol: axon.protocol,
+            placeholder1: axon.placeholder1,
+            placeholder2: axon.placeholder2,
+        }
+    }
+}
+
+/// An axon endpoint, its address is serialized as text together with its kind.
+#[derive(Serialize, Clone, Debug)]
+pub struct AxonEndpointJson {
+    pub kind: &'static str,
+    pub address: String,
+    pub port: u16,
+    pub protocol: u8,
+    pub cert_digest: Option<String>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct AxonInfoV2Json {
+    pub block: u64,
+    pub version: u32,
+    pub endpoints: Vec<AxonEndpointJson>,
+}
+
+impl From<AxonInfoV2> for AxonInfoV2Json {
+    fn from(axon: AxonInfoV2) -> Self {
+        Self {
+            block: axon.block,
+            version: axon.version,
+            endpoints: axon
+                .endpoints
+                .into_iter()
+                .map(|endpoint| {
+                    let (kind, address) = match endpoint.address {
+                        AxonAddress::Ipv4(ip) => ("ipv4", Ipv4Addr::from(ip).to_string()),
+                        AxonAddress::Ipv6(ip) => ("ipv6", Ipv6Addr::from(ip).to_string()),
+                        AxonAddress::Dns(name) => {
+                            ("dns", String::from_utf8_lossy(&name).into_owned())
+                        }
+                    };
+                    AxonEndpointJson {
+                        kind,
+                        address,
+                        port: endpoint.port,
+                        protocol: endpoint.protocol,
+                        cert_digest: endpoint
+                            .cert_digest
+                            .map(|digest| format!("0x{}", HexDisplay::from(&digest))),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct PrometheusInfoJson {
+    pub block: u64,
+    pub version: u32,
+    pub ip: String,
+    pub port: u16,
+    pub ip_type: u8,
+}
+
+impl From<PrometheusInfo> for PrometheusInfoJson {
+    fn from(prometheus: PrometheusInfo) -> Self {
+        Self {
+            block: prometheus.block,
+            version: prometheus.version,
+            ip: ip_to_string(prometheus.ip, prometheus.ip_type),
+            port: prometheus.port,
+            ip_type: prometheus.ip_type,
+        }
+    }
+}
+
+/// An amount owned by an account, serialized as `[address, amount]`.
+#[derive(Decode, Serialize, Clone, Debug)]
+pub struct AccountAmount(pub Ss58, #[codec(compact)] pub u64);
+
+/// A u16 value at a uid, serialized as `[uid, value]`.
+#[derive(Decode, Serialize, Clone, Debug)]
+pub struct UidValue(#[codec(compact)] pub u16, #[codec(compact)] pub u16);
+
+#[derive(Decode, Serialize, Clone, Debug)]
+pub struct DelegateInfoJson {
+    pub delegate_ss58: Ss58,
+    #[codec(compact)]
+    pub take: u16,
+    pub nominators: Vec<AccountAmount>,
+    pub owner_ss58: Ss58,
+    pub registrations: Vec<CompactU16>,
+    pub validator_permits: Vec<CompactU16>,
+    #[codec(compact)]
+    pub return_per_1000: u64,
+    #[codec(compact)]
+    pub total_daily_return: u64,
+}
+
+/// A delegate with the stake of the queried nominator on it.
+#[derive(Decode, Serialize, Clone, Debug)]
+pub struct DelegatedInfoJson(pub DelegateInfoJson, #[codec(compact)] pub u64);
+
+/// A compact encoded u16, serialized as a number.
+#[derive(Decode, Serialize, Clone, Debug)]
+#[serde(transparent)]
+pub struct CompactU16(#[codec(compact)] pub u16);
+
+#[derive(Decode, Serialize, Clone, Debug)]
+pub struct NeuronInfoJson {
+    pub hotkey: Ss58,
+    pub coldkey: Ss58,
+    #[codec(compact)]
+    pub uid: u16,
+    #[codec(compact)]
+    pub netuid: u16,
+    pub active: bool,
+    #[serde(serialize_with = "serialize_axon")]
+    pub axon_info: AxonInfo,
+    #[serde(serialize_with = "serialize_prometheus")]
+    pub prometheus_info: PrometheusInfo,
+    pub stake: Vec<AccountAmount>,
+    #[codec(compact)]
+    pub rank: u16,
+    #[codec(compact)]
+    pub emission: u64,
+    #[codec(compact)]
+    pub incentive: u16,
+    #[codec(compact)]
+    pub consensus: u16,
+    #[codec(compact)]
+    pub trust: u16,
+    #[codec(compact)]
+    pub validator_trust: u16,
+    #[codec(compact)]
+    pub dividends: u16,
+    #[codec(compact)]
+    pub last_update: u64,
+    pub validator_permit: bool,
+    pub weights: Vec<UidValue>,
+    pub bonds: Vec<UidValue>,
+    #[codec(compact)]
+    pub pruning_score: u16,
+}
+
+#[derive(Decode, Serialize, Clone, Debug)]
+pub struct NeuronInfoLiteJson {
+    pub hotkey: Ss58,
+    pub coldkey: Ss58,
+    #[codec(compact)]
+    pub uid: u16,
+    #[codec(compact)]
+    pub netuid: u16,
+    pub active: bool,
+    #[serde(serialize_with = "serialize_axon")]
+    pub axon_info: AxonInfo,
+    #[serde(serialize_with = "serialize_prometheus")]
+    pub prometheus_info: PrometheusInfo,
+    pub stake: Vec<AccountAmount>,
+    #[codec(compact)]
+    pub rank: u16,
+    #[codec(compact)]
+    pub emission: u64,
+    #[codec(compact)]
+    pub incentive: u16,
+    #[codec(compact)]
+    pub consensus: u16,
+    #[codec(compact)]
+    pub trust: u16,
+    #[codec(compact)]
+    pub validator_trust: u16,
+    #[codec(compact)]
+    pub dividends: u16,
+    #[codec(compact)]
+    pub last_update: u64,
+    pub validator_permit: bool,
+    #[codec(compact)]
+    pub pruning_score: u16,
+}
+
+/// A neuron with all endpoints of its axon, serialized as the fields of the neuron and
+/// `axon_info_v2`.
+#[derive(Decode, Serialize, Clone, Debug)]
+pub struct NeuronInfoV2Json {
+    #[serde(flatten)]
+    pub neuron: NeuronInfoJson,
+    #[serde(serialize_with = "serialize_axon_v2")]
+    pub axon_info_v2: AxonInfoV2,
+}
+
+#[derive(Decode, Serialize, Clone, Debug)]
+pub struct NeuronInfoLiteV2Json {
+    #[serde(flatten)]
+    pub neuron: NeuronInfoLiteJson,
+    #[serde(serialize_with = "serialize_axon_v2")]
+    pub axon_info_v2: AxonInfoV2,
+}
+
+/// The share of an emission split recipient, serialized as `[address, share]`.
+#[derive(Decode, Serialize, Clone, Debug)]
+pub struct AccountShare(pub Ss58, #[codec(compact)] pub u16);
+
+#[derive(Decode, Serialize, Clone, Debug)]
+pub struct SubnetInfoJson {
+    #[codec(compact)]
+    pub netuid: u16,
+    #[codec(compact)]
+    pub rho: u16,
+    #[codec(compact)]
+    pub kappa: u16,
+    #[codec(compact)]
+    pub difficulty: u64,
+    #[codec(compact)]
+    pub immunity_period: u16,
+    #[codec(compact)]
+    pub max_allowed_validators: u16,
+    #[codec(compact)]
+    pub min_allowed_weights: u16,
+    #[codec(compact)]
+    pub max_weights_limit: u16,
+    #[codec(compact)]
+    pub scaling_law_power: u16,
+    #[codec(compact)]
+    pub subnetwork_n: u16,
+    #[codec(compact)]
+    pub max_allowed_uids: u16,
+    #[codec(compact)]
+    pub blocks_since_last_step: u64,
+    #[codec(compact)]
+    pub tempo: u16,
+    #[codec(compact)]
+    pub network_modality: u16,
+    pub network_connect: Vec<[u16; 2]>,
+    #[codec(compact)]
+    pub emission_values: u64,
+    #[codec(compact)]
+    pub burn: u64,
+    pub owner: Ss58,
+    #[codec(compact)]
+    pub owner_cut: u16,
+    #[codec(compact)]
+    pub emission_burn: u16,
+    pub emission_recipients: Vec<AccountShare>,
+}
+
+#[derive(Decode, Serialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum RewardDestinationJson {
+    Compound,
+    Free,
+    Account(Ss58),
+}
+
+#[derive(Decode, Serialize, Clone, Debug)]
+pub struct StakeInfoJson {
+    pub hotkey: Ss58,
+    pub coldkey: Ss58,
+    #[codec(compact)]
+    pub stake: u64,
+    pub reward_destination: RewardDestinationJson,
+}
+
+fn serialize_axon<S: Serializer>(axon: &AxonInfo, serializer: S) -> Result<S::Ok, S::Error> {
+    AxonInfoJson::from(axon.clone()).serialize(serializer)
+}
+
+fn serialize_axon_v2<S: Serializer>(axon: &AxonInfoV2, serializer: S) -> Result<S::Ok, S::Error> {
+    AxonInfoV2Json::from(axon.clone()).serialize(serializer)
+}
+
+fn serialize_prometheus<S: Serializer>(
+    prometheus: &PrometheusInfo,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    PrometheusInfoJson::from(prometheus.clone()).serialize(serializer)
+}
+
+/// Decodes the bytes returned by a runtime API, an empty result decodes to None.
+pub fn decode_optional<T: Decode>(bytes: Vec<u8>) -> Result<Option<T>, codec::Error> {
+    if bytes.is_empty() {
+        return Ok(None);
+    }
+    T::decode(&mut &bytes[..]).map(Some)
+}
+
+/// Decodes an SS58 address into the bytes of the account, as expected by the runtime APIs.
+pub fn ss58_to_account_vec(address: &str) -> Option<Vec<u8>> {
+    AccountId32::from_ss58check(address)
+        .ok()
+        .map(|account| <AccountId32 as AsRef<[u8]>>::as_ref(&account).to_vec())
+}
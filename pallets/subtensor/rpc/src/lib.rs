@@ -1,5 +1,9 @@
 //! RPC interface for the custom Subtensor rpc methods
 
+pub mod json;
+pub mod subscriptions;
+
+use codec::Decode;
 use jsonrpsee::{
     core::RpcResult,
     proc_macros::rpc,
@@ -11,9 +15,13 @@ use std::sync::Arc;
 
 use sp_api::ProvideRuntimeApi;
 
+use json::{
+    DelegateInfoJson, DelegatedInfoJson, NeuronInfoJson, NeuronInfoLiteJson, NeuronInfoLiteV2Json,
+    NeuronInfoV2Json, StakeInfoJson, SubnetInfoJson,
+};
 pub use subtensor_custom_rpc_runtime_api::{
-    DelegateInfoRuntimeApi, NeuronInfoRuntimeApi, SubnetInfoRuntimeApi,
-    SubnetRegistrationRuntimeApi,
+    DelegateInfoRuntimeApi, DelegateReturnRuntimeApi, EpochInfoRuntimeApi, NeuronInfoRuntimeApi,
+    StakeInfoRuntimeApi, SubnetInfoRuntimeApi, SubnetRegistrationRuntimeApi,
 };
 
 #[rpc(client, server)]
@@ -32,6 +40,13 @@ pub trait SubtensorCustomApi<BlockHash> {
         delegatee_account_vec: Vec<u8>,
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<u8>>;
+    #[method(name = "delegateInfo_getDelegateReturnEstimate")]
+    fn get_delegate_return_estimate(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        epochs: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
 
     #[method(name = "neuronInfo_getNeuronsLite")]
     fn get_neurons_lite(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
@@ -41,6 +56,39 @@ pub trait SubtensorCustomApi<BlockHash> {
     fn get_neurons(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "neuronInfo_getNeuron")]
     fn get_neuron(&self, netuid: u16, uid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getNeuronsPaged")]
+    fn get_neurons_paged(
+        &self,
+        netuid: u16,
+        start_uid: u16,
+        limit: u16,
+        skip_fields: u8,
+        filters: u8,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getNeuronsLitePaged")]
+    fn get_neurons_lite_paged(
+        &self,
+        netuid: u16,
+        start_uid: u16,
+        limit: u16,
+        skip_fields: u8,
+        filters: u8,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getNeuronsV2")]
+    fn get_neurons_v2(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getNeuronV2")]
+    fn get_neuron_v2(&self, netuid: u16, uid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getNeuronsLiteV2")]
+    fn get_neurons_lite_v2(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "neuronInfo_getNeuronLiteV2")]
+    fn get_neuron_lite_v2(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
 
     #[method(name = "subnetInfo_getSubnetInfo")]
     fn get_subnet_info(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
@@ -48,13 +96,123 @@ pub trait SubtensorCustomApi<BlockHash> {
     fn get_subnets_info(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "subnetInfo_getSubnetHyperparams")]
     fn get_subnet_hyperparams(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getMetagraph")]
+    fn get_metagraph(&self, netuid: u16, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
     #[method(name = "subtensor_epoch")]
     fn epoch(&self, netuid: u16, rao_emission: u64, at: Option<BlockHash>) -> RpcResult<Vec<(Vec<u8>, u64, u64)>>;
+    #[method(name = "subnetInfo_getEpochDryRun")]
+    fn get_epoch_dry_run(
+        &self,
+        netuid: u16,
+        rao_emission: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getRootEpochInfo")]
+    fn get_root_epoch_info(&self, at: Option<BlockHash>) -> RpcResult<Vec<u8>>;
+    #[method(name = "subnetInfo_getMetagraphAtEpoch")]
+    fn get_metagraph_at_epoch(
+        &self,
+        netuid: u16,
+        epochs_ago: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<u8>>;
 
     #[method(name = "subnetInfo_getLockCost")]
     fn get_network_lock_cost(&self, at: Option<BlockHash>) -> RpcResult<u64>;
 }
 
+/// The info structs of `SubtensorCustomApi` decoded and serialized as JSON, with SS58 addresses
+/// and decoded axon IPs. Accounts are passed as SS58 addresses.
+#[rpc(server)]
+pub trait SubtensorJsonApi<BlockHash> {
+    #[method(name = "delegateInfo_getDelegatesJson")]
+    fn get_delegates_json(&self, at: Option<BlockHash>) -> RpcResult<Vec<DelegateInfoJson>>;
+    #[method(name = "delegateInfo_getDelegateJson")]
+    fn get_delegate_json(
+        &self,
+        delegate_ss58: String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<DelegateInfoJson>>;
+    #[method(name = "delegateInfo_getDelegatedJson")]
+    fn get_delegated_json(
+        &self,
+        delegatee_ss58: String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<DelegatedInfoJson>>;
+
+    #[method(name = "neuronInfo_getNeuronsLiteJson")]
+    fn get_neurons_lite_json(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NeuronInfoLiteJson>>;
+    #[method(name = "neuronInfo_getNeuronLiteJson")]
+    fn get_neuron_lite_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<NeuronInfoLiteJson>>;
+    #[method(name = "neuronInfo_getNeuronsJson")]
+    fn get_neurons_json(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NeuronInfoJson>>;
+    #[method(name = "neuronInfo_getNeuronJson")]
+    fn get_neuron_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<NeuronInfoJson>>;
+    #[method(name = "neuronInfo_getNeuronsLiteV2Json")]
+    fn get_neurons_lite_v2_json(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NeuronInfoLiteV2Json>>;
+    #[method(name = "neuronInfo_getNeuronLiteV2Json")]
+    fn get_neuron_lite_v2_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<NeuronInfoLiteV2Json>>;
+    #[method(name = "neuronInfo_getNeuronsV2Json")]
+    fn get_neurons_v2_json(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<NeuronInfoV2Json>>;
+    #[method(name = "neuronInfo_getNeuronV2Json")]
+    fn get_neuron_v2_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<NeuronInfoV2Json>>;
+
+    #[method(name = "subnetInfo_getSubnetInfoJson")]
+    fn get_subnet_info_json(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<SubnetInfoJson>>;
+    #[method(name = "subnetInfo_getSubnetsInfoJson")]
+    fn get_subnets_info_json(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<Option<SubnetInfoJson>>>;
+
+    #[method(name = "stakeInfo_getStakeInfoForColdkeyJson")]
+    fn get_stake_info_for_coldkey_json(
+        &self,
+        coldkey_ss58: String,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<StakeInfoJson>>;
+}
+
 pub struct SubtensorCustom<C, P> {
     /// Shared reference to the client.
     client: Arc<C>,
@@ -75,12 +233,17 @@ impl<C, P> SubtensorCustom<C, P> {
 pub enum Error {
     /// The call to runtime failed.
     RuntimeError(String),
+    /// The subscription limit of the node is reached.
+    TooManySubscriptions,
 }
 
 impl From<Error> for ErrorObjectOwned {
     fn from(e: Error) -> Self {
         match e {
             Error::RuntimeError(e) => ErrorObject::owned(1, e, None::<()>),
+            Error::TooManySubscriptions => {
+                ErrorObject::owned(2, "Too many subscriptions", None::<()>)
+            }
         }
     }
 }
@@ -89,6 +252,7 @@ impl From<Error> for i32 {
     fn from(e: Error) -> i32 {
         match e {
             Error::RuntimeError(_) => 1,
+            Error::TooManySubscriptions => 2,
         }
     }
 }
@@ -98,8 +262,10 @@ where
     Block: BlockT,
     C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
     C::Api: DelegateInfoRuntimeApi<Block>,
+    C::Api: DelegateReturnRuntimeApi<Block>,
     C::Api: NeuronInfoRuntimeApi<Block>,
     C::Api: SubnetInfoRuntimeApi<Block>,
+    C::Api: EpochInfoRuntimeApi<Block>,
     C::Api: SubnetRegistrationRuntimeApi<Block>,
 {
     fn get_delegates(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
@@ -137,6 +303,22 @@ where
         })
     }
 
+    fn get_delegate_return_estimate(
+        &self,
+        delegate_account_vec: Vec<u8>,
+        epochs: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_delegate_return_estimate(at, delegate_account_vec, epochs)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get delegate return estimate: {:?}", e))
+                    .into()
+            })
+    }
+
     fn get_neurons_lite(
         &self,
         netuid: u16,
@@ -185,6 +367,92 @@ where
             .map_err(|e| Error::RuntimeError(format!("Unable to get neuron info: {:?}", e)).into())
     }
 
+    fn get_neurons_paged(
+        &self,
+        netuid: u16,
+        start_uid: u16,
+        limit: u16,
+        skip_fields: u8,
+        filters: u8,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_neurons_paged(at, netuid, start_uid, limit, skip_fields, filters)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get neurons info: {:?}", e)).into())
+    }
+
+    fn get_neurons_lite_paged(
+        &self,
+        netuid: u16,
+        start_uid: u16,
+        limit: u16,
+        skip_fields: u8,
+        filters: u8,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_neurons_lite_paged(at, netuid, start_uid, limit, skip_fields, filters)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e)).into()
+            })
+    }
+
+    fn get_neurons_v2(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_neurons_v2(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get neurons info: {:?}", e)).into())
+    }
+
+    fn get_neuron_v2(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_neuron_v2(at, netuid, uid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get neuron info: {:?}", e)).into())
+    }
+
+    fn get_neurons_lite_v2(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_neurons_lite_v2(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e)).into()
+        })
+    }
+
+    fn get_neuron_lite_v2(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_neuron_lite_v2(at, netuid, uid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e)).into()
+        })
+    }
+
     fn get_subnet_info(
         &self,
         netuid: u16,
@@ -209,6 +477,18 @@ where
             .map_err(|e| Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)).into())
     }
 
+    fn get_metagraph(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_metagraph(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get metagraph: {:?}", e)).into())
+    }
+
     fn get_subnets_info(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
@@ -233,4 +513,268 @@ where
         api.epoch(at, netuid, rao_emission)
             .map_err(|e| Error::RuntimeError(format!("Unable to get epoch info: {:?}", e)).into())
     }
+
+    fn get_epoch_dry_run(
+        &self,
+        netuid: u16,
+        rao_emission: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_epoch_dry_run(at, netuid, rao_emission)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get epoch dry run: {:?}", e)).into()
+            })
+    }
+
+    fn get_root_epoch_info(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_root_epoch_info(at).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get root epoch info: {:?}", e)).into()
+        })
+    }
+
+    fn get_metagraph_at_epoch(
+        &self,
+        netuid: u16,
+        epochs_ago: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<u8>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_metagraph_at_epoch(at, netuid, epochs_ago)
+            .map_err(|e| {
+                Error::RuntimeError(format!("Unable to get metagraph at epoch: {:?}", e)).into()
+            })
+    }
+}
+
+fn decode_json<T: Decode>(bytes: Vec<u8>) -> RpcResult<T> {
+    T::decode(&mut &bytes[..]).map_err(|e| {
+        Error::RuntimeError(format!("Unable to decode runtime response: {:?}", e)).into()
+    })
+}
+
+fn decode_json_optional<T: Decode>(bytes: Vec<u8>) -> RpcResult<Option<T>> {
+    json::decode_optional(bytes).map_err(|e| {
+        Error::RuntimeError(format!("Unable to decode runtime response: {:?}", e)).into()
+    })
+}
+
+fn account_vec(address: &str) -> RpcResult<Vec<u8>> {
+    json::ss58_to_account_vec(address)
+        .ok_or_else(|| Error::RuntimeError(format!("Invalid SS58 address: {}", address)).into())
+}
+
+impl<C, Block> SubtensorJsonApiServer<<Block as BlockT>::Hash> for SubtensorCustom<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DelegateInfoRuntimeApi<Block>,
+    C::Api: NeuronInfoRuntimeApi<Block>,
+    C::Api: SubnetInfoRuntimeApi<Block>,
+    C::Api: StakeInfoRuntimeApi<Block>,
+{
+    fn get_delegates_json(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<DelegateInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_delegates(at)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)))?;
+        decode_json(bytes)
+    }
+
+    fn get_delegate_json(
+        &self,
+        delegate_ss58: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<DelegateInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_delegate(at, account_vec(&delegate_ss58)?)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)))?;
+        decode_json_optional(bytes)
+    }
+
+    fn get_delegated_json(
+        &self,
+        delegatee_ss58: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<DelegatedInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_delegated(at, account_vec(&delegatee_ss58)?)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get delegates info: {:?}", e)))?;
+        decode_json(bytes)
+    }
+
+    fn get_neurons_lite_json(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<NeuronInfoLiteJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api.get_neurons_lite(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e))
+        })?;
+        decode_json(bytes)
+    }
+
+    fn get_neuron_lite_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<NeuronInfoLiteJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api.get_neuron_lite(at, netuid, uid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e))
+        })?;
+        decode_json_optional(bytes)
+    }
+
+    fn get_neurons_json(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<NeuronInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_neurons(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get neurons info: {:?}", e)))?;
+        decode_json(bytes)
+    }
+
+    fn get_neuron_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<NeuronInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_neuron(at, netuid, uid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get neuron info: {:?}", e)))?;
+        decode_json_optional(bytes)
+    }
+
+    fn get_neurons_lite_v2_json(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<NeuronInfoLiteV2Json>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api.get_neurons_lite_v2(at, netuid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e))
+        })?;
+        decode_json(bytes)
+    }
+
+    fn get_neuron_lite_v2_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<NeuronInfoLiteV2Json>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api.get_neuron_lite_v2(at, netuid, uid).map_err(|e| {
+            Error::RuntimeError(format!("Unable to get neurons lite info: {:?}", e))
+        })?;
+        decode_json_optional(bytes)
+    }
+
+    fn get_neurons_v2_json(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<NeuronInfoV2Json>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_neurons_v2(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get neurons info: {:?}", e)))?;
+        decode_json(bytes)
+    }
+
+    fn get_neuron_v2_json(
+        &self,
+        netuid: u16,
+        uid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<NeuronInfoV2Json>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_neuron_v2(at, netuid, uid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get neuron info: {:?}", e)))?;
+        decode_json_optional(bytes)
+    }
+
+    fn get_subnet_info_json(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<SubnetInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_subnet_info(at, netuid)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get subnet info: {:?}", e)))?;
+        decode_json_optional(bytes)
+    }
+
+    fn get_subnets_info_json(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<Option<SubnetInfoJson>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_subnets_info(at)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get subnets info: {:?}", e)))?;
+        decode_json(bytes)
+    }
+
+    fn get_stake_info_for_coldkey_json(
+        &self,
+        coldkey_ss58: String,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<StakeInfoJson>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let bytes = api
+            .get_stake_info_for_coldkey(at, account_vec(&coldkey_ss58)?)
+            .map_err(|e| Error::RuntimeError(format!("Unable to get stake info: {:?}", e)))?;
+        decode_json(bytes)
+    }
 }
@@ -0,0 +1,499 @@
+//! Subscriptions pushing subnet epoch results and neuron changes as blocks are imported.
+//!
+//! Subscribers to the same subnet share a single producer, which follows the client's import
+//! notification stream, reads the subnet once at every new best block and fans out whatever
+//! changed since the previous one. The number of open subscriptions is capped.
+
+use codec::{Decode, Encode};
+use futures::{
+    channel::mpsc,
+    future::{self, Either},
+    FutureExt, Stream, StreamExt,
+};
+use jsonrpsee::{proc_macros::rpc, PendingSubscriptionSink, SubscriptionMessage};
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_core::{hashing::twox_128, storage::StorageKey, traits::SpawnNamed};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+use crate::json::{AxonInfoJson, AxonInfoV2Json, NeuronInfoLiteV2Json, Ss58};
+use crate::Error;
+use subtensor_custom_rpc_runtime_api::NeuronInfoRuntimeApi;
+
+/// Subscriptions of a kind open at once over all subnets, further ones are rejected.
+pub const MAX_SUBSCRIPTIONS: usize = 1024;
+/// Notifications buffered for a subscriber, a subscriber falling further behind is dropped.
+const SUBSCRIBER_BUFFER: usize = 64;
+
+/// Sent when the epoch of a subnet ran, that is when its `LastMechanismStepBlock` advanced.
+#[derive(Serialize, Clone, Debug)]
+pub struct EpochNotification {
+    pub netuid: u16,
+    pub block: u64,
+    pub block_hash: String,
+    /// The registered uids, freed uids are left out.
+    pub uids: Vec<u16>,
+    pub incentive: Vec<u16>,
+    pub dividends: Vec<u16>,
+}
+
+/// A change to a neuron of a subnet.
+#[derive(Serialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NeuronChange {
+    /// A hotkey was registered on the uid, `replaced` is the hotkey it pruned if any.
+    Registered {
+        netuid: u16,
+        uid: u16,
+        block: u64,
+        hotkey: Ss58,
+        replaced: Option<Ss58>,
+    },
+    /// The hotkey on the uid served a new axon.
+    AxonChanged {
+        netuid: u16,
+        uid: u16,
+        block: u64,
+        hotkey: Ss58,
+        axon_info: AxonInfoJson,
+        axon_info_v2: AxonInfoV2Json,
+    },
+}
+
+#[rpc(server)]
+pub trait SubtensorSubscriptionApi {
+    /// Notifies every time the epoch of the subnet ran, with its new incentive and dividends.
+    #[subscription(
+        name = "subtensor_subscribeEpochs" => "subtensor_epochResult",
+        unsubscribe = "subtensor_unsubscribeEpochs",
+        item = EpochNotification
+    )]
+    fn subscribe_epochs(&self, netuid: u16);
+
+    /// Notifies every registration and axon change on the subnet.
+    #[subscription(
+        name = "subtensor_subscribeNeuronChanges" => "subtensor_neuronChange",
+        unsubscribe = "subtensor_unsubscribeNeuronChanges",
+        item = NeuronChange
+    )]
+    fn subscribe_neuron_changes(&self, netuid: u16);
+}
+
+pub struct SubtensorSubscriptions<C, Block, BE> {
+    /// Shared reference to the client.
+    client: Arc<C>,
+    /// Executor the subscription tasks are spawned on.
+    executor: Arc<dyn SpawnNamed>,
+    /// Subscribers to the epochs of each subnet.
+    epochs: Arc<Feeds<EpochNotification>>,
+    /// Subscribers to the neuron changes of each subnet.
+    neuron_changes: Arc<Feeds<NeuronChange>>,
+    _marker: PhantomData<(Block, BE)>,
+}
+
+impl<C, Block, BE> SubtensorSubscriptions<C, Block, BE> {
+    /// Creates a new instance of the subscriptions Rpc helper.
+    pub fn new(client: Arc<C>, executor: Arc<dyn SpawnNamed>) -> Self {
+        Self {
+            client,
+            executor,
+            epochs: Arc::new(Feeds::new()),
+            neuron_changes: Arc::new(Feeds::new()),
+            _marker: Default::default(),
+        }
+    }
+
+    /// Forwards the notifications to the subscriber, or rejects it if there is no room left.
+    fn spawn_subscription<T>(
+        &self,
+        pending: PendingSubscriptionSink,
+        notifications: Option<mpsc::Receiver<T>>,
+    ) where
+        T: Serialize + Send + 'static,
+    {
+        let task = match notifications {
+            Some(notifications) => pipe_from_stream(pending, notifications).boxed(),
+            None => pending.reject(Error::TooManySubscriptions).boxed(),
+        };
+        self.executor
+            .spawn("subtensor-rpc-subscription", Some("rpc"), task);
+    }
+}
+
+/// The subscribers to a kind of notification, by subnet. A subnet with subscribers has a single
+/// producer, yielding the notifications of every new best block to all of them.
+struct Feeds<T> {
+    subscribers: Mutex<HashMap<u16, Vec<mpsc::Sender<T>>>>,
+}
+
+impl<T: Clone + Send + 'static> Feeds<T> {
+    fn new() -> Self {
+        Self {
+            subscribers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Adds a subscriber to the subnet, spawning the producer of the subnet if it is the first.
+    /// None if MAX_SUBSCRIPTIONS subscriptions are already open.
+    fn subscribe<P>(
+        self: &Arc<Self>,
+        netuid: u16,
+        executor: &dyn SpawnNamed,
+        producer: impl FnOnce() -> P,
+    ) -> Option<mpsc::Receiver<T>>
+    where
+        P: Stream<Item = Vec<T>> + Unpin + Send + 'static,
+    {
+        let mut subscribers = self.subscribers.lock().ok()?;
+        if subscribers.values().map(Vec::len).sum::<usize>() >= MAX_SUBSCRIPTIONS {
+            return None;
+        }
+
+        let (sender, receiver) = mpsc::channel(SUBSCRIBER_BUFFER);
+        match subscribers.entry(netuid) {
+            Entry::Occupied(mut entry) => entry.get_mut().push(sender),
+            Entry::Vacant(entry) => {
+                entry.insert(vec![sender]);
+                executor.spawn(
+                    "subtensor-rpc-subscription",
+                    Some("rpc"),
+                    self.clone().produce(netuid, producer()).boxed(),
+                );
+            }
+        }
+        Some(receiver)
+    }
+
+    /// Sends the notifications of every best block to the subscribers of the subnet, dropping
+    /// the closed and lagging ones, until none is left.
+    async fn produce<P>(self: Arc<Self>, netuid: u16, mut producer: P)
+    where
+        P: Stream<Item = Vec<T>> + Unpin,
+    {
+        while let Some(notifications) = producer.next().await {
+            let Ok(mut subscribers) = self.subscribers.lock() else {
+                return;
+            };
+            let Some(senders) = subscribers.get_mut(&netuid) else {
+                return;
+            };
+            senders.retain_mut(|sender| {
+                !sender.is_closed()
+                    && notifications
+                        .iter()
+                        .all(|notification| sender.try_send(notification.clone()).is_ok())
+            });
+            if senders.is_empty() {
+                subscribers.remove(&netuid);
+                return;
+            }
+        }
+
+        if let Ok(mut subscribers) = self.subscribers.lock() {
+            subscribers.remove(&netuid);
+        }
+    }
+}
+
+/// Storage key of `LastMechansimStepBlock` for the subnet.
+fn last_mechanism_step_block_key(netuid: u16) -> StorageKey {
+    let mut key = twox_128(b"SubtensorModule").to_vec();
+    key.extend(twox_128(b"LastMechansimStepBlock"));
+    key.extend(netuid.encode());
+    StorageKey(key)
+}
+
+fn block_number<Block: BlockT>(header: &Block::Header) -> u64 {
+    (*header.number()).unique_saturated_into()
+}
+
+/// Reads the subnet's neurons with all their axon endpoints at the block, None if the subnet
+/// does not exist or the call failed.
+fn neurons_lite<C, Block>(
+    client: &C,
+    at: Block::Hash,
+    netuid: u16,
+) -> Option<Vec<NeuronInfoLiteV2Json>>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block>,
+    C::Api: NeuronInfoRuntimeApi<Block>,
+{
+    let bytes = client.runtime_api().get_neurons_lite_v2(at, netuid).ok()?;
+    Vec::<NeuronInfoLiteV2Json>::decode(&mut &bytes[..]).ok()
+}
+
+/// The changes between the neurons of the subnet at two consecutive best blocks.
+fn neuron_changes(
+    netuid: u16,
+    block: u64,
+    previous: &[NeuronInfoLiteV2Json],
+    current: &[NeuronInfoLiteV2Json],
+) -> Vec<NeuronChange> {
+    let mut changes = Vec::new();
+    for NeuronInfoLiteV2Json {
+        neuron,
+        axon_info_v2,
+    } in current
+    {
+        let uid = neuron.uid;
+        match previous.iter().find(|old| old.neuron.uid == uid) {
+            Some(old) if old.neuron.hotkey.0 == neuron.hotkey.0 => {
+                if old.neuron.axon_info != neuron.axon_info || old.axon_info_v2 != *axon_info_v2 {
+                    changes.push(NeuronChange::AxonChanged {
+                        netuid,
+                        uid,
+                        block,
+                        hotkey: neuron.hotkey.clone(),
+                        axon_info: neuron.axon_info.clone().into(),
+                        axon_info_v2: axon_info_v2.clone().into(),
+                    });
+                }
+            }
+            old => changes.push(NeuronChange::Registered {
+                netuid,
+                uid,
+                block,
+                hotkey: neuron.hotkey.clone(),
+                replaced: old.map(|old| old.neuron.hotkey.clone()),
+            }),
+        }
+    }
+    changes
+}
+
+/// Forwards the items of the stream to the subscriber until either side is closed.
+async fn pipe_from_stream<S, T>(pending: PendingSubscriptionSink, mut stream: S)
+where
+    S: Stream<Item = T> + Unpin + Send + 'static,
+    T: Serialize + Send + 'static,
+{
+    let Ok(sink) = pending.accept().await else {
+        return;
+    };
+    loop {
+        let closed = sink.closed();
+        futures::pin_mut!(closed);
+        match future::select(closed, stream.next()).await {
+            Either::Right((Some(item), _)) => {
+                let Ok(message) = SubscriptionMessage::from_json(&item) else {
+                    break;
+                };
+                if sink.send(message).await.is_err() {
+                    break;
+                }
+            }
+            _ => break,
+        }
+    }
+}
+
+impl<C, Block, BE> SubtensorSubscriptionApiServer for SubtensorSubscriptions<C, Block, BE>
+where
+    Block: BlockT,
+    BE: Backend<Block> + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + BlockchainEvents<Block> + StorageProvider<Block, BE>,
+    C: Send + Sync + 'static,
+    C::Api: NeuronInfoRuntimeApi<Block>,
+{
+    fn subscribe_epochs(&self, pending: PendingSubscriptionSink, netuid: u16) {
+        let client = self.client.clone();
+        let producer = || {
+            let key = last_mechanism_step_block_key(netuid);
+            let mut last_step: Option<u64> = None;
+
+            self.client
+                .import_notification_stream()
+                .filter(|notification| future::ready(notification.is_new_best))
+                .map(move |notification| {
+                    let hash = notification.hash;
+                    let step = client
+                        .storage(hash, &key)
+                        .ok()
+                        .flatten()
+                        .and_then(|data| u64::decode(&mut &data.0[..]).ok())
+                        .unwrap_or_default();
+                    let advanced = last_step.is_some_and(|last| step > last);
+                    last_step = Some(step);
+
+                    advanced
+                        .then(|| neurons_lite(&*client, hash, netuid))
+                        .flatten()
+                        .map(|neurons| EpochNotification {
+                            netuid,
+                            block: step,
+                            block_hash: format!("{:?}", hash),
+                            uids: neurons.iter().map(|neuron| neuron.neuron.uid).collect(),
+                            incentive: neurons
+                                .iter()
+                                .map(|neuron| neuron.neuron.incentive)
+                                .collect(),
+                            dividends: neurons
+                                .iter()
+                                .map(|neuron| neuron.neuron.dividends)
+                                .collect(),
+                        })
+                        .into_iter()
+                        .collect::<Vec<_>>()
+                })
+                .boxed()
+        };
+
+        let notifications = self.epochs.subscribe(netuid, &*self.executor, producer);
+        self.spawn_subscription(pending, notifications);
+    }
+
+    fn subscribe_neuron_changes(&self, pending: PendingSubscriptionSink, netuid: u16) {
+        let client = self.client.clone();
+        let producer = || {
+            let mut previous: Option<Vec<NeuronInfoLiteV2Json>> = None;
+
+            self.client
+                .import_notification_stream()
+                .filter(|notification| future::ready(notification.is_new_best))
+                .map(move |notification| {
+                    let block = block_number::<Block>(&notification.header);
+                    neurons_lite(&*client, notification.hash, netuid)
+                        .map(|current| {
+                            let changes = previous
+                                .as_ref()
+                                .map(|previous| neuron_changes(netuid, block, previous, &current))
+                                .unwrap_or_default();
+                            previous = Some(current);
+                            changes
+                        })
+                        .unwrap_or_default()
+                })
+                .boxed()
+        };
+
+        let notifications = self
+            .neuron_changes
+            .subscribe(netuid, &*self.executor, producer);
+        self.spawn_subscription(pending, notifications);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::NeuronInfoLiteJson;
+    use pallet_subtensor::{AxonInfo, AxonInfoV2, PrometheusInfo};
+    use sp_core::crypto::AccountId32;
+
+    fn neuron(uid: u16, hotkey: u8, port: u16) -> NeuronInfoLiteV2Json {
+        NeuronInfoLiteV2Json {
+            neuron: NeuronInfoLiteJson {
+                hotkey: Ss58(AccountId32::new([hotkey; 32])),
+                coldkey: Ss58(AccountId32::new([0; 32])),
+                uid,
+                netuid: 1,
+                active: true,
+                axon_info: AxonInfo {
+                    port,
+                    ..Default::default()
+                },
+                prometheus_info: PrometheusInfo::default(),
+                stake: Vec::new(),
+                rank: 0,
+                emission: 0,
+                incentive: 0,
+                consensus: 0,
+                trust: 0,
+                validator_trust: 0,
+                dividends: 0,
+                last_update: 0,
+                validator_permit: false,
+                pruning_score: 0,
+            },
+            axon_info_v2: AxonInfoV2::default(),
+        }
+    }
+
+    #[test]
+    fn test_neuron_changes_unchanged() {
+        let neurons = vec![neuron(0, 1, 8091), neuron(1, 2, 8091)];
+
+        assert!(neuron_changes(1, 10, &neurons, &neurons).is_empty());
+    }
+
+    #[test]
+    fn test_neuron_changes_registration() {
+        let previous = vec![neuron(0, 1, 8091)];
+        let current = vec![neuron(0, 1, 8091), neuron(1, 2, 8091)];
+
+        let changes = neuron_changes(1, 10, &previous, &current);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            &changes[0],
+            NeuronChange::Registered { netuid: 1, uid: 1, block: 10, hotkey, replaced: None }
+                if hotkey.0 == AccountId32::new([2; 32])
+        ));
+    }
+
+    #[test]
+    fn test_neuron_changes_registration_on_freed_uid() {
+        let previous = vec![neuron(0, 1, 8091), neuron(2, 3, 8091)];
+        let current = vec![neuron(0, 1, 8091), neuron(1, 2, 8091), neuron(2, 3, 8091)];
+
+        let changes = neuron_changes(1, 10, &previous, &current);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            &changes[0],
+            NeuronChange::Registered {
+                uid: 1,
+                replaced: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_neuron_changes_replacement() {
+        let previous = vec![neuron(0, 1, 8091), neuron(1, 2, 8091)];
+        let current = vec![neuron(0, 1, 8091), neuron(1, 3, 8091)];
+
+        let changes = neuron_changes(1, 10, &previous, &current);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            &changes[0],
+            NeuronChange::Registered { uid: 1, hotkey, replaced: Some(replaced), .. }
+                if hotkey.0 == AccountId32::new([3; 32])
+                    && replaced.0 == AccountId32::new([2; 32])
+        ));
+    }
+
+    #[test]
+    fn test_neuron_changes_axon_change() {
+        let previous = vec![neuron(0, 1, 8091), neuron(1, 2, 8091)];
+        let current = vec![neuron(0, 1, 8091), neuron(1, 2, 9000)];
+
+        let changes = neuron_changes(1, 10, &previous, &current);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            &changes[0],
+            NeuronChange::AxonChanged { uid: 1, hotkey, axon_info, .. }
+                if hotkey.0 == AccountId32::new([2; 32]) && axon_info.port == 9000
+        ));
+    }
+
+    #[test]
+    fn test_neuron_changes_axon_v2_change() {
+        let previous = vec![neuron(0, 1, 8091)];
+        let mut current = vec![neuron(0, 1, 8091)];
+        current[0].axon_info_v2.version = 2;
+
+        let changes = neuron_changes(1, 10, &previous, &current);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(
+            &changes[0],
+            NeuronChange::AxonChanged { uid: 0, axon_info_v2, .. } if axon_info_v2.version == 2
+        ));
+    }
+}
@@ -0,0 +1,274 @@
+use codec::{DecodeAll, Encode};
+use frame_support::storage::storage_prefix;
+use node_subtensor_runtime::{AccountId, Runtime, SubtensorModule};
+use pallet_subtensor::{
+    AxonAddress, AxonEndpoint, AxonInfo, AxonInfoV2, Axons, AxonsV2, EmissionSplit, Prometheus,
+    PrometheusInfo, RewardDestination, RewardDestinations, SubnetEmissionSplit, SubnetOwner,
+};
+use sp_core::crypto::Ss58Codec;
+use sp_runtime::BoundedVec;
+use subtensor_custom_rpc::json::{
+    DelegateInfoJson, DelegatedInfoJson, NeuronInfoJson, NeuronInfoLiteJson, NeuronInfoLiteV2Json,
+    NeuronInfoV2Json, StakeInfoJson, SubnetInfoJson,
+};
+
+const NETUID: u16 = 1;
+const HOTKEY: [u8; 32] = [1_u8; 32];
+const COLDKEY: [u8; 32] = [2_u8; 32];
+const NOMINATOR: [u8; 32] = [3_u8; 32];
+const RECIPIENT: [u8; 32] = [4_u8; 32];
+
+fn ss58(account: [u8; 32]) -> String {
+    AccountId::from(account).to_ss58check()
+}
+
+// Sets a double map entry of the subtensor pallet that has no public setter.
+fn put_uid_values(storage: &[u8], netuid: u16, uid: u16, values: Vec<(u16, u16)>) {
+    let mut key = storage_prefix(b"SubtensorModule", storage).to_vec();
+    key.extend(netuid.encode());
+    key.extend(uid.encode());
+    sp_io::storage::set(&key, &values.encode());
+}
+
+// Registers a delegate on a subnet, with every field of the info structs set.
+#[allow(clippy::unwrap_used)]
+fn new_test_ext() -> sp_io::TestExternalities {
+    let mut ext = sp_io::TestExternalities::default();
+    ext.execute_with(|| {
+        let hotkey = AccountId::from(HOTKEY);
+        let coldkey = AccountId::from(COLDKEY);
+        let nominator = AccountId::from(NOMINATOR);
+
+        SubtensorModule::init_new_network(NETUID, 10);
+        SubnetOwner::<Runtime>::insert(NETUID, coldkey.clone());
+        SubnetEmissionSplit::<Runtime>::insert(
+            NETUID,
+            EmissionSplit {
+                owner_cut: 1_000,
+                burn: 2_000,
+                recipients: vec![(AccountId::from(RECIPIENT), 3_000)],
+            },
+        );
+
+        SubtensorModule::append_neuron(NETUID, &hotkey, 7);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+        SubtensorModule::delegate_hotkey(&hotkey, 11_796);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &hotkey, 500);
+        RewardDestinations::<Runtime>::insert(
+            &nominator,
+            &hotkey,
+            RewardDestination::Account(AccountId::from(RECIPIENT)),
+        );
+
+        Axons::<Runtime>::insert(
+            NETUID,
+            &hotkey,
+            AxonInfo {
+                block: 7,
+                version: 2,
+                ip: 0x7f00_0001,
+                port: 8091,
+                ip_type: 4,
+                protocol: 0,
+                placeholder1: 0,
+                placeholder2: 0,
+            },
+        );
+        AxonsV2::<Runtime>::insert(
+            NETUID,
+            &hotkey,
+            AxonInfoV2 {
+                block: 7,
+                version: 2,
+                endpoints: BoundedVec::try_from(vec![
+                    AxonEndpoint {
+                        address: AxonAddress::Ipv4(0x7f00_0001),
+                        port: 8091,
+                        protocol: 0,
+                        cert_digest: None,
+                    },
+                    AxonEndpoint {
+                        address: AxonAddress::Dns(
+                            BoundedVec::try_from(b"axon.example.com".to_vec()).unwrap(),
+                        ),
+                        port: 443,
+                        protocol: 1,
+                        cert_digest: Some([0xab; 32]),
+                    },
+                ])
+                .unwrap(),
+            },
+        );
+        Prometheus::<Runtime>::insert(
+            NETUID,
+            &hotkey,
+            PrometheusInfo {
+                block: 7,
+                version: 1,
+                ip: 1,
+                port: 9090,
+                ip_type: 6,
+            },
+        );
+        put_uid_values(b"Weights", NETUID, 0, vec![(0, u16::MAX)]);
+        put_uid_values(b"Bonds", NETUID, 0, vec![(0, 42)]);
+    });
+    ext
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_neuron_info_json_decodes_neuron_info() {
+    new_test_ext().execute_with(|| {
+        let encoded = SubtensorModule::get_neurons(NETUID).encode();
+        let neurons = Vec::<NeuronInfoJson>::decode_all(&mut &encoded[..]).unwrap();
+
+        assert_eq!(neurons.len(), 1);
+        let json = serde_json::to_value(&neurons[0]).unwrap();
+        assert_eq!(json["hotkey"], ss58(HOTKEY));
+        assert_eq!(json["coldkey"], ss58(COLDKEY));
+        assert_eq!(json["uid"], 0);
+        assert_eq!(json["netuid"], NETUID);
+        assert_eq!(json["last_update"], 7);
+        assert_eq!(json["axon_info"]["ip"], "127.0.0.1");
+        assert_eq!(json["prometheus_info"]["ip"], "::1");
+        assert_eq!(json["stake"].as_array().unwrap().len(), 2);
+        assert_eq!(json["weights"], serde_json::json!([[0, u16::MAX]]));
+        assert_eq!(json["bonds"], serde_json::json!([[0, 42]]));
+        assert!(json.get("axon_info_v2").is_none());
+
+        let encoded = SubtensorModule::get_neuron(NETUID, 0).encode();
+        let neuron = Option::<NeuronInfoJson>::decode_all(&mut &encoded[..]).unwrap();
+        assert!(neuron.is_some());
+    });
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_neuron_info_v2_json_decodes_neuron_info_v2() {
+    new_test_ext().execute_with(|| {
+        let encoded = SubtensorModule::get_neurons_v2(NETUID).encode();
+        let neurons = Vec::<NeuronInfoV2Json>::decode_all(&mut &encoded[..]).unwrap();
+
+        assert_eq!(neurons.len(), 1);
+        let json = serde_json::to_value(&neurons[0]).unwrap();
+        assert_eq!(json["hotkey"], ss58(HOTKEY));
+        assert_eq!(json["axon_info"]["ip"], "127.0.0.1");
+        assert_eq!(json["pruning_score"], 0);
+        assert_eq!(json["axon_info_v2"]["endpoints"][0]["kind"], "ipv4");
+        assert_eq!(json["axon_info_v2"]["endpoints"][1]["kind"], "dns");
+        assert_eq!(
+            json["axon_info_v2"]["endpoints"][1]["address"],
+            "axon.example.com"
+        );
+        assert_eq!(
+            json["axon_info_v2"]["endpoints"][1]["cert_digest"],
+            format!("0x{}", "ab".repeat(32))
+        );
+
+        let encoded = SubtensorModule::get_neuron_v2(NETUID, 0).encode();
+        let neuron = Option::<NeuronInfoV2Json>::decode_all(&mut &encoded[..]).unwrap();
+        assert!(neuron.is_some());
+    });
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_neuron_info_lite_json_decodes_neuron_info_lite() {
+    new_test_ext().execute_with(|| {
+        let encoded = SubtensorModule::get_neurons_lite(NETUID).encode();
+        let neurons = Vec::<NeuronInfoLiteJson>::decode_all(&mut &encoded[..]).unwrap();
+
+        assert_eq!(neurons.len(), 1);
+        let json = serde_json::to_value(&neurons[0]).unwrap();
+        assert_eq!(json["hotkey"], ss58(HOTKEY));
+        assert_eq!(json["last_update"], 7);
+        assert_eq!(json["axon_info"]["port"], 8091);
+        assert_eq!(json["prometheus_info"]["port"], 9090);
+        assert!(json.get("axon_info_v2").is_none());
+
+        let encoded = SubtensorModule::get_neurons_lite_paged(NETUID, 0, 1, 0, 0).encode();
+        let neurons = Vec::<NeuronInfoLiteJson>::decode_all(&mut &encoded[..]).unwrap();
+        assert_eq!(neurons.len(), 1);
+
+        let encoded = SubtensorModule::get_neurons_lite_v2(NETUID).encode();
+        let neurons = Vec::<NeuronInfoLiteV2Json>::decode_all(&mut &encoded[..]).unwrap();
+        assert_eq!(neurons.len(), 1);
+        let json = serde_json::to_value(&neurons[0]).unwrap();
+        assert_eq!(json["last_update"], 7);
+        assert_eq!(json["axon_info"]["port"], 8091);
+        assert_eq!(json["axon_info_v2"]["endpoints"][1]["port"], 443);
+
+        let encoded = SubtensorModule::get_neuron_lite_v2(NETUID, 0).encode();
+        let neuron = Option::<NeuronInfoLiteV2Json>::decode_all(&mut &encoded[..]).unwrap();
+        assert!(neuron.is_some());
+    });
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_delegate_info_json_decodes_delegate_info() {
+    new_test_ext().execute_with(|| {
+        let encoded = SubtensorModule::get_delegates().encode();
+        let delegates = Vec::<DelegateInfoJson>::decode_all(&mut &encoded[..]).unwrap();
+
+        assert_eq!(delegates.len(), 1);
+        let json = serde_json::to_value(&delegates[0]).unwrap();
+        assert_eq!(json["delegate_ss58"], ss58(HOTKEY));
+        assert_eq!(json["owner_ss58"], ss58(COLDKEY));
+        assert_eq!(json["take"], 11_796);
+        assert_eq!(json["registrations"], serde_json::json!([NETUID]));
+        assert_eq!(json["nominators"].as_array().unwrap().len(), 2);
+
+        let encoded = SubtensorModule::get_delegated(NOMINATOR.to_vec()).encode();
+        let delegated = Vec::<DelegatedInfoJson>::decode_all(&mut &encoded[..]).unwrap();
+
+        assert_eq!(delegated.len(), 1);
+        let json = serde_json::to_value(&delegated[0]).unwrap();
+        assert_eq!(json[0]["delegate_ss58"], ss58(HOTKEY));
+        assert_eq!(json[1], 500);
+    });
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_subnet_info_json_decodes_subnet_info() {
+    new_test_ext().execute_with(|| {
+        let encoded = SubtensorModule::get_subnet_info(NETUID).encode();
+        let subnet = Option::<SubnetInfoJson>::decode_all(&mut &encoded[..])
+            .unwrap()
+            .unwrap();
+
+        let json = serde_json::to_value(&subnet).unwrap();
+        assert_eq!(json["netuid"], NETUID);
+        assert_eq!(json["tempo"], 10);
+        assert_eq!(json["subnetwork_n"], 1);
+        assert_eq!(json["owner"], ss58(COLDKEY));
+        assert_eq!(json["owner_cut"], 1_000);
+        assert_eq!(json["emission_burn"], 2_000);
+        assert_eq!(
+            json["emission_recipients"],
+            serde_json::json!([[ss58(RECIPIENT), 3_000]])
+        );
+    });
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_stake_info_json_decodes_stake_info() {
+    new_test_ext().execute_with(|| {
+        let encoded = SubtensorModule::get_stake_info_for_coldkey(NOMINATOR.to_vec()).encode();
+        let stakes = Vec::<StakeInfoJson>::decode_all(&mut &encoded[..]).unwrap();
+
+        assert_eq!(stakes.len(), 1);
+        let json = serde_json::to_value(&stakes[0]).unwrap();
+        assert_eq!(json["hotkey"], ss58(HOTKEY));
+        assert_eq!(json["coldkey"], ss58(NOMINATOR));
+        assert_eq!(json["stake"], 500);
+        assert_eq!(
+            json["reward_destination"],
+            serde_json::json!({ "account": ss58(RECIPIENT) })
+        );
+    });
+}
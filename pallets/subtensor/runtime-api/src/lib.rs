@@ -3,7 +3,7 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 // Here we declare the runtime API. It is implemented it the `impl` block in
-// src/neuron_info.rs, src/subnet_info.rs, and src/delegate_info.rs
+// src/neuron_info.rs, src/subnet_info.rs, src/delegate_info.rs, src/delegate_return.rs, src/commit_info.rs, src/epoch_info.rs and src/emission_info.rs
 sp_api::decl_runtime_apis! {
     pub trait DelegateInfoRuntimeApi {
         fn get_delegates() -> Vec<u8>;
@@ -11,23 +11,60 @@ sp_api::decl_runtime_apis! {
         fn get_delegated( delegatee_account_vec: Vec<u8> ) -> Vec<u8>;
     }
 
+    pub trait DelegateReturnRuntimeApi {
+        fn get_delegate_return_estimate( delegate_account_vec: Vec<u8>, epochs: u16 ) -> Vec<u8>;
+    }
+
+    #[api_version(3)]
     pub trait NeuronInfoRuntimeApi {
         fn get_neurons(netuid: u16) -> Vec<u8>;
         fn get_neuron(netuid: u16, uid: u16) -> Vec<u8>;
         fn get_neurons_lite(netuid: u16) -> Vec<u8>;
         fn get_neuron_lite(netuid: u16, uid: u16) -> Vec<u8>;
+        #[api_version(2)]
+        fn get_neurons_paged(netuid: u16, start_uid: u16, limit: u16, skip_fields: u8, filters: u8) -> Vec<u8>;
+        #[api_version(2)]
+        fn get_neurons_lite_paged(netuid: u16, start_uid: u16, limit: u16, skip_fields: u8, filters: u8) -> Vec<u8>;
+        #[api_version(3)]
+        fn get_neurons_v2(netuid: u16) -> Vec<u8>;
+        #[api_version(3)]
+        fn get_neuron_v2(netuid: u16, uid: u16) -> Vec<u8>;
+        #[api_version(3)]
+        fn get_neurons_lite_v2(netuid: u16) -> Vec<u8>;
+        #[api_version(3)]
+        fn get_neuron_lite_v2(netuid: u16, uid: u16) -> Vec<u8>;
     }
 
+    #[api_version(2)]
     pub trait SubnetInfoRuntimeApi {
         fn get_subnet_info(netuid: u16) -> Vec<u8>;
         fn get_subnets_info() -> Vec<u8>;
         fn get_subnet_hyperparams(netuid: u16) -> Vec<u8>;
+        #[api_version(2)]
+        fn get_metagraph(netuid: u16) -> Vec<u8>;
         fn epoch(netuid: u16, rao_emission: u64) -> Vec<(Vec<u8>, u64, u64)>;
     }
 
+    #[api_version(2)]
     pub trait StakeInfoRuntimeApi {
         fn get_stake_info_for_coldkey( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
         fn get_stake_info_for_coldkeys( coldkey_account_vecs: Vec<Vec<u8>> ) -> Vec<u8>;
+        #[api_version(2)]
+        fn get_unbonding_info_for_coldkey( coldkey_account_vec: Vec<u8> ) -> Vec<u8>;
+    }
+
+    pub trait WeightCommitInfoRuntimeApi {
+        fn get_pending_weight_commits(netuid: u16, hotkey_account_vec: Vec<u8>) -> Vec<u8>;
+    }
+
+    pub trait EpochInfoRuntimeApi {
+        fn get_epoch_dry_run(netuid: u16, rao_emission: u64) -> Vec<u8>;
+        fn get_root_epoch_info() -> Vec<u8>;
+        fn get_metagraph_at_epoch(netuid: u16, epochs_ago: u16) -> Vec<u8>;
+    }
+
+    pub trait EmissionInfoRuntimeApi {
+        fn get_emission_records_for_coldkey( coldkey_account_vec: Vec<u8>, from_block: u64, to_block: u64 ) -> Vec<u8>;
     }
 
     pub trait SubnetRegistrationRuntimeApi {
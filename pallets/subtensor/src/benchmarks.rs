@@ -6,6 +6,8 @@ use crate::Pallet as Subtensor;
 use crate::*;
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::assert_ok;
+use frame_support::traits::Get;
+use frame_support::BoundedVec;
 use frame_system::RawOrigin;
 pub use pallet::*;
 use sp_core::H256;
@@ -428,4 +430,29 @@ reveal_weights {
     let _ = Subtensor::<T>::commit_weights(<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(hotkey.clone())), netuid, commit_hash);
 
   }: reveal_weights(RawOrigin::Signed(hotkey.clone()), netuid, uids, weight_values, salt, version_key)
+
+  benchmark_rebalance_stake {
+    let n in 1 .. T::MaxRebalanceTargets::get();
+    let netuid: u16 = 1;
+    let tempo: u16 = 1;
+    let coldkey: T::AccountId = account("Test", 0, 1);
+
+    Subtensor::<T>::set_target_stakes_per_interval(100);
+    Subtensor::<T>::init_new_network(netuid, tempo);
+    Subtensor::<T>::set_network_registration_allowed( netuid, true );
+    Subtensor::<T>::set_max_allowed_uids( netuid, 4096 );
+    Subtensor::<T>::set_max_registrations_per_block( netuid, 4096 );
+    Subtensor::<T>::set_target_registrations_per_interval( netuid, 4096 );
+    Subtensor::<T>::set_burn(netuid, 1);
+
+    // Every hotkey is increased from zero, which is the most expensive path.
+    let mut targets: Vec<(T::AccountId, u64)> = vec![];
+    for seed in 0..n {
+      let hotkey: T::AccountId = account("Alice", 0, seed);
+      Subtensor::<T>::add_balance_to_coldkey_account(&coldkey.clone(), 1_000_000_000);
+      assert_ok!(Subtensor::<T>::do_burned_registration(RawOrigin::Signed(coldkey.clone()).into(), netuid, hotkey.clone()));
+      targets.push((hotkey, 100_000_000));
+    }
+    let targets: BoundedVec<(T::AccountId, u64), T::MaxRebalanceTargets> = targets.try_into().unwrap();
+  }: rebalance_stake(RawOrigin::Signed( coldkey.clone() ), targets)
 }
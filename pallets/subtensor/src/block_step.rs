@@ -1,14 +1,18 @@
 use super::*;
 use frame_support::storage::IterableStorageDoubleMap;
 use frame_support::storage::IterableStorageMap;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
 use substrate_fixed::types::I110F18;
 use substrate_fixed::types::I64F64;
 use substrate_fixed::types::I96F32;
 
 impl<T: Config> Pallet<T> {
-    /// Executes the necessary operations for each block.
-    pub fn block_step() -> Result<(), &'static str> {
+    /// Executes the necessary operations for each block. Returns the weight of the steps whose
+    /// work varies from block to block.
+    pub fn block_step() -> Result<Weight, &'static str> {
         let block_number: u64 = Self::get_current_block_as_u64();
+        let mut weight: Weight = Weight::zero();
         log::debug!("block_step for block: {:?} ", block_number);
         // --- 1. Adjust difficulties.
         Self::adjust_registration_terms_for_networks();
@@ -20,11 +24,17 @@ impl<T: Config> Pallet<T> {
             }
         }
         // --- 3. Drains emission tuples ( hotkey, amount ).
-        Self::drain_emission(block_number);
+        weight.saturating_accrue(Self::drain_emission(block_number));
         // --- 4. Generates emission tuples from epoch functions.
-        Self::generate_emission(block_number);
+        weight.saturating_accrue(Self::generate_emission(block_number));
+        // --- 5. Drop weight commits that were not revealed in time.
+        weight.saturating_accrue(Self::expire_weight_commits(block_number));
+        // --- 6. Apply timelocked weight commits whose round key has been published.
+        weight.saturating_accrue(Self::apply_timelocked_weight_commits(block_number));
+        // --- 7. Remove the subnets whose dissolve period has passed.
+        weight.saturating_accrue(Self::remove_dissolved_networks(block_number));
         // Return ok.
-        Ok(())
+        Ok(weight)
     }
 
     /// Helper function which returns the number of blocks remaining before we will run the epoch on this
@@ -79,38 +89,81 @@ impl<T: Config> Pallet<T> {
     }
 
     /// Reads from the loaded emission storage which contains lists of pending emission tuples ( hotkey, amount )
-    /// and distributes small chunks of them at a time.
+    /// and distributes small chunks of them at a time. Returns the weight of the emission ledger writes
+    /// and of the staking hotkey index checks.
     ///
-    pub fn drain_emission(_: u64) {
+    pub fn drain_emission(block_number: u64) -> Weight {
+        let mut weight: Weight = Weight::zero();
         // --- 1. We iterate across each network.
         for (netuid, _) in <Tempo<T> as IterableStorageMap<u16, u16>>::iter() {
+            // Emission queued for a dissolving subnet is dropped when it is removed.
+            if Self::is_network_dissolving(netuid) {
+                continue;
+            }
             let Some(tuples_to_drain) = Self::get_loaded_emission_tuples(netuid) else {
                 // There are no tuples to emit.
                 continue;
             };
             let mut total_emitted: u64 = 0;
             for (hotkey, server_amount, validator_amount) in tuples_to_drain.iter() {
-                Self::emit_inflation_through_hotkey_account(
+                let credited = Self::emit_inflation_through_hotkey_account(
                     hotkey,
                     *server_amount,
                     *validator_amount,
                 );
+                // The owner credit checks the staking hotkey index, nominators are already in it.
+                weight.saturating_accrue(T::DbWeight::get().reads(1));
+                // --- 2. Record what each coldkey received in the emission ledger.
+                for (
+                    coldkey,
+                    server_emission,
+                    validator_emission,
+                    delegate_take,
+                    nominator_share,
+                ) in credited
+                {
+                    if server_emission == 0
+                        && validator_emission == 0
+                        && delegate_take == 0
+                        && nominator_share == 0
+                    {
+                        continue;
+                    }
+                    weight.saturating_accrue(Self::append_emission_record(
+                        &coldkey,
+                        EmissionRecord {
+                            block: block_number,
+                            netuid,
+                            hotkey: hotkey.clone(),
+                            server_emission,
+                            validator_emission,
+                            delegate_take,
+                            nominator_share,
+                        },
+                    ));
+                }
                 total_emitted += *server_amount + *validator_amount;
             }
             LoadedEmission::<T>::remove(netuid);
             TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(total_emitted));
         }
+        weight
     }
 
     /// Iterates through networks queues more emission onto their pending storage.
     /// If a network has no blocks left until tempo, we run the epoch function and generate
     /// more token emission tuples for later draining onto accounts.
+    /// Returns the weight of the epoch snapshots.
     ///
-    pub fn generate_emission(block_number: u64) {
+    pub fn generate_emission(block_number: u64) -> Weight {
+        let mut weight: Weight = Weight::zero();
         // --- 1. Iterate across each network and add pending emission into stash.
         for (netuid, tempo) in <Tempo<T> as IterableStorageMap<u16, u16>>::iter() {
-            // Skip the root network or subnets with registrations turned off
-            if netuid == Self::get_root_netuid() || !Self::is_registration_allowed(netuid) {
+            // Skip the root network, dissolving subnets or subnets with registrations turned off
+            if netuid == Self::get_root_netuid()
+                || Self::is_network_dissolving(netuid)
+                || !Self::is_registration_allowed(netuid)
+            {
                 // Root emission or subnet emission is burned
                 continue;
             }
@@ -127,19 +180,42 @@ impl<T: Config> Pallet<T> {
             let subnet_has_owner = SubnetOwner::<T>::contains_key(netuid);
             let mut remaining = I96F32::from_num(new_queued_emission);
             if subnet_has_owner {
-                let cut = remaining
-                    .saturating_mul(I96F32::from_num(Self::get_subnet_owner_cut()))
-                    .saturating_div(I96F32::from_num(u16::MAX));
+                // --- 4. Split off the owner cut, the burn and the recipient shares.
+                let emission_split = Self::get_emission_split(netuid);
+                let share_of = |share: u16| {
+                    I96F32::from_num(new_queued_emission)
+                        .saturating_mul(I96F32::from_num(share))
+                        .saturating_div(I96F32::from_num(u16::MAX))
+                };
+
+                let mut cuts: Vec<(T::AccountId, I96F32)> = vec![(
+                    Self::get_subnet_owner(netuid),
+                    share_of(emission_split.owner_cut),
+                )];
+                cuts.extend(
+                    emission_split
+                        .recipients
+                        .into_iter()
+                        .map(|(recipient, share)| (recipient, share_of(share))),
+                );
+                for (recipient, cut) in cuts {
+                    remaining = remaining.saturating_sub(cut);
 
-                remaining = remaining.saturating_sub(cut);
+                    // Cuts too small to create the recipient account accumulate until they can.
+                    let owed: u64 = PendingEmissionSplitPayouts::<T>::get(&recipient)
+                        .saturating_add(cut.to_num::<u64>());
+                    if Self::try_add_balance_to_coldkey_account(&recipient, owed) {
+                        PendingEmissionSplitPayouts::<T>::remove(&recipient);
 
-                Self::add_balance_to_coldkey_account(
-                    &Self::get_subnet_owner(netuid),
-                    cut.to_num::<u64>(),
-                );
+                        // We are creating tokens here from the coinbase.
+                        Self::coinbase(owed);
+                    } else {
+                        PendingEmissionSplitPayouts::<T>::insert(&recipient, owed);
+                    }
+                }
 
-                // We are creating tokens here from the coinbase.
-                Self::coinbase(cut.to_num::<u64>());
+                // The burned share is never minted.
+                remaining = remaining.saturating_sub(share_of(emission_split.burn));
             }
             // --- 5. Add remaining amount to the network's pending emission.
             PendingEmission::<T>::mutate(netuid, |queued| *queued += remaining.to_num::<u64>());
@@ -165,8 +241,9 @@ impl<T: Config> Pallet<T> {
             PendingEmission::<T>::insert(netuid, 0);
 
             // --- 8. Run the epoch mechanism and return emission tuples for hotkeys in the network.
-            let emission_tuples_this_block: Vec<(T::AccountId, u64, u64)> =
-                Self::epoch(netuid, emission_to_drain);
+            let (emission_tuples_this_block, snapshot_weight) =
+                Self::run_epoch(netuid, emission_to_drain);
+            weight.saturating_accrue(snapshot_weight);
             log::debug!(
                 "netuid_i: {:?} emission_to_drain: {:?} ",
                 netuid,
@@ -195,21 +272,26 @@ impl<T: Config> Pallet<T> {
             Self::set_blocks_since_last_step(netuid, 0);
             Self::set_last_mechanism_step_block(netuid, block_number);
         }
+        weight
     }
     /// Distributes token inflation through the hotkey based on emission. The call ensures that the inflation
     /// is distributed onto the accounts in proportion of the stake delegated minus the take. This function
     /// is called after an epoch to distribute the newly minted stake according to delegation.
+    /// Returns what was credited to each coldkey as tuples
+    /// ( coldkey, server_emission, validator_emission, delegate_take, nominator_share ).
     ///
     pub fn emit_inflation_through_hotkey_account(
         hotkey: &T::AccountId,
         server_emission: u64,
         validator_emission: u64,
-    ) {
+    ) -> Vec<(T::AccountId, u64, u64, u64, u64)> {
+        let owner: T::AccountId = Self::get_owning_coldkey_for_hotkey(hotkey);
+
         // --- 1. Check if the hotkey is a delegate. If not, we simply pass the stake through to the
         // coldkey - hotkey account as normal.
         if !Self::hotkey_is_delegate(hotkey) {
             Self::increase_stake_on_hotkey_account(hotkey, server_emission + validator_emission);
-            return;
+            return vec![(owner, server_emission, validator_emission, 0, 0)];
         }
         // Then this is a delegate, we distribute validator_emission, then server_emission.
 
@@ -220,6 +302,7 @@ impl<T: Config> Pallet<T> {
             Self::calculate_delegate_proportional_take(hotkey, validator_emission);
         let validator_emission_minus_take: u64 = validator_emission - delegate_take;
         let mut remaining_validator_emission: u64 = validator_emission_minus_take;
+        let mut credited: Vec<(T::AccountId, u64, u64, u64, u64)> = Vec::new();
 
         // 3. -- The remaining emission goes to the owners in proportion to the stake delegated.
         for (owning_coldkey_i, stake_i) in
@@ -233,11 +316,7 @@ impl<T: Config> Pallet<T> {
                 total_hotkey_stake,
                 validator_emission_minus_take,
             );
-            Self::increase_stake_on_coldkey_hotkey_account(
-                &owning_coldkey_i,
-                hotkey,
-                stake_proportion,
-            );
+            Self::pay_nominator_reward(&owning_coldkey_i, hotkey, stake_proportion);
             log::debug!(
                 "owning_coldkey_i: {:?} hotkey: {:?} emission: +{:?} ",
                 owning_coldkey_i,
@@ -245,6 +324,7 @@ impl<T: Config> Pallet<T> {
                 stake_proportion
             );
             remaining_validator_emission -= stake_proportion;
+            credited.push((owning_coldkey_i, 0, 0, 0, stake_proportion));
         }
 
         // --- 5. Last increase final account balance of delegate after 4, since 5 will change the stake proportion of
@@ -258,6 +338,105 @@ impl<T: Config> Pallet<T> {
         // The server emission is distributed in-full to the delegate owner.
         // We do this after 4. for the same reason as above.
         Self::increase_stake_on_hotkey_account(hotkey, server_emission);
+
+        // --- 6. The owner is credited the server emission and the take, rounding remainders included.
+        let owner_take: u64 = delegate_take + remaining_validator_emission;
+        match credited.iter_mut().find(|(coldkey, ..)| *coldkey == owner) {
+            Some((_, owner_server_emission, _, owner_delegate_take, _)) => {
+                *owner_server_emission = server_emission;
+                *owner_delegate_take = owner_take;
+            }
+            None => credited.push((owner, server_emission, 0, owner_take, 0)),
+        }
+        credited
+    }
+
+    /// Appends a record to the emission ledger of a coldkey that keeps one, overwriting its oldest
+    /// record once the ledger holds MaxEmissionLedgerEntries records. Returns the weight used.
+    ///
+    pub fn append_emission_record(
+        coldkey: &T::AccountId,
+        record: EmissionRecord<T::AccountId>,
+    ) -> Weight {
+        let max_entries: u64 = T::MaxEmissionLedgerEntries::get() as u64;
+        if max_entries == 0 {
+            return Weight::zero();
+        }
+        if !EmissionLedgerEnabled::<T>::get(coldkey) {
+            return T::DbWeight::get().reads(1);
+        }
+        let written: u64 = EmissionLedgerLength::<T>::get(coldkey);
+        EmissionLedger::<T>::insert(coldkey, (written % max_entries) as u32, record);
+        EmissionLedgerLength::<T>::insert(coldkey, written.saturating_add(1));
+        T::DbWeight::get().reads_writes(2, 2)
+    }
+
+    /// Returns the emission records of a coldkey drained between from_block and to_block (inclusive),
+    /// oldest first.
+    ///
+    pub fn get_emission_records(
+        coldkey: &T::AccountId,
+        from_block: u64,
+        to_block: u64,
+    ) -> Vec<EmissionRecord<T::AccountId>> {
+        let mut records: Vec<EmissionRecord<T::AccountId>> =
+            EmissionLedger::<T>::iter_prefix_values(coldkey)
+                .filter(|record| from_block <= record.block && record.block <= to_block)
+                .collect();
+        records.sort_by_key(|record| record.block);
+        records
+    }
+
+    /// Sets whether the emission ledger of the calling coldkey is kept, removing its records when
+    /// it is disabled.
+    ///
+    pub fn do_set_emission_ledger_enabled(
+        origin: T::RuntimeOrigin,
+        enabled: bool,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the transaction is signed by the caller and retrieve the coldkey.
+        let coldkey = ensure_signed(origin)?;
+
+        // --- 2. Store the flag, or remove it together with the ledger.
+        if enabled {
+            EmissionLedgerEnabled::<T>::insert(&coldkey, true);
+        } else {
+            EmissionLedgerEnabled::<T>::remove(&coldkey);
+            let _ = EmissionLedger::<T>::clear_prefix(
+                &coldkey,
+                T::MaxEmissionLedgerEntries::get(),
+                None,
+            );
+            EmissionLedgerLength::<T>::remove(&coldkey);
+        }
+
+        // --- 3. Emit the event.
+        log::info!(
+            "EmissionLedgerEnabledSet( coldkey:{:?}, enabled:{:?} )",
+            coldkey,
+            enabled
+        );
+        Self::deposit_event(Event::EmissionLedgerEnabledSet(coldkey, enabled));
+
+        // --- 4. Ok and return.
+        Ok(())
+    }
+
+    /// Pays the reward of a nominator on a hotkey to the reward destination of the pairing.
+    /// A reward too small to create the destination account is compounded into the stake instead.
+    /// The nominator must already have a stake entry on the hotkey.
+    ///
+    pub fn pay_nominator_reward(coldkey: &T::AccountId, hotkey: &T::AccountId, reward: u64) {
+        let paid: bool = match RewardDestinations::<T>::get(coldkey, hotkey) {
+            RewardDestination::Compound => false,
+            RewardDestination::Free => Self::try_add_balance_to_coldkey_account(coldkey, reward),
+            RewardDestination::Account(account) => {
+                Self::try_add_balance_to_coldkey_account(&account, reward)
+            }
+        };
+        if !paid {
+            Self::increase_stake_on_staked_coldkey_hotkey_account(coldkey, hotkey, reward);
+        }
     }
 
     /// Increases the stake on the cold - hot pairing by increment while also incrementing other counters.
@@ -273,6 +452,7 @@ impl<T: Config> Pallet<T> {
             hotkey,
             TotalHotkeyStake::<T>::get(hotkey).saturating_add(increment),
         );
+        Self::record_staking_hotkey(coldkey, hotkey);
         Stake::<T>::insert(
             hotkey,
             coldkey,
@@ -0,0 +1,41 @@
+use super::*;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use sp_core::{hexdisplay::AsBytesRef, H256};
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct WeightCommitInfo {
+    commit_hash: H256,
+    commit_block: Compact<u64>,
+    reveal_start: Compact<u64>, // First block the commit can be revealed at.
+    reveal_end: Compact<u64>,   // First block the commit can no longer be revealed at.
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_pending_weight_commits(
+        netuid: u16,
+        hotkey_account_vec: Vec<u8>,
+    ) -> Vec<WeightCommitInfo> {
+        if hotkey_account_vec.len() != 32 {
+            return Vec::new(); // Invalid hotkey
+        }
+
+        let Ok(hotkey) = T::AccountId::decode(&mut hotkey_account_vec.as_bytes_ref()) else {
+            return Vec::new();
+        };
+
+        WeightCommits::<T>::get(netuid, &hotkey)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(commit_hash, commit_block)| {
+                let (reveal_start, reveal_end) = Self::get_reveal_block_range(netuid, commit_block);
+                WeightCommitInfo {
+                    commit_hash,
+                    commit_block: commit_block.into(),
+                    reveal_start: reveal_start.into(),
+                    reveal_end: reveal_end.into(),
+                }
+            })
+            .collect()
+    }
+}
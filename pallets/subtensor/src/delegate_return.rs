@@ -0,0 +1,217 @@
+use super::*;
+use frame_support::pallet_prelude::{Decode, Encode};
+use frame_support::traits::Get;
+extern crate alloc;
+use codec::Compact;
+use sp_core::hexdisplay::AsBytesRef;
+
+/// Milliseconds in a day.
+const MILLISECS_PER_DAY: u64 = 86_400_000;
+
+/// Epochs of each subnet the daily returns reported with the delegate info are averaged over.
+/// Bounds the snapshots read per delegate; longer projections go through
+/// `get_delegate_return_estimate`.
+pub const DELEGATE_INFO_RETURN_EPOCHS: u16 = 3;
+
+/// Projected daily return of a delegate on one subnet. Emissions are in rao per day.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct SubnetReturnEstimate {
+    netuid: Compact<u16>,
+    epochs: Compact<u16>, // Number of epochs the projection is averaged over.
+    epochs_per_day: Compact<u64>,
+    daily_emission: Compact<u64>, // Server and validator emission of the delegate.
+    daily_validator_emission: Compact<u64>,
+    daily_nominator_return: Compact<u64>, // Validator emission minus the delegate take.
+    return_per_1000: Compact<u64>,        // Nominator return per 1000 staked.
+}
+
+/// Projected daily return of a delegate on every subnet it is registered on, and in aggregate.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct DelegateReturnEstimate<T: Config> {
+    delegate: T::AccountId,
+    take: Compact<u16>,
+    total_stake: Compact<u64>,
+    blocks_per_day: Compact<u64>,
+    subnets: Vec<SubnetReturnEstimate>,
+    daily_emission: Compact<u64>,
+    daily_nominator_return: Compact<u64>,
+    return_per_1000: Compact<u64>,
+}
+
+/// Per-epoch emission of a uid, split into its server and validator parts.
+struct EpochEmission {
+    emission: u64,
+    validator_emission: u64,
+}
+
+impl EpochEmission {
+    /// The epoch normalizes server emission (incentive) and validator emission (dividends) on
+    /// their common sum, so the validator part of the emission is its dividends share.
+    fn new(emission: u64, incentive: u16, dividends: u16) -> Self {
+        let shares: u128 = (incentive as u128).saturating_add(dividends as u128);
+        let validator_emission: u64 = if shares == 0 {
+            0
+        } else {
+            ((emission as u128).saturating_mul(dividends as u128) / shares) as u64
+        };
+        Self {
+            emission,
+            validator_emission,
+        }
+    }
+}
+
+/// Daily amount of a per-epoch amount on a subnet whose epoch runs once every tempo + 1 blocks.
+/// Not rounded to whole epochs per day, which would drop up to an epoch of a long tempo.
+fn per_day(epoch_amount: u64, blocks_per_day: u64, tempo: u16) -> u64 {
+    let daily: u128 =
+        (epoch_amount as u128).saturating_mul(blocks_per_day as u128) / (tempo as u128 + 1);
+    daily.min(u64::MAX as u128) as u64
+}
+
+/// Return per 1000 staked of a daily amount.
+fn per_1000(daily: u64, total_stake: u64) -> u64 {
+    if total_stake == 0 {
+        return 0;
+    }
+    ((daily as u128).saturating_mul(1000) / total_stake as u128) as u64
+}
+
+impl<T: Config> Pallet<T> {
+    /// Blocks produced per day at the expected block time of the runtime.
+    pub fn get_blocks_per_day() -> u64 {
+        MILLISECS_PER_DAY / T::ExpectedBlockTime::get().max(1)
+    }
+
+    /// Emissions of the uid of a hotkey over the last epochs of a subnet, newest first.
+    /// Uses the retained epoch snapshots of the subnet, at most `epochs` of them, and falls back
+    /// to the latest epoch in storage when none of them holds the hotkey.
+    fn get_recent_epoch_emissions(
+        netuid: u16,
+        uid: u16,
+        hotkey: &T::AccountId,
+        epochs: u16,
+    ) -> Vec<EpochEmission> {
+        let retained: u16 = Self::get_epoch_snapshot_retention(netuid).min(epochs);
+        let from_snapshots: Vec<EpochEmission> = (0..retained)
+            .map_while(|epochs_ago| Self::get_epoch_snapshot(netuid, epochs_ago))
+            .filter_map(|snapshot| {
+                // The uid may have been held by another hotkey at the time.
+                let index = snapshot
+                    .hotkeys
+                    .iter()
+                    .position(|key| key.as_ref() == Some(hotkey))?;
+                Some(EpochEmission::new(
+                    snapshot.emission.get(index).copied().unwrap_or(0),
+                    snapshot.incentive.get(index).copied().unwrap_or(0),
+                    snapshot.dividends.get(index).copied().unwrap_or(0),
+                ))
+            })
+            .collect();
+        if !from_snapshots.is_empty() || epochs == 0 {
+            return from_snapshots;
+        }
+        vec![EpochEmission::new(
+            Self::get_emission_for_uid(netuid, uid),
+            Self::get_incentive_for_uid(netuid, uid),
+            Self::get_dividends_for_uid(netuid, uid),
+        )]
+    }
+
+    /// Projects the daily return of a delegate from the last `epochs` epochs of each subnet it
+    /// is registered on. Epoch emissions are already net of the emission split of the subnet
+    /// (owner cut, burn and recipients); the delegate take is applied to the validator part.
+    pub fn estimate_delegate_return(
+        delegate: &T::AccountId,
+        epochs: u16,
+    ) -> DelegateReturnEstimate<T> {
+        let take: u16 = Delegates::<T>::get(delegate);
+        let total_stake: u64 = Self::get_total_stake_for_hotkey(delegate);
+        let blocks_per_day: u64 = Self::get_blocks_per_day();
+
+        let mut subnets: Vec<SubnetReturnEstimate> = Vec::new();
+        let mut daily_emission: u64 = 0;
+        let mut daily_nominator_return: u64 = 0;
+        for netuid in Self::get_registered_networks_for_hotkey(delegate) {
+            let Ok(uid) = Self::get_uid_for_net_and_hotkey(netuid, delegate) else {
+                continue;
+            };
+            let emissions = Self::get_recent_epoch_emissions(netuid, uid, delegate, epochs);
+            let count: u64 = emissions.len() as u64;
+            let (epoch_emission, epoch_validator_emission) = if count == 0 {
+                (0, 0)
+            } else {
+                let (emission, validator_emission) =
+                    emissions
+                        .iter()
+                        .fold((0u64, 0u64), |(emission, validator_emission), epoch| {
+                            (
+                                emission.saturating_add(epoch.emission),
+                                validator_emission.saturating_add(epoch.validator_emission),
+                            )
+                        });
+                (emission / count, validator_emission / count)
+            };
+
+            // The epoch of a subnet runs once every tempo + 1 blocks.
+            let tempo: u16 = Self::get_tempo(netuid);
+            let epochs_per_day: u64 = blocks_per_day / (tempo as u64).saturating_add(1);
+            let subnet_daily_emission: u64 = per_day(epoch_emission, blocks_per_day, tempo);
+            let subnet_daily_validator_emission: u64 =
+                per_day(epoch_validator_emission, blocks_per_day, tempo);
+            let subnet_daily_nominator_return: u64 = subnet_daily_validator_emission
+                .saturating_sub(Self::calculate_delegate_proportional_take(
+                    delegate,
+                    subnet_daily_validator_emission,
+                ));
+
+            daily_emission = daily_emission.saturating_add(subnet_daily_emission);
+            daily_nominator_return =
+                daily_nominator_return.saturating_add(subnet_daily_nominator_return);
+            subnets.push(SubnetReturnEstimate {
+                netuid: netuid.into(),
+                epochs: (count as u16).into(),
+                epochs_per_day: epochs_per_day.into(),
+                daily_emission: subnet_daily_emission.into(),
+                daily_validator_emission: subnet_daily_validator_emission.into(),
+                daily_nominator_return: subnet_daily_nominator_return.into(),
+                return_per_1000: per_1000(subnet_daily_nominator_return, total_stake).into(),
+            });
+        }
+
+        DelegateReturnEstimate {
+            delegate: delegate.clone(),
+            take: take.into(),
+            total_stake: total_stake.into(),
+            blocks_per_day: blocks_per_day.into(),
+            subnets,
+            daily_emission: daily_emission.into(),
+            daily_nominator_return: daily_nominator_return.into(),
+            return_per_1000: per_1000(daily_nominator_return, total_stake).into(),
+        }
+    }
+
+    /// Returns the daily emission and the nominator return per 1000 staked of a delegate,
+    /// projected over the last `DELEGATE_INFO_RETURN_EPOCHS` epochs of each subnet.
+    pub fn get_delegate_daily_returns(delegate: &T::AccountId) -> (u64, u64) {
+        let estimate = Self::estimate_delegate_return(delegate, DELEGATE_INFO_RETURN_EPOCHS);
+        (estimate.daily_emission.0, estimate.return_per_1000.0)
+    }
+
+    pub fn get_delegate_return_estimate(
+        delegate_account_vec: Vec<u8>,
+        epochs: u16,
+    ) -> Option<DelegateReturnEstimate<T>> {
+        if delegate_account_vec.len() != 32 {
+            return None;
+        }
+
+        let delegate: T::AccountId =
+            T::AccountId::decode(&mut delegate_account_vec.as_bytes_ref()).ok()?;
+        if !Delegates::<T>::contains_key(&delegate) {
+            return None;
+        }
+
+        Some(Self::estimate_delegate_return(&delegate, epochs))
+    }
+}
@@ -0,0 +1,46 @@
+use super::*;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use sp_core::hexdisplay::AsBytesRef;
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct EmissionRecordInfo<T: Config> {
+    coldkey: T::AccountId,
+    hotkey: T::AccountId,
+    netuid: Compact<u16>,
+    block: Compact<u64>,
+    server_emission: Compact<u64>,
+    validator_emission: Compact<u64>,
+    delegate_take: Compact<u64>,
+    nominator_share: Compact<u64>,
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_emission_records_for_coldkey(
+        coldkey_account_vec: Vec<u8>,
+        from_block: u64,
+        to_block: u64,
+    ) -> Vec<EmissionRecordInfo<T>> {
+        if coldkey_account_vec.len() != 32 {
+            return Vec::new(); // Invalid coldkey
+        }
+
+        let Ok(coldkey) = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()) else {
+            return Vec::new();
+        };
+
+        Self::get_emission_records(&coldkey, from_block, to_block)
+            .into_iter()
+            .map(|record| EmissionRecordInfo {
+                coldkey: coldkey.clone(),
+                hotkey: record.hotkey,
+                netuid: record.netuid.into(),
+                block: record.block.into(),
+                server_emission: record.server_emission.into(),
+                validator_emission: record.validator_emission.into(),
+                delegate_take: record.delegate_take.into(),
+                nominator_share: record.nominator_share.into(),
+            })
+            .collect()
+    }
+}
@@ -1,14 +1,143 @@
 use super::*;
 use crate::math::*;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
 use frame_support::IterableStorageDoubleMap;
 use sp_std::vec;
 use substrate_fixed::types::{I32F32, I64F64, I96F32};
 
+/// Terms of an epoch, computed before anything is written to storage.
+pub(crate) struct EpochTerms<AccountId> {
+    pub n: u16,
+    pub hotkeys: Vec<(u16, AccountId)>,
+    pub active: Vec<bool>,
+    pub stake: Vec<I32F32>,
+    pub validator_permits: Vec<bool>,
+    pub new_validator_permits: Vec<bool>,
+    pub active_stake: Vec<I32F32>,
+    pub preranks: Vec<I32F32>,
+    pub consensus: Vec<I32F32>,
+    pub weights: Vec<Vec<(u16, I32F32)>>, // Weights clipped at consensus.
+    pub validator_trust: Vec<I32F32>,
+    pub ranks: Vec<I32F32>,
+    pub trust: Vec<I32F32>,
+    pub incentive: Vec<I32F32>,
+    pub bonds_delta: Vec<Vec<(u16, I32F32)>>,
+    pub ema_bonds: Vec<Vec<(u16, I32F32)>>,
+    pub dividends: Vec<I32F32>,
+    pub server_emission: Vec<u64>,
+    pub validator_emission: Vec<u64>,
+    pub combined_emission: Vec<u64>,
+    pub pruning_scores: Vec<I32F32>,
+}
+
+/// Converts a dense matrix to sparse rows holding every non-zero column.
+fn dense_to_sparse(matrix: &[Vec<I32F32>]) -> Vec<Vec<(u16, I32F32)>> {
+    let zero: I32F32 = I32F32::from_num(0);
+    matrix
+        .iter()
+        .map(|row| {
+            (0..)
+                .zip(row.iter().copied())
+                .filter(|(_, value)| *value != zero)
+                .collect()
+        })
+        .collect()
+}
+
 impl<T: Config> Pallet<T> {
+    /// Runs the epoch of a network with the dense implementation when its weights density
+    /// reaches the dense epoch threshold, and with the sparse implementation otherwise.
+    /// Both implementations produce the same results, which are then snapshotted if the network
+    /// retains epoch snapshots. Returns the emission tuples and the weight of the snapshot.
+    pub fn run_epoch(netuid: u16, rao_emission: u64) -> (Vec<(T::AccountId, u64, u64)>, Weight) {
+        let emission_tuples = if Self::runs_dense_epoch(netuid) {
+            Self::epoch_dense(netuid, rao_emission)
+        } else {
+            Self::epoch(netuid, rao_emission)
+        };
+        let snapshot_weight = Self::record_epoch_snapshot(netuid);
+        (emission_tuples, snapshot_weight)
+    }
+
+    /// Returns true if the epoch of a network runs with the dense implementation, that is when its
+    /// weights density reaches the dense epoch threshold.
+    pub fn runs_dense_epoch(netuid: u16) -> bool {
+        Self::get_weights_density(netuid) >= Self::get_dense_epoch_threshold()
+    }
+
+    /// Appends the metagraph stored by the latest epoch of a network to its snapshots, overwriting
+    /// the oldest snapshot once the network holds EpochSnapshotRetention snapshots.
+    /// Returns the weight used.
+    pub fn record_epoch_snapshot(netuid: u16) -> Weight {
+        let retention: u64 = Self::get_epoch_snapshot_retention(netuid) as u64;
+        if retention == 0 {
+            return T::DbWeight::get().reads(1);
+        }
+        let n: u16 = Self::get_subnetwork_n(netuid);
+        let hotkeys: Vec<Option<T::AccountId>> = (0..n)
+            .map(|uid| Keys::<T>::try_get(netuid, uid).ok())
+            .collect();
+        let snapshot = EpochSnapshot {
+            block: Self::get_current_block_as_u64(),
+            stake: hotkeys
+                .iter()
+                .map(|hotkey| hotkey.as_ref().map_or(0, Self::get_total_stake_for_hotkey))
+                .collect(),
+            hotkeys,
+            rank: Rank::<T>::get(netuid),
+            trust: Trust::<T>::get(netuid),
+            consensus: Consensus::<T>::get(netuid),
+            validator_trust: ValidatorTrust::<T>::get(netuid),
+            incentive: Incentive::<T>::get(netuid),
+            dividends: Dividends::<T>::get(netuid),
+            emission: Emission::<T>::get(netuid),
+            validator_permit: ValidatorPermit::<T>::get(netuid),
+        };
+        let taken: u64 = EpochSnapshotsTaken::<T>::get(netuid);
+        EpochSnapshots::<T>::insert(netuid, (taken % retention) as u16, snapshot);
+        EpochSnapshotsTaken::<T>::insert(netuid, taken.saturating_add(1));
+
+        // The key and total stake of every uid, the eight metagraph vectors, the retention, the
+        // network size and the number of snapshots taken.
+        T::DbWeight::get().reads_writes((n as u64).saturating_mul(2).saturating_add(11), 2)
+    }
+
+    /// Returns the snapshot of the epoch of a network that ran epochs_ago epochs before its latest
+    /// one, None if it is no longer retained.
+    pub fn get_epoch_snapshot(netuid: u16, epochs_ago: u16) -> Option<EpochSnapshot<T::AccountId>> {
+        let retention: u64 = Self::get_epoch_snapshot_retention(netuid) as u64;
+        let taken: u64 = EpochSnapshotsTaken::<T>::get(netuid);
+        if epochs_ago as u64 >= taken.min(retention) {
+            return None;
+        }
+        let slot: u64 = (taken - 1 - epochs_ago as u64) % retention;
+        EpochSnapshots::<T>::get(netuid, slot as u16)
+    }
+
+    /// Returns the share of set entries in the weight matrix of a network as a u16 proportion.
+    /// Only the lengths of the weight rows are read, not the weights themselves.
+    pub fn get_weights_density(netuid: u16) -> u16 {
+        let n: u64 = Self::get_subnetwork_n(netuid) as u64;
+        if n == 0 {
+            return 0;
+        }
+        let entries: u64 = (0..n as u16)
+            .map(|uid_i| Weights::<T>::decode_len(netuid, uid_i).unwrap_or(0) as u64)
+            .sum();
+        (entries.saturating_mul(u16::MAX as u64) / n.saturating_mul(n)).min(u16::MAX as u64) as u16
+    }
+
     /// Calculates reward consensus and returns the emissions for uids/hotkeys in a given `netuid`.
-    /// (Dense version used only for testing purposes.)
-    #[allow(clippy::indexing_slicing)]
+    /// (Dense version, run for networks with dense weights.)
     pub fn epoch_dense(netuid: u16, rao_emission: u64) -> Vec<(T::AccountId, u64, u64)> {
+        Self::store_epoch_terms(netuid, Self::epoch_dense_terms(netuid, rao_emission))
+    }
+
+    /// Computes every term of the dense epoch of a network without writing to storage.
+    /// The matrices are returned as sparse rows holding every column.
+    #[allow(clippy::indexing_slicing)]
+    pub(crate) fn epoch_dense_terms(netuid: u16, rao_emission: u64) -> EpochTerms<T::AccountId> {
         // Get subnetwork size.
         let n: u16 = Self::get_subnetwork_n(netuid);
         log::trace!("n:\n{:?}\n", n);
@@ -142,6 +271,15 @@ impl<T: Config> Pallet<T> {
         // Clip weights at majority consensus
         let kappa: I32F32 = Self::get_float_kappa(netuid); // consensus majority ratio, e.g. 51%.
         let consensus: Vec<I32F32> = weighted_median_col(&active_stake, &weights, kappa);
+
+        // Compute liquid alpha per (validator, server) pair from the weights before clipping.
+        let liquid_alphas: Option<Vec<Vec<I32F32>>> =
+            Self::get_liquid_alpha_enabled(netuid).then(|| {
+                let (alpha_low, alpha_high, steepness) =
+                    Self::get_float_liquid_alpha_params(netuid);
+                mat_liquid_alpha(&weights, &consensus, alpha_low, alpha_high, steepness)
+            });
+
         inplace_col_clip(&mut weights, &consensus);
         let validator_trust: Vec<I32F32> = row_sum(&weights);
 
@@ -178,7 +316,10 @@ impl<T: Config> Pallet<T> {
         let bonds_moving_average: I64F64 =
             I64F64::from_num(Self::get_bonds_moving_average(netuid)) / I64F64::from_num(1_000_000);
         let alpha: I32F32 = I32F32::from_num(1) - I32F32::from_num(bonds_moving_average);
-        let mut ema_bonds: Vec<Vec<I32F32>> = mat_ema(&bonds_delta, &bonds, alpha);
+        let mut ema_bonds: Vec<Vec<I32F32>> = match liquid_alphas {
+            Some(liquid_alphas) => mat_ema_alpha(&bonds_delta, &bonds, &liquid_alphas),
+            None => mat_ema(&bonds_delta, &bonds, alpha),
+        };
         inplace_col_normalize(&mut ema_bonds); // sum_i b_ij = 1
                                                // log::trace!( "emaB:\n{:?}\n", &ema_bonds );
 
@@ -265,6 +406,74 @@ impl<T: Config> Pallet<T> {
         let pruning_scores: Vec<I32F32> = normalized_combined_emission.clone();
         log::trace!("P: {:?}", &pruning_scores);
 
+        EpochTerms {
+            n,
+            hotkeys,
+            active,
+            stake,
+            validator_permits,
+            new_validator_permits,
+            active_stake,
+            preranks,
+            consensus,
+            weights: dense_to_sparse(&weights),
+            validator_trust,
+            ranks,
+            trust,
+            incentive,
+            bonds_delta: dense_to_sparse(&bonds_delta),
+            ema_bonds: dense_to_sparse(&ema_bonds),
+            dividends,
+            server_emission,
+            validator_emission,
+            combined_emission,
+            pruning_scores,
+        }
+    }
+
+    /// Calculates reward consensus values, then updates rank, trust, consensus, incentive, dividend, pruning_score, emission and bonds, and
+    /// returns the emissions for uids/hotkeys in a given `netuid`.
+    ///
+    /// # Args:
+    ///  * 'netuid': ( u16 ):
+    ///     - The network to distribute the emission onto.
+    ///
+    ///  * 'rao_emission': ( u64 ):
+    ///     - The total emission for the epoch.
+    ///
+    ///  * 'debug' ( bool ):
+    ///     - Print debugging outputs.
+    ///
+    pub fn epoch(netuid: u16, rao_emission: u64) -> Vec<(T::AccountId, u64, u64)> {
+        Self::store_epoch_terms(netuid, Self::epoch_terms(netuid, rao_emission))
+    }
+
+    /// Stores the terms of an epoch of a network and returns its emission tuples.
+    #[allow(clippy::indexing_slicing)]
+    fn store_epoch_terms(
+        netuid: u16,
+        terms: EpochTerms<T::AccountId>,
+    ) -> Vec<(T::AccountId, u64, u64)> {
+        let EpochTerms {
+            n,
+            hotkeys,
+            active,
+            validator_permits,
+            new_validator_permits,
+            consensus,
+            validator_trust,
+            ranks,
+            trust,
+            incentive,
+            mut ema_bonds,
+            dividends,
+            server_emission,
+            validator_emission,
+            combined_emission,
+            pruning_scores,
+            ..
+        } = terms;
+
         // ===================
         // == Value storage ==
         // ===================
@@ -306,7 +515,7 @@ impl<T: Config> Pallet<T> {
         ValidatorPermit::<T>::insert(netuid, new_validator_permits.clone());
 
         // Column max-upscale EMA bonds for storage: max_i w_ij = 1.
-        inplace_col_max_upscale(&mut ema_bonds);
+        inplace_col_max_upscale_sparse(&mut ema_bonds, n);
         new_validator_permits
             .iter()
             .zip(validator_permits)
@@ -315,8 +524,9 @@ impl<T: Config> Pallet<T> {
             .for_each(|(i, ((new_permit, validator_permit), ema_bond))| {
                 // Set bonds only if uid retains validator permit, otherwise clear bonds.
                 if *new_permit {
-                    let new_bonds_row: Vec<(u16, u16)> = (0..n)
-                        .zip(vec_fixed_proportions_to_u16(ema_bond.clone()))
+                    let new_bonds_row: Vec<(u16, u16)> = ema_bond
+                        .iter()
+                        .map(|(j, value)| (*j, fixed_proportion_to_u16(*value)))
                         .collect();
                     Bonds::<T>::insert(netuid, i as u16, new_bonds_row);
                 } else if validator_permit {
@@ -326,6 +536,7 @@ impl<T: Config> Pallet<T> {
                 }
             });
 
+        // Emission tuples ( hotkeys, server_emission, validator_emission )
         hotkeys
             .into_iter()
             .map(|(uid_i, hotkey)| {
@@ -338,21 +549,10 @@ impl<T: Config> Pallet<T> {
             .collect()
     }
 
-    /// Calculates reward consensus values, then updates rank, trust, consensus, incentive, dividend, pruning_score, emission and bonds, and
-    /// returns the emissions for uids/hotkeys in a given `netuid`.
-    ///
-    /// # Args:
-    ///  * 'netuid': ( u16 ):
-    ///     - The network to distribute the emission onto.
-    ///
-    ///  * 'rao_emission': ( u64 ):
-    ///     - The total emission for the epoch.
-    ///
-    ///  * 'debug' ( bool ):
-    ///     - Print debugging outputs.
-    ///
+    /// Computes every term of the (sparse) epoch of a network without writing to storage.
+    /// The epoch stores the resulting values, the epoch dry run reports them.
     #[allow(clippy::indexing_slicing)]
-    pub fn epoch(netuid: u16, rao_emission: u64) -> Vec<(T::AccountId, u64, u64)> {
+    pub(crate) fn epoch_terms(netuid: u16, rao_emission: u64) -> EpochTerms<T::AccountId> {
         // Get subnetwork size.
         let n: u16 = Self::get_subnetwork_n(netuid);
         log::trace!("n: {:?}", n);
@@ -483,6 +683,14 @@ impl<T: Config> Pallet<T> {
         let consensus: Vec<I32F32> = weighted_median_col_sparse(&active_stake, &weights, n, kappa);
         log::trace!("C: {:?}", &consensus);
 
+        // Compute liquid alpha per (validator, server) pair from the weights before clipping.
+        let liquid_alphas: Option<Vec<Vec<(u16, I32F32)>>> = Self::get_liquid_alpha_enabled(netuid)
+            .then(|| {
+                let (alpha_low, alpha_high, steepness) =
+                    Self::get_float_liquid_alpha_params(netuid);
+                mat_liquid_alpha_sparse(&weights, &consensus, alpha_low, alpha_high, steepness)
+            });
+
         weights = col_clip_sparse(&weights, &consensus);
         // log::trace!( "W: {:?}", &weights );
 
@@ -538,7 +746,18 @@ impl<T: Config> Pallet<T> {
         let bonds_moving_average: I64F64 =
             I64F64::from_num(Self::get_bonds_moving_average(netuid)) / I64F64::from_num(1_000_000);
         let alpha: I32F32 = I32F32::from_num(1) - I32F32::from_num(bonds_moving_average);
-        let mut ema_bonds: Vec<Vec<(u16, I32F32)>> = mat_ema_sparse(&bonds_delta, &bonds, alpha);
+        let mut ema_bonds: Vec<Vec<(u16, I32F32)>> = match liquid_alphas {
+            Some(liquid_alphas) => {
+                // Pairs without a weight get the liquid alpha of a zero weight.
+                let (alpha_low, alpha_high, steepness) =
+                    Self::get_float_liquid_alpha_params(netuid);
+                let zero: I32F32 = I32F32::from_num(0);
+                let default_alpha: I32F32 =
+                    liquid_alpha(zero, zero, alpha_low, alpha_high, steepness);
+                mat_ema_alpha_sparse(&bonds_delta, &bonds, &liquid_alphas, default_alpha)
+            }
+            None => mat_ema_sparse(&bonds_delta, &bonds, alpha),
+        };
 
         // Normalize EMA bonds.
         inplace_col_normalize_sparse(&mut ema_bonds, n); // sum_i b_ij = 1
@@ -625,79 +844,29 @@ impl<T: Config> Pallet<T> {
         let pruning_scores: Vec<I32F32> = normalized_combined_emission.clone();
         log::trace!("P: {:?}", &pruning_scores);
 
-        // ===================
-        // == Value storage ==
-        // ===================
-        let cloned_emission: Vec<u64> = combined_emission.clone();
-        let cloned_ranks: Vec<u16> = ranks
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_trust: Vec<u16> = trust
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_consensus: Vec<u16> = consensus
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_incentive: Vec<u16> = incentive
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_dividends: Vec<u16> = dividends
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        let cloned_pruning_scores: Vec<u16> = vec_max_upscale_to_u16(&pruning_scores);
-        let cloned_validator_trust: Vec<u16> = validator_trust
-            .iter()
-            .map(|xi| fixed_proportion_to_u16(*xi))
-            .collect::<Vec<u16>>();
-        Active::<T>::insert(netuid, active.clone());
-        Emission::<T>::insert(netuid, cloned_emission);
-        Rank::<T>::insert(netuid, cloned_ranks);
-        Trust::<T>::insert(netuid, cloned_trust);
-        Consensus::<T>::insert(netuid, cloned_consensus);
-        Incentive::<T>::insert(netuid, cloned_incentive);
-        Dividends::<T>::insert(netuid, cloned_dividends);
-        PruningScores::<T>::insert(netuid, cloned_pruning_scores);
-        ValidatorTrust::<T>::insert(netuid, cloned_validator_trust);
-        ValidatorPermit::<T>::insert(netuid, new_validator_permits.clone());
-
-        // Column max-upscale EMA bonds for storage: max_i w_ij = 1.
-        inplace_col_max_upscale_sparse(&mut ema_bonds, n);
-        new_validator_permits
-            .iter()
-            .zip(validator_permits)
-            .zip(ema_bonds)
-            .enumerate()
-            .for_each(|(i, ((new_permit, validator_permit), ema_bond))| {
-                // Set bonds only if uid retains validator permit, otherwise clear bonds.
-                if *new_permit {
-                    let new_bonds_row: Vec<(u16, u16)> = ema_bond
-                        .iter()
-                        .map(|(j, value)| (*j, fixed_proportion_to_u16(*value)))
-                        .collect();
-                    Bonds::<T>::insert(netuid, i as u16, new_bonds_row);
-                } else if validator_permit {
-                    // Only overwrite the intersection.
-                    let new_empty_bonds_row: Vec<(u16, u16)> = vec![];
-                    Bonds::<T>::insert(netuid, i as u16, new_empty_bonds_row);
-                }
-            });
-
-        // Emission tuples ( hotkeys, server_emission, validator_emission )
-        hotkeys
-            .into_iter()
-            .map(|(uid_i, hotkey)| {
-                (
-                    hotkey,
-                    server_emission[uid_i as usize],
-                    validator_emission[uid_i as usize],
-                )
-            })
-            .collect()
+        EpochTerms {
+            n,
+            hotkeys,
+            active,
+            stake,
+            validator_permits,
+            new_validator_permits,
+            active_stake,
+            preranks,
+            consensus,
+            weights,
+            validator_trust,
+            ranks,
+            trust,
+            incentive,
+            bonds_delta,
+            ema_bonds,
+            dividends,
+            server_emission,
+            validator_emission,
+            combined_emission,
+            pruning_scores,
+        }
     }
 
     pub fn get_float_rho(netuid: u16) -> I32F32 {
@@ -706,6 +875,15 @@ impl<T: Config> Pallet<T> {
     pub fn get_float_kappa(netuid: u16) -> I32F32 {
         I32F32::from_num(Self::get_kappa(netuid)) / I32F32::from_num(u16::MAX)
     }
+    /// Returns the liquid alpha parameters ( alpha_low, alpha_high, steepness ) of a network.
+    pub fn get_float_liquid_alpha_params(netuid: u16) -> (I32F32, I32F32, I32F32) {
+        let (alpha_low, alpha_high) = Self::get_alpha_values(netuid);
+        (
+            u16_proportion_to_fixed(alpha_low),
+            u16_proportion_to_fixed(alpha_high),
+            I32F32::from_num(Self::get_alpha_sigmoid_steepness(netuid)),
+        )
+    }
 
     pub fn get_normalized_stake(netuid: u16) -> Vec<I32F32> {
         let n = Self::get_subnetwork_n(netuid);
@@ -719,16 +897,11 @@ impl<T: Config> Pallet<T> {
         stake
     }
 
+    /// Returns the block at registration of every uid, a freed uid has the block it was freed at.
     pub fn get_block_at_registration(netuid: u16) -> Vec<u64> {
         let n = Self::get_subnetwork_n(netuid);
         let block_at_registration: Vec<u64> = (0..n)
-            .map(|neuron_uid| {
-                if Keys::<T>::contains_key(netuid, neuron_uid) {
-                    Self::get_neuron_block_at_registration(netuid, neuron_uid)
-                } else {
-                    0
-                }
-            })
+            .map(|neuron_uid| Self::get_neuron_block_at_registration(netuid, neuron_uid))
             .collect();
         block_at_registration
     }
@@ -0,0 +1,224 @@
+use super::*;
+use crate::epoch::EpochTerms;
+use crate::math::*;
+use crate::root::RootEpochTerms;
+use codec::Compact;
+use frame_support::pallet_prelude::{Decode, Encode};
+use substrate_fixed::types::{I32F32, I64F64};
+
+/// Every intermediate term of an epoch, indexed by uid. Proportions are u16-normalized
+/// (u16::MAX == 1.0) as they are in storage, emissions are in rao.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct EpochInfo<T: Config> {
+    netuid: Compact<u16>,
+    rao_emission: Compact<u64>,
+    hotkeys: Vec<(Compact<u16>, T::AccountId)>, // Vec of (uid, hotkey)
+    active: Vec<bool>,
+    stake: Vec<Compact<u16>>,
+    validator_permits: Vec<bool>, // Validator permits after this epoch.
+    active_stake: Vec<Compact<u16>>,
+    preranks: Vec<Compact<u16>>,
+    consensus: Vec<Compact<u16>>,
+    clipped_weights: Vec<Vec<(Compact<u16>, Compact<u16>)>>, // Row-normalized, clipped at consensus.
+    validator_trust: Vec<Compact<u16>>,
+    ranks: Vec<Compact<u16>>,
+    trust: Vec<Compact<u16>>,
+    incentive: Vec<Compact<u16>>,
+    bonds_delta: Vec<Vec<(Compact<u16>, Compact<u16>)>>, // Column-normalized.
+    ema_bonds: Vec<Vec<(Compact<u16>, Compact<u16>)>>,   // Column-normalized, before max-upscaling.
+    dividends: Vec<Compact<u16>>,
+    server_emission: Vec<Compact<u64>>,
+    validator_emission: Vec<Compact<u64>>,
+    pruning_scores: Vec<Compact<u16>>, // Max-upscaled, as stored.
+}
+
+/// Every term of the root epoch for the current state. Rows of the weight matrix are indexed
+/// by root validator, columns by subnet in `netuids` order. Emissions are in rao per block.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct RootEpochInfo<T: Config> {
+    block_emission: Compact<u64>,
+    netuids: Vec<Compact<u16>>,
+    hotkeys: Vec<(Compact<u16>, T::AccountId)>, // Vec of (uid, hotkey)
+    stake: Vec<Compact<u16>>,
+    weights: Vec<Vec<Compact<u16>>>, // Row-normalized.
+    ranks: Vec<Compact<u16>>,
+    trust: Vec<Compact<u16>>,
+    consensus: Vec<Compact<u16>>,
+    emission: Vec<Compact<u64>>,
+}
+
+/// The metagraph of a subnet as stored by a past epoch, indexed by uid. Proportions are
+/// u16-normalized (u16::MAX == 1.0), emissions are in rao.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct MetagraphSnapshotInfo<T: Config> {
+    netuid: Compact<u16>,
+    block: Compact<u64>,
+    hotkeys: Vec<Option<T::AccountId>>, // None for a freed uid
+    stake: Vec<Compact<u64>>,
+    ranks: Vec<Compact<u16>>,
+    trust: Vec<Compact<u16>>,
+    consensus: Vec<Compact<u16>>,
+    validator_trust: Vec<Compact<u16>>,
+    incentive: Vec<Compact<u16>>,
+    dividends: Vec<Compact<u16>>,
+    emission: Vec<Compact<u64>>,
+    validator_permits: Vec<bool>,
+}
+
+fn compact<V: Copy>(vec: Vec<V>) -> Vec<Compact<V>> {
+    vec.into_iter().map(Compact).collect()
+}
+
+fn proportions_64(vec: &[I64F64]) -> Vec<Compact<u16>> {
+    vec.iter()
+        .map(|x| fixed_proportion_to_u16(fixed64_to_fixed32(*x)).into())
+        .collect()
+}
+
+fn proportions(vec: &[I32F32]) -> Vec<Compact<u16>> {
+    vec.iter()
+        .map(|x| fixed_proportion_to_u16(*x).into())
+        .collect()
+}
+
+// Leaves out the zero entries the sparse epoch keeps, so both epochs report the same rows.
+fn sparse_proportions(matrix: &[Vec<(u16, I32F32)>]) -> Vec<Vec<(Compact<u16>, Compact<u16>)>> {
+    matrix
+        .iter()
+        .map(|row| {
+            row.iter()
+                .filter(|(_, x)| *x != I32F32::from_num(0))
+                .map(|(j, x)| ((*j).into(), fixed_proportion_to_u16(*x).into()))
+                .collect()
+        })
+        .collect()
+}
+
+impl<T: Config> Pallet<T> {
+    /// Runs the epoch of a network without writing to storage and returns all of its terms, using
+    /// the same implementation as run_epoch.
+    pub fn get_epoch_dry_run(netuid: u16, rao_emission: u64) -> Option<EpochInfo<T>> {
+        if !Self::if_subnet_exist(netuid) {
+            return None;
+        }
+
+        let EpochTerms {
+            hotkeys,
+            active,
+            stake,
+            new_validator_permits,
+            active_stake,
+            preranks,
+            consensus,
+            weights,
+            validator_trust,
+            ranks,
+            trust,
+            incentive,
+            bonds_delta,
+            ema_bonds,
+            dividends,
+            server_emission,
+            validator_emission,
+            pruning_scores,
+            ..
+        } = if Self::runs_dense_epoch(netuid) {
+            Self::epoch_dense_terms(netuid, rao_emission)
+        } else {
+            Self::epoch_terms(netuid, rao_emission)
+        };
+
+        Some(EpochInfo {
+            netuid: netuid.into(),
+            rao_emission: rao_emission.into(),
+            hotkeys: hotkeys
+                .into_iter()
+                .map(|(uid, hotkey)| (uid.into(), hotkey))
+                .collect(),
+            active,
+            stake: proportions(&stake),
+            validator_permits: new_validator_permits,
+            active_stake: proportions(&active_stake),
+            preranks: proportions(&preranks),
+            consensus: proportions(&consensus),
+            clipped_weights: sparse_proportions(&weights),
+            validator_trust: proportions(&validator_trust),
+            ranks: proportions(&ranks),
+            trust: proportions(&trust),
+            incentive: proportions(&incentive),
+            bonds_delta: sparse_proportions(&bonds_delta),
+            ema_bonds: sparse_proportions(&ema_bonds),
+            dividends: proportions(&dividends),
+            server_emission: compact(server_emission),
+            validator_emission: compact(validator_emission),
+            pruning_scores: compact(vec_max_upscale_to_u16(&pruning_scores)),
+        })
+    }
+
+    /// Runs the root epoch on the current state without writing to storage and returns
+    /// all of its terms, or None when the root epoch would not set emission values.
+    pub fn get_root_epoch_info() -> Option<RootEpochInfo<T>> {
+        let RootEpochTerms {
+            netuids,
+            hotkeys,
+            block_emission,
+            stake,
+            weights,
+            ranks,
+            trust,
+            consensus,
+            emission,
+        } = Self::root_epoch_terms().ok()?;
+
+        Some(RootEpochInfo {
+            block_emission: block_emission.into(),
+            netuids: compact(netuids),
+            hotkeys: hotkeys
+                .into_iter()
+                .map(|(uid, hotkey)| (uid.into(), hotkey))
+                .collect(),
+            stake: proportions_64(&stake),
+            weights: weights.iter().map(|row| proportions_64(row)).collect(),
+            ranks: proportions_64(&ranks),
+            trust: proportions_64(&trust),
+            consensus: proportions_64(&consensus),
+            emission: compact(emission),
+        })
+    }
+
+    /// Returns the metagraph of a subnet as stored by the epoch that ran epochs_ago epochs before
+    /// its latest one (0 is the latest), or None when that epoch is no longer retained.
+    pub fn get_metagraph_at_epoch(
+        netuid: u16,
+        epochs_ago: u16,
+    ) -> Option<MetagraphSnapshotInfo<T>> {
+        let EpochSnapshot {
+            block,
+            hotkeys,
+            stake,
+            rank,
+            trust,
+            consensus,
+            validator_trust,
+            incentive,
+            dividends,
+            emission,
+            validator_permit,
+        } = Self::get_epoch_snapshot(netuid, epochs_ago)?;
+
+        Some(MetagraphSnapshotInfo {
+            netuid: netuid.into(),
+            block: block.into(),
+            hotkeys,
+            stake: compact(stake),
+            ranks: compact(rank),
+            trust: compact(trust),
+            consensus: compact(consensus),
+            validator_trust: compact(validator_trust),
+            incentive: compact(incentive),
+            dividends: compact(dividends),
+            emission: compact(emission),
+            validator_permits: validator_permit,
+        })
+    }
+}
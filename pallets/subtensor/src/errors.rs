@@ -126,5 +126,49 @@ mod errors {
         CommitRevealEnabled,
         /// Attemtping to commit/reveal weights when disabled.
         CommitRevealDisabled,
+        /// Too many unbonding chunks are pending for this coldkey and hotkey.
+        TooManyUnbondingChunks,
+        /// No unbonded stake is ready to be withdrawn.
+        NoUnbondedStakeToWithdraw,
+        /// Attempting to move stake onto the hotkey it is moved from.
+        SameHotkeyStakeMove,
+        /// A hotkey was passed more than once to a stake rebalance.
+        DuplicateRebalanceTarget,
+        /// The hotkey already has the maximum number of pending weight commits.
+        TooManyPendingWeightCommits,
+        /// The round of a timelocked weight commit is not inside the reveal window of a commit made now.
+        InvalidTimelockRound,
+        /// A timelocked weight commit could not be decrypted or decoded with the round key.
+        TimelockDecryptionFailed,
+        /// The runtime has no source of round keys for timelocked weight commits.
+        TimelockUnavailable,
+        /// The new coldkey is the same as the coldkey being swapped.
+        NewColdKeyIsSameWithOld,
+        /// A coldkey swap is already scheduled for this coldkey.
+        ColdkeySwapAlreadyScheduled,
+        /// No coldkey swap is scheduled for this coldkey.
+        NoColdkeySwapScheduled,
+        /// The announcement delay of the scheduled coldkey swap has not passed yet.
+        ColdkeySwapNotReady,
+        /// More recipients than allowed were passed to an emission split.
+        TooManyEmissionSplitRecipients,
+        /// A recipient was passed more than once to an emission split.
+        DuplicateEmissionSplitRecipient,
+        /// The shares of an emission split add up to more than the maximum emission split share.
+        EmissionSplitShareTooHigh,
+        /// The subnet is dissolving.
+        SubnetDissolving,
+        /// An axon is served without any endpoint.
+        NoAxonEndpoints,
+        /// The DNS name of an axon endpoint is not a valid hostname.
+        InvalidHostname,
+        /// A subnet is passed more than once to a multi-subnet axon serve.
+        DuplicateServeAxonNetuid,
+        /// A multi-subnet axon serve could not serve the axon on any of the subnets.
+        AxonNotServedOnAnySubnet,
+        /// Neurons cannot deregister from the root subnet.
+        DeregistrationNotPermittedOnRootSubnet,
+        /// The coldkey has no stake on the hotkey.
+        NoStakeOnHotkey,
     }
 }
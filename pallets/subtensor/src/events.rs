@@ -19,6 +19,10 @@ mod events {
         WeightsSet(u16, u16),
         /// a new neuron account has been registered to the chain.
         NeuronRegistered(u16, u16, T::AccountId),
+        /// a neuron has left the network, freeing its uid, with the amount of its registration burn refunded.
+        NeuronDeregistered(u16, u16, T::AccountId, u64),
+        /// setting the fraction of the registration burn refunded on a deregistration on a network.
+        DeregistrationRefundRatioSet(u16, u16),
         /// multiple uids have been concurrently registered.
         BulkNeuronsRegistered(u16, u16),
         /// FIXME: Not used yet
@@ -57,6 +61,8 @@ mod events {
         MaxAllowedValidatorsSet(u16, u16),
         /// the axon server information is added to the network.
         AxonServed(u16, T::AccountId),
+        /// the axon server information is added to several networks, with the outcome for every network.
+        AxonServedMulti(T::AccountId, Vec<(u16, AxonServeOutcome)>),
         /// the prometheus server information is added to the network.
         PrometheusServed(u16, T::AccountId),
         /// emission ratios for all networks is set.
@@ -73,6 +79,8 @@ mod events {
         MaxDifficultySet(u16, u64),
         /// setting the prometheus serving rate limit.
         ServingRateLimitSet(u16, u64),
+        /// setting whether private ip ranges may be served on a network.
+        AllowPrivateIpsSet(u16, bool),
         /// setting burn on a network.
         BurnSet(u16, u64),
         /// setting max burn on a network.
@@ -132,5 +140,61 @@ mod events {
         MinDelegateTakeSet(u16),
         /// the target stakes per interval is set by sudo/admin transaction
         TargetStakesPerIntervalSet(u64),
+        /// the unbonding period for a subnet is set ( netuid, blocks ).
+        UnbondingPeriodSet(u16, u64),
+        /// stake has been removed and queued for unbonding ( coldkey, hotkey, amount, unlock_block ).
+        StakeUnbonding(T::AccountId, T::AccountId, u64, u64),
+        /// unbonded stake has been withdrawn to the coldkey balance ( coldkey, hotkey, amount ).
+        UnbondedStakeWithdrawn(T::AccountId, T::AccountId, u64),
+        /// stake has been moved between hotkeys ( coldkey, origin_hotkey, destination_hotkey, amount ).
+        StakeMoved(T::AccountId, T::AccountId, T::AccountId, u64),
+        /// stake of a coldkey has been rebalanced ( coldkey, [( hotkey, outcome )] ).
+        StakeRebalanced(T::AccountId, Vec<(T::AccountId, StakeRebalanceOutcome)>),
+        /// a weight commit was not revealed within its reveal window and was dropped ( netuid, hotkey, commit_hash ).
+        CommitExpired(u16, T::AccountId, H256),
+        /// a timelocked weight commit has been queued ( netuid, hotkey, round ).
+        TimelockedWeightsCommitted(u16, T::AccountId, u64),
+        /// a timelocked weight commit has been decrypted and its weights set ( netuid, hotkey, round ).
+        TimelockedWeightsApplied(u16, T::AccountId, u64),
+        /// a timelocked weight commit could not be decrypted or its weights not set ( netuid, hotkey, round, error ).
+        TimelockedWeightsFailed(u16, T::AccountId, u64, DispatchError),
+        /// the key of a timelocked weight commit was not published within its reveal window ( netuid, hotkey, round ).
+        TimelockedWeightsExpired(u16, T::AccountId, u64),
+        /// a coldkey swap has been scheduled ( coldkey, new_coldkey, execution_block ).
+        ColdkeySwapScheduled(T::AccountId, T::AccountId, u64),
+        /// a scheduled coldkey swap has been cancelled ( coldkey, new_coldkey ).
+        ColdkeySwapCancelled(T::AccountId, T::AccountId),
+        /// the position of a coldkey has been moved to a new coldkey ( coldkey, new_coldkey ).
+        ColdkeySwapped(T::AccountId, T::AccountId),
+        /// the destination of a scheduled coldkey swap has been replaced by the council ( coldkey, new_coldkey ).
+        ColdkeySwapDestinationOverridden(T::AccountId, T::AccountId),
+        /// the weights density from which subnets run the dense epoch has been set.
+        DenseEpochThresholdSet(u16),
+        /// liquid alpha has been enabled or disabled on a subnet ( netuid, enabled ).
+        LiquidAlphaEnabledSet(u16, bool),
+        /// the liquid alpha bounds of a subnet have been set ( netuid, alpha_low, alpha_high ).
+        AlphaValuesSet(u16, u16, u16),
+        /// the governance bounds on the liquid alpha bounds of subnets have been set ( min_alpha_low, max_alpha_high ).
+        AlphaValuesBoundsSet(u16, u16),
+        /// the liquid alpha sigmoid steepness of a subnet has been set ( netuid, steepness ).
+        AlphaSigmoidSteepnessSet(u16, u16),
+        /// the emission split of a subnet has been set ( netuid, emission_split ).
+        EmissionSplitSet(u16, EmissionSplit<T::AccountId>),
+        /// the maximum total share of an emission split has been set.
+        MaxEmissionSplitShareSet(u16),
+        /// the reward destination of a coldkey on a hotkey has been set ( coldkey, hotkey, destination ).
+        RewardDestinationSet(T::AccountId, T::AccountId, RewardDestination<T::AccountId>),
+        /// the emission ledger of a coldkey has been enabled or disabled ( coldkey, enabled ).
+        EmissionLedgerEnabledSet(T::AccountId, bool),
+        /// the dissolution of a subnet has been announced ( netuid, removal_block ).
+        NetworkDissolutionAnnounced(u16, u64),
+        /// a network registration waits for the pruned subnet to be removed ( netuid, coldkey ).
+        NetworkRegistrationQueued(u16, T::AccountId),
+        /// the lock of a removed subnet has been refunded ( netuid, coldkey, amount ).
+        NetworkLockRefunded(u16, T::AccountId, u64),
+        /// the number of blocks a subnet is dissolving before it is removed has been set.
+        NetworkDissolvePeriodSet(u64),
+        /// the number of epoch snapshots retained by a subnet has been set ( netuid, retention ).
+        EpochSnapshotRetentionSet(u16, u16),
     }
 }
@@ -5,6 +5,8 @@
 // Learn more about FRAME and the core library of Substrate FRAME pallets:
 // <https://docs.substrate.io/reference/frame-pallets/>
 pub use pallet::*;
+pub use serving::IpClass;
+pub use weight_info::WeightInfo;
 
 use frame_system::{self as system, ensure_signed};
 
@@ -47,10 +49,17 @@ mod uids;
 mod utils;
 mod weights;
 
+pub mod commit_info;
 pub mod delegate_info;
+pub mod delegate_return;
+pub mod emission_info;
+pub mod epoch_info;
+pub mod metagraph_info;
 pub mod neuron_info;
 pub mod stake_info;
 pub mod subnet_info;
+pub mod timelock;
+pub mod weight_info;
 
 // apparently this is stabilized since rust 1.36
 extern crate alloc;
@@ -80,7 +89,7 @@ pub mod pallet {
 
     /// Tracks version for migrations. Should be monotonic with respect to the
     /// order of migrations. (i.e. always increasing)
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(9);
 
     #[pallet::pallet]
     #[pallet::without_storage_info]
@@ -111,6 +120,12 @@ pub mod pallet {
         /// Interface to allow other pallets to control who can register identities
         type TriumvirateInterface: crate::CollectiveInterface<Self::AccountId, Self::Hash, u32>;
 
+        /// Hook for the pallets that keep state keyed by hotkey to follow a hotkey swap.
+        type OnHotkeySwapped: crate::OnHotkeySwapped<Self::AccountId>;
+
+        /// Source of the round keys used to decrypt timelocked weight commits.
+        type TimelockKeySource: crate::TimelockKeySource;
+
         /// =================================
         /// ==== Initial Value Constants ====
         /// =================================
@@ -238,6 +253,41 @@ pub mod pallet {
         /// Initial target stakes per interval issuance.
         #[pallet::constant]
         type InitialTargetStakesPerInterval: Get<u64>;
+        /// Maximum number of pending unbonding chunks per coldkey and hotkey.
+        #[pallet::constant]
+        type MaxUnbondingChunks: Get<u32>;
+        /// Maximum number of hotkeys in a single stake rebalance.
+        #[pallet::constant]
+        type MaxRebalanceTargets: Get<u32>;
+        /// Weight information for the benchmarked extrinsics of this pallet.
+        type WeightInfo: WeightInfo;
+        /// Maximum number of pending weight commits per hotkey and subnet.
+        #[pallet::constant]
+        type MaxPendingWeightCommits: Get<u32>;
+        /// Maximum length in bytes of the ciphertext of a timelocked weight commit.
+        #[pallet::constant]
+        type MaxTimelockedCiphertextLength: Get<u32>;
+        /// Maximum number of timelocked weight commits applied in a single block.
+        #[pallet::constant]
+        type MaxTimelockedCommitsPerBlock: Get<u32>;
+        /// Number of blocks between scheduling a coldkey swap and being able to execute it.
+        #[pallet::constant]
+        type ColdkeySwapAnnouncementDelay: Get<u64>;
+        /// Maximum number of emission records kept per coldkey, older records are overwritten.
+        #[pallet::constant]
+        type MaxEmissionLedgerEntries: Get<u32>;
+        /// Maximum number of recipient accounts in the emission split of a subnet.
+        #[pallet::constant]
+        type MaxEmissionSplitRecipients: Get<u32>;
+        /// Maximum number of epoch snapshots a subnet can retain.
+        #[pallet::constant]
+        type MaxEpochSnapshotRetention: Get<u16>;
+        /// Expected time between blocks in milliseconds, used to project returns per day.
+        #[pallet::constant]
+        type ExpectedBlockTime: Get<u64>;
+        /// Maximum number of subnets an axon can be served on in a single call.
+        #[pallet::constant]
+        type MaxServeAxonNetuids: Get<u32>;
     }
 
     /// Alias for the account ID.
@@ -364,6 +414,171 @@ pub mod pallet {
         ValueQuery,
         DefaultAccountTake<T>,
     >;
+    #[pallet::storage] // --- MAP ( cold ) --> Vec<hot> | Returns the hotkeys owned by a coldkey.
+    pub type OwnedHotkeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
+    #[pallet::storage] // --- MAP ( cold ) --> Vec<hot> | Returns the hotkeys a coldkey has a stake entry on.
+    pub type StakingHotkeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<T::AccountId>, ValueQuery>;
+    /// Default unbonding chunks.
+    #[pallet::type_value]
+    pub fn DefaultUnbondingChunks<T: Config>() -> Vec<(u64, u64)> {
+        vec![]
+    }
+    #[pallet::storage] // --- DMAP ( cold, hot ) --> Vec<(amount, unlock_block)> | Returns the stake chunks waiting to be withdrawn.
+    pub type Unbonding<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        T::AccountId,
+        Vec<(u64, u64)>,
+        ValueQuery,
+        DefaultUnbondingChunks<T>,
+    >;
+    #[pallet::storage] // --- DMAP ( cold, hot ) --> unbonding_period | Returns the unbonding period in effect when the stake was bonded.
+    pub type BondedUnbondingPeriod<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        T::AccountId,
+        u64,
+        ValueQuery,
+        DefaultUnbondingPeriod<T>,
+    >;
+    /// Where the nominator rewards of a coldkey on a delegate hotkey are paid.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub enum RewardDestination<AccountId> {
+        /// Rewards are added onto the stake of the coldkey on the hotkey.
+        Compound,
+        /// Rewards are paid to the free balance of the coldkey.
+        Free,
+        /// Rewards are paid to the free balance of the account.
+        Account(AccountId),
+    }
+    /// Default reward destination.
+    #[pallet::type_value]
+    pub fn DefaultRewardDestination<T: Config>() -> RewardDestination<T::AccountId> {
+        RewardDestination::Compound
+    }
+    #[pallet::storage] // --- DMAP ( cold, hot ) --> reward_destination | Returns where the nominator rewards of a coldkey on a hotkey are paid.
+    pub type RewardDestinations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        T::AccountId,
+        RewardDestination<T::AccountId>,
+        ValueQuery,
+        DefaultRewardDestination<T>,
+    >;
+    #[pallet::storage] // --- MAP ( cold ) --> ( new_cold, execution_block ) | Returns the coldkey swap scheduled for a coldkey.
+    pub type ColdkeySwapScheduled<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (T::AccountId, u64), OptionQuery>;
+
+    /// Outcome of a single hotkey entry in a stake rebalance.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub enum StakeRebalanceOutcome {
+        /// Stake was added onto the hotkey.
+        Increased(u64),
+        /// Stake was removed from the hotkey.
+        Decreased(u64),
+        /// The hotkey already held the target amount.
+        Unchanged,
+        /// The entry could not be applied and was skipped.
+        Failed(DispatchError),
+    }
+
+    /// Emission credited to a coldkey through a hotkey on a subnet when an epoch is drained.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct EmissionRecord<AccountId> {
+        /// Block the emission was drained at.
+        pub block: u64,
+        /// Subnet the emission was earned on.
+        pub netuid: u16,
+        /// Hotkey the emission was earned through.
+        pub hotkey: AccountId,
+        /// Server emission, credited to the owner of the hotkey.
+        pub server_emission: u64,
+        /// Validator emission, credited to the owner of a hotkey that is not a delegate.
+        pub validator_emission: u64,
+        /// Take on the validator emission, credited to the owner of a delegate hotkey.
+        pub delegate_take: u64,
+        /// Share of the validator emission, credited for stake on a delegate hotkey.
+        pub nominator_share: u64,
+    }
+
+    /// Metagraph of a subnet as stored by one of its epochs, indexed by uid.
+    /// Proportions are u16-normalized (u16::MAX == 1.0), emissions are in rao.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct EpochSnapshot<AccountId> {
+        /// Block the epoch ran at.
+        pub block: u64,
+        /// Hotkey registered on each uid, None for a freed uid.
+        pub hotkeys: Vec<Option<AccountId>>,
+        /// Total stake of each hotkey.
+        pub stake: Vec<u64>,
+        pub rank: Vec<u16>,
+        pub trust: Vec<u16>,
+        pub consensus: Vec<u16>,
+        pub validator_trust: Vec<u16>,
+        pub incentive: Vec<u16>,
+        pub dividends: Vec<u16>,
+        pub emission: Vec<u64>,
+        pub validator_permit: Vec<bool>,
+    }
+
+    /// Split of the emission of a subnet before the remainder is queued for its epoch.
+    /// Shares are u16-normalized (u16::MAX == 1.0) proportions of the emission of every block.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct EmissionSplit<AccountId> {
+        /// Share credited to the coldkey of the subnet owner.
+        pub owner_cut: u16,
+        /// Share that is burned.
+        pub burn: u16,
+        /// Accounts credited with a share, next to the subnet owner.
+        pub recipients: Vec<(AccountId, u16)>,
+    }
+
+    /// Default value for whether the emission ledger of a coldkey is kept.
+    #[pallet::type_value]
+    pub fn DefaultEmissionLedgerEnabled<T: Config>() -> bool {
+        false
+    }
+    #[pallet::storage] // --- MAP ( cold ) --> enabled | Whether emission records are kept for a coldkey, set by the coldkey.
+    pub type EmissionLedgerEnabled<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+        DefaultEmissionLedgerEnabled<T>,
+    >;
+    /// Default number of emission records written for a coldkey.
+    #[pallet::type_value]
+    pub fn DefaultEmissionLedgerLength<T: Config>() -> u64 {
+        0
+    }
+    #[pallet::storage] // --- MAP ( cold ) --> records_written | Number of emission records ever written for a coldkey.
+    pub type EmissionLedgerLength<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u64,
+        ValueQuery,
+        DefaultEmissionLedgerLength<T>,
+    >;
+    #[pallet::storage] // --- DMAP ( cold, slot ) --> record | Ring buffer of the most recent emission records of a coldkey.
+    pub type EmissionLedger<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Identity,
+        u32,
+        EmissionRecord<T::AccountId>,
+        OptionQuery,
+    >;
 
     /// =====================================
     /// ==== Difficulty / Registrations =====
@@ -523,6 +738,21 @@ pub mod pallet {
     pub fn DefaultSubnetOwnerCut<T: Config>() -> u16 {
         T::InitialSubnetOwnerCut::get()
     }
+    /// Default number of blocks a subnet is dissolving before it is removed.
+    #[pallet::type_value]
+    pub fn DefaultNetworkDissolvePeriod<T: Config>() -> u64 {
+        7200
+    }
+    /// Default number of epoch snapshots retained by a subnet.
+    #[pallet::type_value]
+    pub fn DefaultEpochSnapshotRetention<T: Config>() -> u16 {
+        0
+    }
+    /// Default number of epoch snapshots taken for a subnet.
+    #[pallet::type_value]
+    pub fn DefaultEpochSnapshotsTaken<T: Config>() -> u64 {
+        0
+    }
     /// Default value for subnet limit.
     #[pallet::type_value]
     pub fn DefaultSubnetLimit<T: Config>() -> u16 {
@@ -588,6 +818,33 @@ pub mod pallet {
         StorageValue<_, u64, ValueQuery, DefaultNetworkLockReductionInterval<T>>;
     #[pallet::storage] // ITEM( subnet_owner_cut )
     pub type SubnetOwnerCut<T> = StorageValue<_, u16, ValueQuery, DefaultSubnetOwnerCut<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> emission_split | Split of the subnet emission set by the subnet owner.
+    pub type SubnetEmissionSplit<T: Config> =
+        StorageMap<_, Identity, u16, EmissionSplit<T::AccountId>, OptionQuery>;
+    #[pallet::storage] // ITEM( max_emission_split_share ) | The subnet owner cut applies until governance sets it.
+    pub type MaxEmissionSplitShare<T> = StorageValue<_, u16, OptionQuery>;
+    #[pallet::storage] // --- MAP ( cold ) --> owed | Emission split cuts owed to an account, paid once they can create it.
+    pub type PendingEmissionSplitPayouts<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u64, ValueQuery>;
+    #[pallet::storage] // ITEM( network_dissolve_period )
+    pub type NetworkDissolvePeriod<T> =
+        StorageValue<_, u64, ValueQuery, DefaultNetworkDissolvePeriod<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> removal_block | Block at which a dissolving subnet is removed.
+    pub type DissolvingNetworks<T> = StorageMap<_, Identity, u16, u64, OptionQuery>;
+    #[pallet::storage] // --- MAP ( block ) --> Vec<netuid> | Dissolving subnets to remove at the given block.
+    pub type DissolutionsAt<T> = StorageMap<_, Twox64Concat, u64, Vec<u16>, ValueQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> ( coldkey, lock ) | Registration waiting for the pruned subnet to be removed.
+    pub type PendingNetworkRegistrations<T: Config> =
+        StorageMap<_, Identity, u16, (T::AccountId, u64), OptionQuery>;
+    #[pallet::storage] // --- MAP ( netuid ) --> retention | Number of epoch snapshots a subnet retains, 0 disables them.
+    pub type EpochSnapshotRetention<T> =
+        StorageMap<_, Identity, u16, u16, ValueQuery, DefaultEpochSnapshotRetention<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> snapshots_taken | Number of epoch snapshots taken since the retention was set.
+    pub type EpochSnapshotsTaken<T> =
+        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultEpochSnapshotsTaken<T>>;
+    #[pallet::storage] // --- DMAP ( netuid, slot ) --> snapshot | Ring buffer of the most recent epoch snapshots of a subnet.
+    pub type EpochSnapshots<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Identity, u16, EpochSnapshot<T::AccountId>, OptionQuery>;
     #[pallet::storage] // ITEM( network_rate_limit )
     pub type NetworkRateLimit<T> = StorageValue<_, u64, ValueQuery, DefaultNetworkRateLimit<T>>;
     #[pallet::storage] // ITEM( nominator_min_required_stake )
@@ -684,6 +941,55 @@ pub mod pallet {
         pub placeholder2: u8,
     }
 
+    /// Maximum number of endpoints an axon can advertise.
+    pub type MaxAxonEndpoints = ConstU32<8>;
+    /// Maximum length of the DNS name of an axon endpoint.
+    pub type MaxAxonHostnameLength = ConstU32<253>;
+
+    /// Address of an axon endpoint.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub enum AxonAddress {
+        ///  u32 encoded ipv4 address.
+        Ipv4(u32),
+        ///  u128 encoded ipv6 address.
+        Ipv6(u128),
+        ///  ASCII encoded DNS name.
+        Dns(BoundedVec<u8, MaxAxonHostnameLength>),
+    }
+
+    /// Data structure for an axon endpoint.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct AxonEndpoint {
+        ///  Endpoint address, an ipv4 or ipv6 address or a DNS name.
+        pub address: AxonAddress,
+        ///  Endpoint u16 encoded port.
+        pub port: u16,
+        ///  Endpoint protocol. TCP, UDP, other.
+        pub protocol: u8,
+        ///  SHA-256 digest of the endpoint TLS certificate, for validators to pin.
+        pub cert_digest: Option<[u8; 32]>,
+    }
+
+    /// Data structure for versioned Axon information, advertising several endpoints.
+    #[derive(Encode, Decode, Default, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub struct AxonInfoV2 {
+        ///  Axon serving block.
+        pub block: u64,
+        ///  Axon version
+        pub version: u32,
+        ///  Axon endpoints, in order of preference.
+        pub endpoints: BoundedVec<AxonEndpoint, MaxAxonEndpoints>,
+    }
+
+    /// Outcome of a single subnet in a multi-subnet axon serve.
+    #[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+    pub enum AxonServeOutcome {
+        /// The axon was served on the subnet.
+        Served,
+        /// The axon could not be served on the subnet and was skipped.
+        Failed(DispatchError),
+    }
+
     ///  Struct for Prometheus.
     pub type PrometheusInfoOf = PrometheusInfo;
     /// Data structure for Prometheus information.
@@ -723,10 +1029,10 @@ pub mod pallet {
     pub(super) type TxDelegateTakeRateLimit<T> =
         StorageValue<_, u64, ValueQuery, DefaultTxDelegateTakeRateLimit<T>>;
     #[pallet::storage] // --- MAP ( key ) --> last_block
-    pub(super) type LastTxBlock<T: Config> =
+    pub type LastTxBlock<T: Config> =
         StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultLastTxBlock<T>>;
     #[pallet::storage] // --- MAP ( key ) --> last_block
-    pub(super) type LastTxBlockDelegateTake<T: Config> =
+    pub type LastTxBlockDelegateTake<T: Config> =
         StorageMap<_, Identity, T::AccountId, u64, ValueQuery, DefaultLastTxBlock<T>>;
 
     /// Default value for serving rate limit.
@@ -738,11 +1044,22 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( netuid ) --> serving_rate_limit
     pub type ServingRateLimit<T> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultServingRateLimit<T>>;
+    /// Default value for allowing private ip ranges on a network.
+    #[pallet::type_value]
+    pub fn DefaultAllowPrivateIps<T: Config>() -> bool {
+        false
+    }
+    #[pallet::storage] // --- MAP ( netuid ) --> allow_private_ips
+    pub type AllowPrivateIps<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultAllowPrivateIps<T>>;
     #[pallet::storage] // --- MAP ( netuid, hotkey ) --> axon_info
-    pub(super) type Axons<T: Config> =
+    pub type Axons<T: Config> =
         StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, AxonInfoOf, OptionQuery>;
+    #[pallet::storage] // --- MAP ( netuid, hotkey ) --> axon_info_v2
+    pub type AxonsV2<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, AxonInfoV2, OptionQuery>;
     #[pallet::storage] // --- MAP ( netuid, hotkey ) --> prometheus_info
-    pub(super) type Prometheus<T: Config> = StorageDoubleMap<
+    pub type Prometheus<T: Config> = StorageDoubleMap<
         _,
         Identity,
         u16,
@@ -761,6 +1078,11 @@ pub mod pallet {
     pub fn DefaultWeightsSetRateLimit<T: Config>() -> u64 {
         100
     }
+    /// Default burn at registration.
+    #[pallet::type_value]
+    pub fn DefaultBurnAtRegistration<T: Config>() -> u64 {
+        0
+    }
     /// Default block at registration.
     #[pallet::type_value]
     pub fn DefaultBlockAtRegistration<T: Config>() -> u64 {
@@ -902,6 +1224,27 @@ pub mod pallet {
     #[pallet::storage] // --- MAP ( netuid ) --> target_registrations_this_interval
     pub type TargetRegistrationsPerInterval<T> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultTargetRegistrationsPerInterval<T>>;
+    /// Default value for the deregistration refund ratio.
+    #[pallet::type_value]
+    pub fn DefaultDeregistrationRefundRatio<T: Config>() -> u16 {
+        0
+    }
+    #[pallet::storage] // --- MAP ( netuid ) --> deregistration_refund_ratio | Fraction of the registration burn, normalized to u16::MAX, refunded on a voluntary deregistration.
+    pub type DeregistrationRefundRatio<T> =
+        StorageMap<_, Identity, u16, u16, ValueQuery, DefaultDeregistrationRefundRatio<T>>;
+    #[pallet::storage] // --- MAP ( netuid ) --> freed_uids | Uids freed by a voluntary deregistration, filled first by the next registrations.
+    pub type FreedUids<T> = StorageMap<_, Identity, u16, Vec<u16>, ValueQuery, EmptyU16Vec<T>>;
+    #[pallet::storage] // --- DMAP ( netuid, uid ) --> burn_at_registration | Amount burned by the burned registration of the uid.
+    pub type BurnAtRegistration<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Identity,
+        u16,
+        u64,
+        ValueQuery,
+        DefaultBurnAtRegistration<T>,
+    >;
     #[pallet::storage] // --- DMAP ( netuid, uid ) --> block_at_registration
     pub type BlockAtRegistration<T: Config> = StorageDoubleMap<
         _,
@@ -917,16 +1260,25 @@ pub mod pallet {
     pub type AdjustmentAlpha<T: Config> =
         StorageMap<_, Identity, u16, u64, ValueQuery, DefaultAdjustmentAlpha<T>>;
 
-    #[pallet::storage] // --- MAP (netuid, who) --> (hash, weight) | Returns the hash and weight committed by an account for a given netuid.
+    #[pallet::storage] // --- MAP (netuid, who) --> Vec<(hash, block)> | Returns the pending weight commits of an account for a given netuid, oldest first.
     pub type WeightCommits<T: Config> = StorageDoubleMap<
         _,
         Twox64Concat,
         u16,
         Twox64Concat,
         T::AccountId,
-        (H256, u64),
+        Vec<(H256, u64)>,
         OptionQuery,
     >;
+    #[pallet::storage] // --- MAP ( block ) --> Vec<(netuid, who, commit_block)> | Weight commits to check for expiry at the given block.
+    pub type WeightCommitExpiries<T: Config> =
+        StorageMap<_, Twox64Concat, u64, Vec<(u16, T::AccountId, u64)>, ValueQuery>;
+    #[pallet::storage] // --- MAP ( block ) --> Vec<(netuid, who, round, commit_block, ciphertext)> | Timelocked weight commits to decrypt at the given block.
+    pub type TimelockedWeightCommits<T: Config> =
+        StorageMap<_, Twox64Concat, u64, Vec<(u16, T::AccountId, u64, u64, Vec<u8>)>, ValueQuery>;
+    #[pallet::storage] // --- DMAP ( netuid, who ) --> Vec<block> | Returns the blocks at which the pending timelocked weight commits of an account are queued.
+    pub type PendingTimelockedWeightCommits<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, u16, Twox64Concat, T::AccountId, Vec<u64>, ValueQuery>;
 
     /// Default value for weight commit reveal interval.
     #[pallet::type_value]
@@ -948,6 +1300,67 @@ pub mod pallet {
     pub type CommitRevealWeightsEnabled<T> =
         StorageMap<_, Identity, u16, bool, ValueQuery, DefaultCommitRevealWeightsEnabled<T>>;
 
+    /// Default value for the stake unbonding period, zero disables unbonding.
+    #[pallet::type_value]
+    pub fn DefaultUnbondingPeriod<T: Config>() -> u64 {
+        0
+    }
+    // --- MAP ( netuid ) --> unbonding_period
+    #[pallet::storage]
+    pub type UnbondingPeriod<T> =
+        StorageMap<_, Identity, u16, u64, ValueQuery, DefaultUnbondingPeriod<T>>;
+
+    /// Default weights density from which the dense epoch is run, as a u16 proportion.
+    #[pallet::type_value]
+    pub fn DefaultDenseEpochThreshold<T: Config>() -> u16 {
+        u16::MAX / 2
+    }
+    // --- ITEM ( dense_epoch_threshold )
+    #[pallet::storage]
+    pub type DenseEpochThreshold<T> =
+        StorageValue<_, u16, ValueQuery, DefaultDenseEpochThreshold<T>>;
+
+    /// Default value for liquid alpha enabled.
+    #[pallet::type_value]
+    pub fn DefaultLiquidAlphaOn<T: Config>() -> bool {
+        false
+    }
+    // --- MAP ( netuid ) --> liquid_alpha_on
+    #[pallet::storage]
+    pub type LiquidAlphaOn<T> =
+        StorageMap<_, Identity, u16, bool, ValueQuery, DefaultLiquidAlphaOn<T>>;
+
+    /// Default liquid alpha bounds ( alpha_low, alpha_high ) as u16 proportions, about 0.1 and 0.3.
+    #[pallet::type_value]
+    pub fn DefaultAlphaValues<T: Config>() -> (u16, u16) {
+        (6_554, 19_661)
+    }
+    // --- MAP ( netuid ) --> ( alpha_low, alpha_high )
+    #[pallet::storage]
+    pub type AlphaValues<T> =
+        StorageMap<_, Identity, u16, (u16, u16), ValueQuery, DefaultAlphaValues<T>>;
+
+    /// Default governance bounds ( min_alpha_low, max_alpha_high ) on the liquid alpha bounds of
+    /// every subnet, about 0.01 and 0.9.
+    #[pallet::type_value]
+    pub fn DefaultAlphaValuesBounds<T: Config>() -> (u16, u16) {
+        (656, 58_982)
+    }
+    // --- ITEM ( min_alpha_low, max_alpha_high )
+    #[pallet::storage]
+    pub type AlphaValuesBounds<T> =
+        StorageValue<_, (u16, u16), ValueQuery, DefaultAlphaValuesBounds<T>>;
+
+    /// Default steepness of the liquid alpha sigmoid.
+    #[pallet::type_value]
+    pub fn DefaultAlphaSigmoidSteepness<T: Config>() -> u16 {
+        10
+    }
+    // --- MAP ( netuid ) --> alpha_sigmoid_steepness
+    #[pallet::storage]
+    pub type AlphaSigmoidSteepness<T> =
+        StorageMap<_, Identity, u16, u16, ValueQuery, DefaultAlphaSigmoidSteepness<T>>;
+
     /// =======================================
     /// ==== Subnetwork Consensus Storage  ====
     /// =======================================
@@ -985,13 +1398,13 @@ pub mod pallet {
     }
 
     #[pallet::storage] // --- DMAP ( netuid, hotkey ) --> uid
-    pub(super) type Uids<T: Config> =
+    pub type Uids<T: Config> =
         StorageDoubleMap<_, Identity, u16, Blake2_128Concat, T::AccountId, u16, OptionQuery>;
     #[pallet::storage] // --- DMAP ( netuid, uid ) --> hotkey
-    pub(super) type Keys<T: Config> =
+    pub type Keys<T: Config> =
         StorageDoubleMap<_, Identity, u16, Identity, u16, T::AccountId, ValueQuery, DefaultKey<T>>;
     #[pallet::storage] // --- DMAP ( netuid ) --> (hotkey, se, ve)
-    pub(super) type LoadedEmission<T: Config> =
+    pub type LoadedEmission<T: Config> =
         StorageMap<_, Identity, u16, Vec<(T::AccountId, u64, u64)>, OptionQuery>;
 
     #[pallet::storage] // --- DMAP ( netuid ) --> active
@@ -1171,6 +1584,7 @@ pub mod pallet {
 
                     // Fill stake information.
                     Owner::<T>::insert(hotkey.clone(), coldkey.clone());
+                    OwnedHotkeys::<T>::append(coldkey.clone(), hotkey.clone());
 
                     TotalHotkeyStake::<T>::insert(hotkey.clone(), stake);
                     TotalColdkeyStake::<T>::insert(
@@ -1181,6 +1595,7 @@ pub mod pallet {
                     // Update total issuance value
                     TotalIssuance::<T>::put(TotalIssuance::<T>::get().saturating_add(*stake));
 
+                    Pallet::<T>::record_staking_hotkey(coldkey, hotkey);
                     Stake::<T>::insert(hotkey.clone(), coldkey.clone(), stake);
 
                     next_uid += 1;
@@ -1241,12 +1656,13 @@ pub mod pallet {
         fn on_initialize(_block_number: BlockNumberFor<T>) -> Weight {
             let block_step_result = Self::block_step();
             match block_step_result {
-                Ok(_) => {
+                Ok(block_step_weight) => {
                     // --- If the block step was successful, return the weight.
                     log::info!("Successfully ran block step.");
                     Weight::from_parts(110_634_229_000_u64, 0)
                         .saturating_add(T::DbWeight::get().reads(8304_u64))
                         .saturating_add(T::DbWeight::get().writes(110_u64))
+                        .saturating_add(block_step_weight)
                 }
                 Err(e) => {
                     // --- If the block step was unsuccessful, return the weight anyway.
@@ -1283,7 +1699,13 @@ pub mod pallet {
                 // Storage version v4 -> v5
                 .saturating_add(migration::migrate_delete_subnet_3::<T>())
                 // Doesn't check storage version. TODO: Remove after upgrade
-                .saturating_add(migration::migration5_total_issuance::<T>(false));
+                .saturating_add(migration::migration5_total_issuance::<T>(false))
+                // Storage version v6 -> v7
+                .saturating_add(migration::migrate_to_v7_weight_commit_queue::<T>())
+                // Storage version v7 -> v8
+                .saturating_add(migration::migrate_to_v8_axon_info_v2::<T>())
+                // Storage version v8 -> v9
+                .saturating_add(migration::migrate_to_v9_coldkey_hotkey_indexes::<T>());
 
             weight
         }
@@ -1444,6 +1866,55 @@ pub mod pallet {
             Self::do_reveal_weights(origin, netuid, uids, values, salt, version_key)
         }
 
+        /// ---- Used to commit weights encrypted against the key of a future round. Once the
+        /// key of the round is published the chain decrypts the payload and sets the weights.
+        ///
+        /// # Args:
+        /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
+        ///   - The signature of the committing hotkey.
+        ///
+        /// * `netuid` (`u16`):
+        ///   - The u16 network identifier.
+        ///
+        /// * `ciphertext` (`BoundedVec<u8, T::MaxTimelockedCiphertextLength>`):
+        ///   - The SCALE encoded ( uids, values, version_key ) encrypted against the round key.
+        ///
+        /// * `round` (`u64`):
+        ///   - The round whose key decrypts the payload.
+        ///
+        /// # Event:
+        /// * TimelockedWeightsCommitted;
+        ///   - On successfully queueing the commit.
+        ///
+        /// # Raises:
+        /// * `TimelockUnavailable`:
+        ///   - The runtime has no source of round keys.
+        ///
+        /// * `CommitRevealDisabled`:
+        ///   - Attempting to commit while commit/reveal is disabled on the network.
+        ///
+        /// * `HotKeyNotRegisteredInSubNet`:
+        ///   - The hotkey is not registered on the network.
+        ///
+        /// * `InvalidTimelockRound`:
+        ///   - The round is not inside the reveal window of a commit made now.
+        ///
+        /// * `TooManyPendingWeightCommits`:
+        ///   - Attempting to commit while the queue of pending commits is full.
+        ///
+        #[pallet::call_index(98)]
+        #[pallet::weight((Weight::from_parts(46_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(5))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::Yes))]
+        pub fn commit_timelocked_weights(
+            origin: T::RuntimeOrigin,
+            netuid: u16,
+            ciphertext: BoundedVec<u8, T::MaxTimelockedCiphertextLength>,
+            round: u64,
+        ) -> DispatchResult {
+            Self::do_commit_timelocked_weights(origin, netuid, ciphertext.into_inner(), round)
+        }
+
         /// # Args:
         /// * `origin`: (<T as frame_system::Config>Origin):
         /// 	- The caller, a hotkey who wishes to set their weights.
@@ -1716,6 +2187,111 @@ pub mod pallet {
             Self::do_remove_stake(origin, hotkey, amount_unstaked)
         }
 
+        /// Withdraw stake that has finished unbonding back onto the coldkey. Stake removed
+        /// from a hotkey registered on a subnet with a non-zero unbonding period is queued
+        /// and can only be withdrawn once its unlock block has passed.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey the stake was removed from.
+        ///
+        /// # Event:
+        /// * UnbondedStakeWithdrawn;
+        /// 	- On successfully withdrawing the matured stake.
+        ///
+        /// # Raises:
+        /// * 'NoUnbondedStakeToWithdraw':
+        /// 	- Thrown if no unbonding chunk has reached its unlock block.
+        ///
+        #[pallet::call_index(71)]
+        #[pallet::weight((Weight::from_parts(45_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn withdraw_unbonded(origin: OriginFor<T>, hotkey: T::AccountId) -> DispatchResult {
+            Self::do_withdraw_unbonded(origin, hotkey)
+        }
+
+        /// Move stake from one hotkey to another without passing through the coldkey balance.
+        /// The stake stays bonded for the whole operation and keeps the unbonding period of the
+        /// origin hotkey. The move counts against the rate limits of both hotkeys.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'origin_hotkey' (T::AccountId):
+        /// 	- The hotkey the stake is moved away from.
+        ///
+        /// * 'destination_hotkey' (T::AccountId):
+        /// 	- The hotkey the stake is moved onto.
+        ///
+        /// * 'amount_moved' (u64):
+        /// 	- The amount of stake to move.
+        ///
+        /// # Event:
+        /// * StakeMoved;
+        /// 	- On successfully moving the stake.
+        ///
+        /// # Raises:
+        /// * 'NotEnoughStakeToWithdraw':
+        /// 	- Thrown if there is not enough stake on the origin hotkey.
+        ///
+        /// * 'NomStakeBelowMinimumThreshold':
+        /// 	- Thrown if either resulting nomination is below the minimum required stake.
+        ///
+        /// * 'UnstakeRateLimitExceeded':
+        /// 	- Thrown if the coldkey has hit the stake rate limit on the origin hotkey.
+        ///
+        /// * 'StakeRateLimitExceeded':
+        /// 	- Thrown if the coldkey has hit the stake rate limit on the destination hotkey.
+        ///
+        #[pallet::call_index(72)]
+        #[pallet::weight((Weight::from_parts(124_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(18))
+		.saturating_add(T::DbWeight::get().writes(10)), DispatchClass::Normal, Pays::No))]
+        pub fn move_stake(
+            origin: OriginFor<T>,
+            origin_hotkey: T::AccountId,
+            destination_hotkey: T::AccountId,
+            amount_moved: u64,
+        ) -> DispatchResult {
+            Self::do_move_stake(origin, origin_hotkey, destination_hotkey, amount_moved)
+        }
+
+        /// Rebalance the stake of the calling coldkey over a set of hotkeys in a single call.
+        /// For each hotkey the difference to the target amount is computed. Decreases are applied
+        /// before increases. Without an unbonding period the released stake is credited to the
+        /// coldkey and can fund the increases, otherwise it is queued for unbonding and the
+        /// increases are funded from the free balance only. An entry that cannot be applied is
+        /// skipped and reported, it does not abort the rebalance.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller's coldkey.
+        ///
+        /// * 'targets' (BoundedVec<(T::AccountId, u64), T::MaxRebalanceTargets>):
+        /// 	- The hotkeys and the stake the coldkey should hold on each of them.
+        ///
+        /// # Event:
+        /// * StakeRebalanced;
+        /// 	- With the outcome for every hotkey in the request.
+        ///
+        /// # Raises:
+        /// * 'DuplicateRebalanceTarget':
+        /// 	- Thrown if a hotkey is passed more than once.
+        ///
+        #[pallet::call_index(73)]
+        #[pallet::weight((T::WeightInfo::rebalance_stake(targets.len() as u32), DispatchClass::Normal, Pays::No))]
+        pub fn rebalance_stake(
+            origin: OriginFor<T>,
+            targets: BoundedVec<(T::AccountId, u64), T::MaxRebalanceTargets>,
+        ) -> DispatchResult {
+            Self::do_rebalance_stake(origin, targets.into_inner())
+        }
+
         /// Serves or updates axon /promethteus information for the neuron associated with the caller. If the caller is
         /// already registered the metadata is updated. If the caller is not registered this call throws NotRegistered.
         ///
@@ -1762,7 +2338,8 @@ pub mod pallet {
         /// 	- The ip type is not 4 or 6.
         ///
         /// * 'InvalidIpAddress':
-        /// 	- The numerically encoded ip address does not resolve to a proper ip.
+        /// 	- The numerically encoded ip address does not resolve to a proper ip, or is in a
+        /// 	special-purpose range that is not allowed on the subnet.
         ///
         /// * 'ServingRateLimitExceeded':
         /// 	- Attempting to set prometheus information withing the rate limit min.
@@ -1795,6 +2372,111 @@ pub mod pallet {
             )
         }
 
+        /// Serves or updates the axon of the neuron associated with the caller with several
+        /// endpoints. Each endpoint is an ipv4 or ipv6 address or a DNS name, with its port,
+        /// protocol and an optional digest of its TLS certificate for validators to pin. The first
+        /// ip endpoint is also served as the axon of the first version.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The u16 network identifier.
+        ///
+        /// * 'version' (u32):
+        /// 	- The bittensor version identifier.
+        ///
+        /// * 'endpoints' (BoundedVec<AxonEndpoint>):
+        /// 	- The endpoints of the axon, in order of preference.
+        ///
+        /// # Event:
+        /// * AxonServed;
+        /// 	- On successfully serving the axon info.
+        ///
+        /// # Raises:
+        /// * 'NotRegistered':
+        /// 	- Attempting to serve an axon from a non registered account.
+        ///
+        /// * 'NoAxonEndpoints':
+        /// 	- No endpoint is passed.
+        ///
+        /// * 'InvalidIpAddress':
+        /// 	- An ip endpoint is in a special-purpose range that is not allowed on the subnet.
+        ///
+        /// * 'InvalidHostname':
+        /// 	- A DNS endpoint is not a valid hostname, or is a special-use name that is not
+        /// 	allowed on the subnet.
+        ///
+        /// * 'InvalidPort':
+        /// 	- An endpoint has port 0.
+        ///
+        /// * 'ServingRateLimitExceeded':
+        /// 	- Attempting to serve the axon within the rate limit min.
+        ///
+        #[pallet::call_index(79)]
+        #[pallet::weight((Weight::from_parts(46_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(2)), DispatchClass::Normal, Pays::No))]
+        pub fn serve_axon_v2(
+            origin: OriginFor<T>,
+            netuid: u16,
+            version: u32,
+            endpoints: BoundedVec<AxonEndpoint, MaxAxonEndpoints>,
+        ) -> DispatchResult {
+            Self::do_serve_axon_v2(origin, netuid, version, endpoints)
+        }
+
+        /// Serves or updates the axon of the neuron associated with the caller on several subnets
+        /// at once, with the endpoints of serve_axon_v2. The caller must be registered on every
+        /// subnet. Each subnet applies its own serving rate limit and private ip policy, a subnet
+        /// where the axon cannot be served is skipped and reported in the outcome event. The call
+        /// fails if the axon is served on none of them.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the caller.
+        ///
+        /// * 'netuids' (BoundedVec<u16>):
+        /// 	- The u16 network identifiers, at most MaxServeAxonNetuids.
+        ///
+        /// * 'version' (u32):
+        /// 	- The bittensor version identifier.
+        ///
+        /// * 'endpoints' (BoundedVec<AxonEndpoint>):
+        /// 	- The endpoints of the axon, in order of preference.
+        ///
+        /// # Event:
+        /// * AxonServed;
+        /// 	- For every subnet the axon info is served on.
+        ///
+        /// * AxonServedMulti;
+        /// 	- With the outcome for every subnet, in the order they were passed.
+        ///
+        /// # Raises:
+        /// * 'DuplicateServeAxonNetuid':
+        /// 	- A subnet is passed more than once.
+        ///
+        /// * 'HotKeyNotRegisteredInSubNet':
+        /// 	- The caller is not registered on one of the subnets.
+        ///
+        /// * 'AxonNotServedOnAnySubnet':
+        /// 	- The axon could not be served on any of the subnets.
+        ///
+        #[pallet::call_index(80)]
+        #[pallet::weight((Weight::from_parts(46_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(4))
+		.saturating_add(T::DbWeight::get().writes(2))
+		.saturating_mul(netuids.len() as u64), DispatchClass::Normal, Pays::No))]
+        pub fn serve_axon_multi(
+            origin: OriginFor<T>,
+            netuids: BoundedVec<u16, T::MaxServeAxonNetuids>,
+            version: u32,
+            endpoints: BoundedVec<AxonEndpoint, MaxAxonEndpoints>,
+        ) -> DispatchResult {
+            Self::do_serve_axon_multi(origin, netuids, version, endpoints)
+        }
+
         /// ---- Set prometheus information for the neuron.
         /// # Args:
         /// * 'origin': (<T as frame_system::Config>Origin):
@@ -1915,6 +2597,40 @@ pub mod pallet {
             Self::do_burned_registration(origin, netuid, hotkey)
         }
 
+        /// The neuron of the calling hotkey leaves the subnetwork, freeing its uid immediately.
+        /// Its axon, weights and bonds are cleared, and the uid is filled by the next registration
+        /// before any neuron is pruned. A governance set fraction of the burn paid by its burned
+        /// registration is refunded to the coldkey of the hotkey.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the calling hotkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The u16 network identifier.
+        ///
+        /// # Event:
+        /// * NeuronDeregistered;
+        /// 	- On successfully freeing the uid.
+        ///
+        /// # Raises:
+        /// * 'DeregistrationNotPermittedOnRootSubnet':
+        /// 	- Attempting to deregister from the root network.
+        ///
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- Attempting to deregister from a non existent network.
+        ///
+        /// * 'HotKeyNotRegisteredInSubNet':
+        /// 	- The hotkey is not registered on the network.
+        ///
+        #[pallet::call_index(81)]
+        #[pallet::weight((Weight::from_parts(120_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(22))
+		.saturating_add(T::DbWeight::get().writes(24)), DispatchClass::Normal, Pays::Yes))]
+        pub fn deregister(origin: OriginFor<T>, netuid: u16) -> DispatchResult {
+            Self::do_deregister(origin, netuid)
+        }
+
         /// The extrinsic for user to change its hotkey
         #[pallet::call_index(70)]
         #[pallet::weight((Weight::from_parts(1_940_000_000, 0)
@@ -1928,6 +2644,265 @@ pub mod pallet {
             Self::do_swap_hotkey(origin, &hotkey, &new_hotkey)
         }
 
+        /// Schedules the swap of the calling coldkey to a new coldkey. The swap can be executed
+        /// once the announcement delay has passed, giving the owner time to cancel it.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey to swap.
+        ///
+        /// * 'new_coldkey' (T::AccountId):
+        /// 	- The coldkey that receives the position.
+        ///
+        /// # Event:
+        /// * ColdkeySwapScheduled;
+        /// 	- On successfully scheduling the swap.
+        ///
+        /// # Raises:
+        /// * 'NewColdKeyIsSameWithOld':
+        /// 	- The new coldkey is the calling coldkey.
+        ///
+        /// * 'ColdkeySwapAlreadyScheduled':
+        /// 	- A swap is already scheduled for the calling coldkey.
+        ///
+        #[pallet::call_index(74)]
+        #[pallet::weight((Weight::from_parts(21_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::Yes))]
+        pub fn schedule_coldkey_swap(
+            origin: OriginFor<T>,
+            new_coldkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_schedule_coldkey_swap(origin, new_coldkey)
+        }
+
+        /// Cancels the coldkey swap scheduled for the calling coldkey.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey with the scheduled swap.
+        ///
+        /// # Event:
+        /// * ColdkeySwapCancelled;
+        /// 	- On successfully cancelling the swap.
+        ///
+        /// # Raises:
+        /// * 'NoColdkeySwapScheduled':
+        /// 	- No swap is scheduled for the calling coldkey.
+        ///
+        #[pallet::call_index(75)]
+        #[pallet::weight((Weight::from_parts(19_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn cancel_coldkey_swap(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_cancel_coldkey_swap(origin)
+        }
+
+        /// Executes a scheduled coldkey swap, moving the owned hotkeys, the stake, the owned
+        /// subnets and the free balance to the new coldkey. Any signed account can execute a swap
+        /// whose announcement delay has passed, the council or root can execute it at any time.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- Any signed account, the council or root.
+        ///
+        /// * 'coldkey' (T::AccountId):
+        /// 	- The coldkey whose scheduled swap is executed.
+        ///
+        /// # Event:
+        /// * ColdkeySwapped;
+        /// 	- On successfully swapping the coldkey.
+        ///
+        /// # Raises:
+        /// * 'NoColdkeySwapScheduled':
+        /// 	- No swap is scheduled for the coldkey.
+        ///
+        /// * 'ColdkeySwapNotReady':
+        /// 	- A signed account executes the swap before the announcement delay has passed.
+        ///
+        #[pallet::call_index(76)]
+        #[pallet::weight((Weight::from_parts(1_940_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(272))
+		.saturating_add(T::DbWeight::get().writes(527)), DispatchClass::Operational, Pays::No))]
+        pub fn execute_coldkey_swap(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_execute_coldkey_swap(origin, coldkey)
+        }
+
+        /// Cancels the coldkey swap scheduled for a coldkey on behalf of the council or root.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The council or root.
+        ///
+        /// * 'coldkey' (T::AccountId):
+        /// 	- The coldkey whose scheduled swap is cancelled.
+        ///
+        /// # Event:
+        /// * ColdkeySwapCancelled;
+        /// 	- On successfully cancelling the swap.
+        ///
+        /// # Raises:
+        /// * 'NoColdkeySwapScheduled':
+        /// 	- No swap is scheduled for the coldkey.
+        ///
+        #[pallet::call_index(82)]
+        #[pallet::weight((Weight::from_parts(19_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn force_cancel_coldkey_swap(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_force_cancel_coldkey_swap(origin, coldkey)
+        }
+
+        /// Replaces the destination of the coldkey swap scheduled for a coldkey on behalf of the
+        /// council or root. The execution block is kept.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The council or root.
+        ///
+        /// * 'coldkey' (T::AccountId):
+        /// 	- The coldkey whose scheduled swap is changed.
+        ///
+        /// * 'new_coldkey' (T::AccountId):
+        /// 	- The coldkey that receives the position instead.
+        ///
+        /// # Event:
+        /// * ColdkeySwapDestinationOverridden;
+        /// 	- On successfully replacing the destination.
+        ///
+        /// # Raises:
+        /// * 'NoColdkeySwapScheduled':
+        /// 	- No swap is scheduled for the coldkey.
+        ///
+        /// * 'NewColdKeyIsSameWithOld':
+        /// 	- The new destination is the coldkey itself.
+        ///
+        #[pallet::call_index(83)]
+        #[pallet::weight((Weight::from_parts(19_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Operational, Pays::No))]
+        pub fn override_coldkey_swap_destination(
+            origin: OriginFor<T>,
+            coldkey: T::AccountId,
+            new_coldkey: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_override_coldkey_swap_destination(origin, coldkey, new_coldkey)
+        }
+
+        /// Sets the split of the emission of a subnet into an owner cut, a burned share and the
+        /// shares of extra recipient accounts. The remainder is queued for the epoch of the subnet.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the subnet owner coldkey.
+        ///
+        /// * 'netuid' (u16):
+        /// 	- The subnet to set the emission split of.
+        ///
+        /// * 'owner_cut' (u16):
+        /// 	- The share credited to the subnet owner, normalized to u16::MAX.
+        ///
+        /// * 'burn' (u16):
+        /// 	- The share that is burned, normalized to u16::MAX.
+        ///
+        /// * 'recipients' (BoundedVec<(T::AccountId, u16), T::MaxEmissionSplitRecipients>):
+        /// 	- The extra recipient accounts and their shares, normalized to u16::MAX.
+        ///
+        /// # Event:
+        /// * EmissionSplitSet;
+        /// 	- On successfully setting the emission split.
+        ///
+        /// # Raises:
+        /// * 'SubNetworkDoesNotExist':
+        /// 	- The subnet does not exist.
+        ///
+        /// * 'NotSubnetOwner':
+        /// 	- The caller does not own the subnet.
+        ///
+        /// * 'DuplicateEmissionSplitRecipient':
+        /// 	- A recipient is passed more than once.
+        ///
+        /// * 'EmissionSplitShareTooHigh':
+        /// 	- The shares add up to more than MaxEmissionSplitShare.
+        ///
+        #[pallet::call_index(77)]
+        #[pallet::weight((Weight::from_parts(22_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(3))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn set_emission_split(
+            origin: OriginFor<T>,
+            netuid: u16,
+            owner_cut: u16,
+            burn: u16,
+            recipients: BoundedVec<(T::AccountId, u16), T::MaxEmissionSplitRecipients>,
+        ) -> DispatchResult {
+            Self::do_set_emission_split(origin, netuid, owner_cut, burn, recipients.into_inner())
+        }
+
+        /// Sets where the nominator rewards of the calling coldkey on a hotkey are paid: compounded
+        /// into its stake, to its free balance or to the free balance of another account.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the nominating coldkey.
+        ///
+        /// * 'hotkey' (T::AccountId):
+        /// 	- The hotkey the rewards are earned on.
+        ///
+        /// * 'destination' (RewardDestination<T::AccountId>):
+        /// 	- Where the rewards are paid.
+        ///
+        /// # Event:
+        /// * RewardDestinationSet;
+        /// 	- On successfully setting the reward destination.
+        ///
+        /// # Raises:
+        /// * 'HotKeyAccountNotExists':
+        /// 	- The hotkey does not exist.
+        ///
+        /// * 'NoStakeOnHotkey':
+        /// 	- The caller has no stake on the hotkey.
+        ///
+        #[pallet::call_index(78)]
+        #[pallet::weight((Weight::from_parts(16_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(2))
+		.saturating_add(T::DbWeight::get().writes(1)), DispatchClass::Normal, Pays::No))]
+        pub fn set_reward_destination(
+            origin: OriginFor<T>,
+            hotkey: T::AccountId,
+            destination: RewardDestination<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_set_reward_destination(origin, hotkey, destination)
+        }
+
+        /// Sets whether the emission ledger of the calling coldkey is kept. Disabling it removes
+        /// the records already written.
+        ///
+        /// # Args:
+        /// * 'origin': (<T as frame_system::Config>Origin):
+        /// 	- The signature of the coldkey.
+        ///
+        /// * 'enabled' (bool):
+        /// 	- Whether emission records are written for the coldkey.
+        ///
+        /// # Event:
+        /// * EmissionLedgerEnabledSet;
+        /// 	- On successfully setting the flag.
+        ///
+        #[pallet::call_index(84)]
+        #[pallet::weight((Weight::from_parts(16_000_000, 0)
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(T::DbWeight::get().writes(2_u64.saturating_add(T::MaxEmissionLedgerEntries::get() as u64))), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_emission_ledger_enabled(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            Self::do_set_emission_ledger_enabled(origin, enabled)
+        }
+
         // ---- SUDO ONLY FUNCTIONS ------------------------------------------------------------
 
         // ==================================
@@ -2042,7 +3017,7 @@ pub mod pallet {
             Err(Error::<T>::FaucetDisabled.into())
         }
 
-        /// Remove a user's subnetwork
+        /// Announce the dissolution of a user's subnetwork, it is removed after the dissolve period.
         /// The caller must be the owner of the network
         #[pallet::call_index(61)]
         #[pallet::weight((Weight::from_parts(119_000_000, 0)
@@ -2084,6 +3059,9 @@ pub mod pallet {
             if !Self::get_network_registration_allowed(netuid) {
                 return false;
             }
+            if Self::is_network_dissolving(netuid) {
+                return false;
+            }
             if Self::get_registrations_this_block(netuid)
                 >= Self::get_max_registrations_per_block(netuid)
             {
@@ -2400,6 +3378,59 @@ impl<T> MemberManagement<T> for () {
     }
 }
 
+/// Trait for pallets that keep state keyed by hotkey and need to follow a hotkey swap
+pub trait OnHotkeySwapped<AccountId> {
+    /// Called once the subtensor storage has been moved, with the netuids the hotkey is registered on
+    fn on_hotkey_swapped(
+        old_hotkey: &AccountId,
+        new_hotkey: &AccountId,
+        netuids: &[u16],
+    ) -> frame_support::weights::Weight;
+}
+
+impl<A> OnHotkeySwapped<A> for () {
+    fn on_hotkey_swapped(_: &A, _: &A, _: &[u16]) -> frame_support::weights::Weight {
+        frame_support::weights::Weight::zero()
+    }
+}
+
+impl<A, X: OnHotkeySwapped<A>, Y: OnHotkeySwapped<A>> OnHotkeySwapped<A> for (X, Y) {
+    fn on_hotkey_swapped(
+        old_hotkey: &A,
+        new_hotkey: &A,
+        netuids: &[u16],
+    ) -> frame_support::weights::Weight {
+        X::on_hotkey_swapped(old_hotkey, new_hotkey, netuids)
+            .saturating_add(Y::on_hotkey_swapped(old_hotkey, new_hotkey, netuids))
+    }
+}
+
+/// Trait for the source of the round keys that timelocked weight commits are encrypted against.
+pub trait TimelockKeySource {
+    /// Returns true if round keys are ever published, timelocked commits are refused otherwise.
+    fn is_available() -> bool;
+
+    /// Returns the key of the round once it has been published.
+    fn round_key(round: u64) -> Option<Vec<u8>>;
+
+    /// Decrypts a payload encrypted against the round with its published key.
+    fn decrypt(round: u64, round_key: &[u8], ciphertext: &[u8]) -> Option<Vec<u8>>;
+}
+
+impl TimelockKeySource for () {
+    fn is_available() -> bool {
+        false
+    }
+
+    fn round_key(_: u64) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn decrypt(_: u64, _: &[u8], _: &[u8]) -> Option<Vec<u8>> {
+        None
+    }
+}
+
 /// Trait for interacting with collective pallets
 pub trait CollectiveInterface<AccountId, Hash, ProposalIndex> {
     /// Remove vote
@@ -1100,6 +1100,146 @@ pub fn mat_ema_sparse(
     result
 }
 
+// Return matrix exponential moving average with a coefficient per element: `alpha_ij * a_ij + (1 - alpha_ij) * b_ij`.
+#[allow(dead_code)]
+pub fn mat_ema_alpha(
+    new: &[Vec<I32F32>],
+    old: &[Vec<I32F32>],
+    alpha: &[Vec<I32F32>],
+) -> Vec<Vec<I32F32>> {
+    let Some(first_row) = new.first() else {
+        return vec![vec![]];
+    };
+    if first_row.is_empty() {
+        return vec![vec![]; 1];
+    }
+    let one: I32F32 = I32F32::from_num(1.0);
+    new.iter()
+        .zip(old)
+        .zip(alpha)
+        .map(|((new_row, old_row), alpha_row)| {
+            new_row
+                .iter()
+                .zip(old_row)
+                .zip(alpha_row)
+                .map(|((new_elem, old_elem), alpha_elem)| {
+                    alpha_elem * new_elem + (one - alpha_elem) * old_elem
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Return sparse matrix exponential moving average with a coefficient per element: `alpha_ij * a_ij + (1 - alpha_ij) * b_ij`.
+// `alpha` is sparse, elements it does not list use `default_alpha`.
+#[allow(dead_code, clippy::indexing_slicing)]
+pub fn mat_ema_alpha_sparse(
+    new: &[Vec<(u16, I32F32)>],
+    old: &[Vec<(u16, I32F32)>],
+    alpha: &[Vec<(u16, I32F32)>],
+    default_alpha: I32F32,
+) -> Vec<Vec<(u16, I32F32)>> {
+    assert!(new.len() == old.len());
+    assert!(new.len() == alpha.len());
+    let n = new.len(); // assume square matrix, rows=cols
+    let zero: I32F32 = I32F32::from_num(0.0);
+    let one: I32F32 = I32F32::from_num(1.0);
+    let mut result: Vec<Vec<(u16, I32F32)>> = vec![vec![]; n];
+    for i in 0..new.len() {
+        let mut row_alpha: Vec<I32F32> = vec![default_alpha; n];
+        for (j, value) in alpha[i].iter() {
+            row_alpha[*j as usize] = *value;
+        }
+        let mut row: Vec<I32F32> = vec![zero; n];
+        for (j, value) in new[i].iter() {
+            row[*j as usize] += row_alpha[*j as usize] * value;
+        }
+        for (j, value) in old[i].iter() {
+            row[*j as usize] += (one - row_alpha[*j as usize]) * value;
+        }
+        for (j, value) in row.iter().enumerate() {
+            if *value > zero {
+                result[i].push((j as u16, *value))
+            }
+        }
+    }
+    result
+}
+
+// Return the liquid alpha of a weight w_ij given the consensus c_j of its column:
+// `alpha_low + (alpha_high - alpha_low) * sigmoid(steepness * (x - 1/2))`, where x = max(w_ij - c_j, 0) / w_ij
+// is the share of the weight that is ahead of consensus (the share clipped away by consensus).
+#[allow(dead_code)]
+pub fn liquid_alpha(
+    weight: I32F32,
+    consensus: I32F32,
+    alpha_low: I32F32,
+    alpha_high: I32F32,
+    steepness: I32F32,
+) -> I32F32 {
+    let zero: I32F32 = I32F32::from_num(0);
+    let ahead: I32F32 = if weight > zero {
+        weight.saturating_sub(consensus).max(zero) / weight
+    } else {
+        zero
+    };
+    let sigmoid: I32F32 = sigmoid_safe(ahead, steepness, I32F32::from_num(0.5));
+    alpha_low.saturating_add(alpha_high.saturating_sub(alpha_low).saturating_mul(sigmoid))
+}
+
+// Return the liquid alpha of every element of a weight matrix, see `liquid_alpha`.
+#[allow(dead_code)]
+pub fn mat_liquid_alpha(
+    weights: &[Vec<I32F32>],
+    consensus: &[I32F32],
+    alpha_low: I32F32,
+    alpha_high: I32F32,
+    steepness: I32F32,
+) -> Vec<Vec<I32F32>> {
+    weights
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(consensus)
+                .map(|(weight, consensus)| {
+                    liquid_alpha(*weight, *consensus, alpha_low, alpha_high, steepness)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// Return the liquid alpha of every element of a sparse weight matrix, see `liquid_alpha`.
+// Elements missing from the weight matrix have the liquid alpha of a zero weight.
+#[allow(dead_code, clippy::indexing_slicing)]
+pub fn mat_liquid_alpha_sparse(
+    weights: &[Vec<(u16, I32F32)>],
+    consensus: &[I32F32],
+    alpha_low: I32F32,
+    alpha_high: I32F32,
+    steepness: I32F32,
+) -> Vec<Vec<(u16, I32F32)>> {
+    weights
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|(j, weight)| {
+                    (
+                        *j,
+                        liquid_alpha(
+                            *weight,
+                            consensus[*j as usize],
+                            alpha_low,
+                            alpha_high,
+                            steepness,
+                        ),
+                    )
+                })
+                .collect()
+        })
+        .collect()
+}
+
 // Return sparse matrix only with elements >= threshold of an input sparse matrix.
 #[allow(dead_code)]
 pub fn sparse_threshold(w: &[Vec<(u16, I32F32)>], threshold: I32F32) -> Vec<Vec<(u16, I32F32)>> {
@@ -2971,6 +3111,92 @@ mod tests {
         assert_sparse_mat_compare(&result, &target, I32F32::from_num(0.000001));
     }
 
+    #[test]
+    fn test_math_mat_ema_alpha() {
+        let old: Vec<f32> = vec![1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.];
+        let new: Vec<f32> = vec![
+            10., 20., 30., 40., 50., 60., 70., 80., 90., 100., 110., 120.,
+        ];
+        let alpha: Vec<f32> = vec![0.1, 0.1, 0.1, 0., 0., 0., 1., 1., 1., 0.5, 0.5, 0.5];
+        let target: Vec<f32> = vec![1.9, 3.8, 5.7, 4., 5., 6., 70., 80., 90., 55., 60.5, 66.];
+        let old = vec_to_mat_fixed(&old, 4, false);
+        let new = vec_to_mat_fixed(&new, 4, false);
+        let alpha = vec_to_mat_fixed(&alpha, 4, false);
+        let target = vec_to_mat_fixed(&target, 4, false);
+        let result = mat_ema_alpha(&new, &old, &alpha);
+        assert_mat_compare(&result, &target, I32F32::from_num(0.000001));
+    }
+
+    #[test]
+    fn test_math_sparse_mat_ema_alpha() {
+        let old: Vec<f32> = vec![0., 2., 3., 4., 0., 6., 7., 8., 0., 10., 11., 12.];
+        let new: Vec<f32> = vec![10., 20., 0., 40., 0., 60., 0., 80., 90., 100., 110., 120.];
+        // Missing alphas default to 0.1.
+        let alpha: Vec<f32> = vec![0., 0.5, 0., 1., 0., 0., 0., 0., 0., 0., 0., 0.];
+        let target: Vec<f32> = vec![1., 11., 2.7, 40., 0., 11.4, 6.3, 15.2, 9., 19., 20.9, 22.8];
+        let old = vec_to_sparse_mat_fixed(&old, 4, false);
+        let new = vec_to_sparse_mat_fixed(&new, 4, false);
+        let alpha = vec_to_sparse_mat_fixed(&alpha, 4, false);
+        let target = vec_to_sparse_mat_fixed(&target, 4, false);
+        let result = mat_ema_alpha_sparse(&new, &old, &alpha, I32F32::from_num(0.1));
+        assert_sparse_mat_compare(&result, &target, I32F32::from_num(0.000001));
+    }
+
+    #[test]
+    fn test_math_liquid_alpha() {
+        let epsilon: I32F32 = I32F32::from_num(0.0001);
+        let zero: I32F32 = I32F32::from_num(0);
+        let alpha_low: I32F32 = I32F32::from_num(0.1);
+        let alpha_high: I32F32 = I32F32::from_num(0.3);
+        let steepness: I32F32 = I32F32::from_num(10);
+        let alpha = |weight: f32, consensus: f32| {
+            liquid_alpha(
+                I32F32::from_num(weight),
+                I32F32::from_num(consensus),
+                alpha_low,
+                alpha_high,
+                steepness,
+            )
+        };
+        // Weights at or below consensus, and zero weights, are close to alpha_low.
+        let at_consensus: I32F32 = alpha(0.2, 0.2);
+        assert_float_compare(at_consensus, I32F32::from_num(0.1013), epsilon);
+        assert_eq!(alpha(0.1, 0.2), at_consensus);
+        assert_eq!(alpha(0., 0.2), at_consensus);
+        // Weights entirely ahead of consensus are close to alpha_high.
+        assert_float_compare(alpha(0.2, 0.), I32F32::from_num(0.2987), epsilon);
+        // Weights half ahead of consensus are halfway.
+        assert_float_compare(alpha(0.2, 0.1), I32F32::from_num(0.2), epsilon);
+        // Liquid alpha grows with the share ahead of consensus.
+        assert!(alpha(0.2, 0.15) < alpha(0.2, 0.05));
+        // Equal bounds give a constant alpha.
+        assert_eq!(
+            liquid_alpha(I32F32::from_num(0.2), zero, alpha_low, alpha_low, steepness),
+            alpha_low
+        );
+
+        let weights: Vec<f32> = vec![0.2, 0., 0.1, 0.1, 0., 0.2];
+        let consensus: Vec<I32F32> = vec![I32F32::from_num(0.1), I32F32::from_num(0.2)];
+        let dense = mat_liquid_alpha(
+            &vec_to_mat_fixed(&weights, 3, false),
+            &consensus,
+            alpha_low,
+            alpha_high,
+            steepness,
+        );
+        let sparse = mat_liquid_alpha_sparse(
+            &vec_to_sparse_mat_fixed(&weights, 3, false),
+            &consensus,
+            alpha_low,
+            alpha_high,
+            steepness,
+        );
+        assert_eq!(dense[0][0], alpha(0.2, 0.1));
+        assert_eq!(dense[0][1], at_consensus);
+        assert_eq!(sparse[0], vec![(0, alpha(0.2, 0.1))]);
+        assert_eq!(sparse[2], vec![(1, alpha(0.2, 0.2))]);
+    }
+
     #[test]
     fn test_math_matmul2() {
         let epsilon: I32F32 = I32F32::from_num(0.0001);
@@ -0,0 +1,115 @@
+use super::*;
+use crate::subnet_info::SubnetHyperparams;
+use frame_support::pallet_prelude::{Decode, Encode};
+extern crate alloc;
+use codec::Compact;
+
+/// The full metagraph of a subnet, column-oriented and indexed by uid, together with its
+/// hyperparameters. Everything is read from the same state. A freed uid has no hotkey and
+/// coldkey, and no stake.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct Metagraph<T: Config> {
+    netuid: Compact<u16>,
+    block: Compact<u64>,
+    n: Compact<u16>,
+    hyperparams: SubnetHyperparams,
+    hotkeys: Vec<Option<T::AccountId>>,
+    coldkeys: Vec<Option<T::AccountId>>,
+    active: Vec<bool>,
+    last_update: Vec<Compact<u64>>,
+    validator_permit: Vec<bool>,
+    stake: Vec<Compact<u64>>,
+    rank: Vec<Compact<u16>>,
+    trust: Vec<Compact<u16>>,
+    consensus: Vec<Compact<u16>>,
+    validator_trust: Vec<Compact<u16>>,
+    incentive: Vec<Compact<u16>>,
+    dividends: Vec<Compact<u16>>,
+    emission: Vec<Compact<u64>>,
+    axons: Vec<AxonInfo>,
+    axons_v2: Vec<AxonInfoV2>, // all endpoints of each axon, axons holds the first ip endpoint
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn get_metagraph(netuid: u16) -> Option<Metagraph<T>> {
+        let hyperparams = Self::get_subnet_hyperparams(netuid)?;
+
+        let n: u16 = Self::get_subnetwork_n(netuid);
+        let hotkeys: Vec<Option<T::AccountId>> = (0..n)
+            .map(|uid| Keys::<T>::try_get(netuid, uid).ok())
+            .collect();
+        let coldkeys: Vec<Option<T::AccountId>> = hotkeys
+            .iter()
+            .map(|hotkey| hotkey.as_ref().map(Owner::<T>::get))
+            .collect();
+        let stake: Vec<Compact<u64>> = hotkeys
+            .iter()
+            .map(|hotkey| {
+                hotkey
+                    .as_ref()
+                    .map_or(0, Self::get_total_stake_for_hotkey)
+                    .into()
+            })
+            .collect();
+        let axons: Vec<AxonInfo> = hotkeys
+            .iter()
+            .map(|hotkey| {
+                hotkey
+                    .as_ref()
+                    .map(|hotkey| Self::get_axon_info(netuid, hotkey))
+                    .unwrap_or_default()
+            })
+            .collect();
+        let axons_v2: Vec<AxonInfoV2> = hotkeys
+            .iter()
+            .map(|hotkey| {
+                hotkey
+                    .as_ref()
+                    .map(|hotkey| Self::get_axon_info_v2(netuid, hotkey))
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        Some(Metagraph {
+            netuid: netuid.into(),
+            block: Self::get_current_block_as_u64().into(),
+            n: n.into(),
+            hyperparams,
+            coldkeys,
+            active: (0..n)
+                .map(|uid| Self::get_active_for_uid(netuid, uid))
+                .collect(),
+            last_update: (0..n)
+                .map(|uid| Self::get_last_update_for_uid(netuid, uid).into())
+                .collect(),
+            validator_permit: (0..n)
+                .map(|uid| Self::get_validator_permit_for_uid(netuid, uid))
+                .collect(),
+            stake,
+            rank: (0..n)
+                .map(|uid| Self::get_rank_for_uid(netuid, uid).into())
+                .collect(),
+            trust: (0..n)
+                .map(|uid| Self::get_trust_for_uid(netuid, uid).into())
+                .collect(),
+            consensus: (0..n)
+                .map(|uid| Self::get_consensus_for_uid(netuid, uid).into())
+                .collect(),
+            validator_trust: (0..n)
+                .map(|uid| Self::get_validator_trust_for_uid(netuid, uid).into())
+                .collect(),
+            incentive: (0..n)
+                .map(|uid| Self::get_incentive_for_uid(netuid, uid).into())
+                .collect(),
+            dividends: (0..n)
+                .map(|uid| Self::get_dividends_for_uid(netuid, uid).into())
+                .collect(),
+            emission: (0..n)
+                .map(|uid| Self::get_emission_for_uid(netuid, uid).into())
+                .collect(),
+            axons,
+            axons_v2,
+            hotkeys,
+        })
+    }
+}
@@ -474,3 +474,114 @@ pub fn migrate_to_v2_fixed_total_stake<T: Config>() -> Weight {
         Weight::zero()
     }
 }
+
+const LOG_TARGET_7: &str = "weightcommitqueue";
+
+/// Wraps every single pending `(hash, block)` weight commit into a queue of pending commits.
+pub fn migrate_to_v7_weight_commit_queue<T: Config>() -> Weight {
+    let new_storage_version = 7;
+
+    // Setup migration weight
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Grab current version
+    let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+    // Only runs if we haven't already updated version past above new_storage_version.
+    if onchain_version < new_storage_version {
+        info!(target: LOG_TARGET_7, ">>> Migrating WeightCommits to a queue of pending commits {:?}", onchain_version);
+
+        let mut translated: u64 = 0;
+        WeightCommits::<T>::translate::<(sp_core::H256, u64), _>(|_netuid, _hotkey, commit| {
+            translated = translated.saturating_add(1);
+            Some(sp_std::vec![commit])
+        });
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(translated, translated));
+
+        // Update storage version.
+        StorageVersion::new(new_storage_version).put::<Pallet<T>>(); // Update to version so we don't run this again.
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        weight
+    } else {
+        info!(target: LOG_TARGET_7, "Migration to v7 already done!");
+        Weight::zero()
+    }
+}
+
+const LOG_TARGET_8: &str = "axoninfov2";
+
+/// Serves every axon of the first version as an axon of the second version, with a single
+/// endpoint. The axons of the first version are kept for their clients.
+pub fn migrate_to_v8_axon_info_v2<T: Config>() -> Weight {
+    let new_storage_version = 8;
+
+    // Setup migration weight
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Grab current version
+    let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+    // Only runs if we haven't already updated version past above new_storage_version.
+    if onchain_version < new_storage_version {
+        info!(target: LOG_TARGET_8, ">>> Migrating Axons to AxonsV2 {:?}", onchain_version);
+
+        let mut migrated: u64 = 0;
+        for (netuid, hotkey, axon_info) in Axons::<T>::iter() {
+            AxonsV2::<T>::insert(netuid, hotkey, AxonInfoV2::from(&axon_info));
+            migrated = migrated.saturating_add(1);
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(migrated, migrated));
+
+        // Update storage version.
+        StorageVersion::new(new_storage_version).put::<Pallet<T>>(); // Update to version so we don't run this again.
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        weight
+    } else {
+        info!(target: LOG_TARGET_8, "Migration to v8 already done!");
+        Weight::zero()
+    }
+}
+
+const LOG_TARGET_9: &str = "coldkeyhotkeyindexes";
+
+/// Fills the hotkeys owned by every coldkey and the hotkeys every coldkey has a stake entry on,
+/// so a coldkey swap does not have to walk the whole owner and stake maps.
+pub fn migrate_to_v9_coldkey_hotkey_indexes<T: Config>() -> Weight {
+    let new_storage_version = 9;
+
+    // Setup migration weight
+    let mut weight = T::DbWeight::get().reads(1);
+
+    // Grab current version
+    let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+    // Only runs if we haven't already updated version past above new_storage_version.
+    if onchain_version < new_storage_version {
+        info!(target: LOG_TARGET_9, ">>> Indexing the hotkeys of every coldkey {:?}", onchain_version);
+
+        let mut owners: u64 = 0;
+        for (hotkey, coldkey) in Owner::<T>::iter() {
+            OwnedHotkeys::<T>::append(coldkey, hotkey);
+            owners = owners.saturating_add(1);
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(owners, owners));
+
+        let mut stakes: u64 = 0;
+        for (hotkey, coldkey) in Stake::<T>::iter_keys() {
+            StakingHotkeys::<T>::append(coldkey, hotkey);
+            stakes = stakes.saturating_add(1);
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(stakes, stakes));
+
+        // Update storage version.
+        StorageVersion::new(new_storage_version).put::<Pallet<T>>(); // Update to version so we don't run this again.
+        weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+        weight
+    } else {
+        info!(target: LOG_TARGET_9, "Migration to v9 already done!");
+        Weight::zero()
+    }
+}
@@ -51,6 +51,31 @@ pub struct NeuronInfoLite<T: Config> {
     pruning_score: Compact<u16>,
 }
 
+/// A neuron with all endpoints of its axon, axon_info of the neuron is the first ip endpoint.
+/// Encodes as the fields of NeuronInfo followed by axon_info_v2.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct NeuronInfoV2<T: Config> {
+    neuron: NeuronInfo<T>,
+    axon_info_v2: AxonInfoV2,
+}
+
+/// A lite neuron with all endpoints of its axon, see NeuronInfoV2.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct NeuronInfoLiteV2<T: Config> {
+    neuron: NeuronInfoLite<T>,
+    axon_info_v2: AxonInfoV2,
+}
+
+/// Field-selection flags of the paged neuron queries, a skipped field is returned empty.
+pub const NEURON_SKIP_WEIGHTS: u8 = 1 << 0;
+pub const NEURON_SKIP_BONDS: u8 = 1 << 1;
+pub const NEURON_SKIP_AXON: u8 = 1 << 2;
+pub const NEURON_SKIP_PROMETHEUS: u8 = 1 << 3;
+
+/// Filters of the paged neuron queries, only neurons matching every set filter are returned.
+pub const NEURON_FILTER_VALIDATORS: u8 = 1 << 0;
+pub const NEURON_FILTER_ACTIVE: u8 = 1 << 1;
+
 impl<T: Config> Pallet<T> {
     pub fn get_neurons(netuid: u16) -> Vec<NeuronInfo<T>> {
         if !Self::if_subnet_exist(netuid) {
@@ -60,9 +85,9 @@ impl<T: Config> Pallet<T> {
         let mut neurons = Vec::new();
         let n = Self::get_subnetwork_n(netuid);
         for uid in 0..n {
-            let neuron = match Self::get_neuron_subnet_exists(netuid, uid) {
+            let neuron = match Self::get_neuron_subnet_exists(netuid, uid, 0) {
                 Some(n) => n,
-                None => break, // No more neurons
+                None => continue, // Freed uid
             };
 
             neurons.push(neuron);
@@ -70,15 +95,67 @@ impl<T: Config> Pallet<T> {
         neurons
     }
 
-    fn get_neuron_subnet_exists(netuid: u16, uid: u16) -> Option<NeuronInfo<T>> {
+    /// Returns up to limit neurons of a subnet matching the filters, starting at start_uid.
+    /// Skipped fields are returned empty. The next page starts after the uid of the last neuron.
+    pub fn get_neurons_paged(
+        netuid: u16,
+        start_uid: u16,
+        limit: u16,
+        skip_fields: u8,
+        filters: u8,
+    ) -> Vec<NeuronInfo<T>> {
+        if !Self::if_subnet_exist(netuid) {
+            return Vec::new();
+        }
+
+        let mut neurons = Vec::new();
+        let n = Self::get_subnetwork_n(netuid);
+        for uid in start_uid..n {
+            if neurons.len() >= limit as usize {
+                break;
+            }
+            if !Self::neuron_matches_filters(netuid, uid, filters) {
+                continue;
+            }
+            let neuron = match Self::get_neuron_subnet_exists(netuid, uid, skip_fields) {
+                Some(n) => n,
+                None => continue, // Freed uid
+            };
+
+            neurons.push(neuron);
+        }
+        neurons
+    }
+
+    fn neuron_matches_filters(netuid: u16, uid: u16, filters: u8) -> bool {
+        if filters & NEURON_FILTER_VALIDATORS != 0
+            && !Self::get_validator_permit_for_uid(netuid, uid)
+        {
+            return false;
+        }
+        if filters & NEURON_FILTER_ACTIVE != 0 && !Self::get_active_for_uid(netuid, uid) {
+            return false;
+        }
+        true
+    }
+
+    fn get_neuron_subnet_exists(netuid: u16, uid: u16, skip_fields: u8) -> Option<NeuronInfo<T>> {
         let hotkey = match Self::get_hotkey_for_net_and_uid(netuid, uid) {
             Ok(h) => h,
             Err(_) => return None,
         };
 
-        let axon_info = Self::get_axon_info(netuid, &hotkey.clone());
+        let axon_info = if skip_fields & NEURON_SKIP_AXON == 0 {
+            Self::get_axon_info(netuid, &hotkey.clone())
+        } else {
+            AxonInfo::default()
+        };
 
-        let prometheus_info = Self::get_prometheus_info(netuid, &hotkey.clone());
+        let prometheus_info = if skip_fields & NEURON_SKIP_PROMETHEUS == 0 {
+            Self::get_prometheus_info(netuid, &hotkey.clone())
+        } else {
+            PrometheusInfo::default()
+        };
 
         let coldkey = Owner::<T>::get(hotkey.clone()).clone();
 
@@ -94,27 +171,35 @@ impl<T: Config> Pallet<T> {
         let last_update = Self::get_last_update_for_uid(netuid, uid);
         let validator_permit = Self::get_validator_permit_for_uid(netuid, uid);
 
-        let weights = <Weights<T>>::get(netuid, uid)
-            .iter()
-            .filter_map(|(i, w)| {
-                if *w > 0 {
-                    Some((i.into(), w.into()))
-                } else {
-                    None
-                }
-            })
-            .collect::<Vec<(Compact<u16>, Compact<u16>)>>();
-
-        let bonds = <Bonds<T>>::get(netuid, uid)
-            .iter()
-            .filter_map(|(i, b)| {
-                if *b > 0 {
-                    Some((i.into(), b.into()))
-                } else {
-                    None
-                }
-            })
-            .collect::<Vec<(Compact<u16>, Compact<u16>)>>();
+        let weights = if skip_fields & NEURON_SKIP_WEIGHTS == 0 {
+            <Weights<T>>::get(netuid, uid)
+        } else {
+            Vec::new()
+        }
+        .iter()
+        .filter_map(|(i, w)| {
+            if *w > 0 {
+                Some((i.into(), w.into()))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<(Compact<u16>, Compact<u16>)>>();
+
+        let bonds = if skip_fields & NEURON_SKIP_BONDS == 0 {
+            <Bonds<T>>::get(netuid, uid)
+        } else {
+            Vec::new()
+        }
+        .iter()
+        .filter_map(|(i, b)| {
+            if *b > 0 {
+                Some((i.into(), b.into()))
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<(Compact<u16>, Compact<u16>)>>();
 
         let stake: Vec<(T::AccountId, Compact<u64>)> =
             <Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64>>::iter_prefix(
@@ -154,18 +239,30 @@ impl<T: Config> Pallet<T> {
             return None;
         }
 
-        Self::get_neuron_subnet_exists(netuid, uid)
+        Self::get_neuron_subnet_exists(netuid, uid, 0)
     }
 
-    fn get_neuron_lite_subnet_exists(netuid: u16, uid: u16) -> Option<NeuronInfoLite<T>> {
+    fn get_neuron_lite_subnet_exists(
+        netuid: u16,
+        uid: u16,
+        skip_fields: u8,
+    ) -> Option<NeuronInfoLite<T>> {
         let hotkey = match Self::get_hotkey_for_net_and_uid(netuid, uid) {
             Ok(h) => h,
             Err(_) => return None,
         };
 
-        let axon_info = Self::get_axon_info(netuid, &hotkey.clone());
+        let axon_info = if skip_fields & NEURON_SKIP_AXON == 0 {
+            Self::get_axon_info(netuid, &hotkey.clone())
+        } else {
+            AxonInfo::default()
+        };
 
-        let prometheus_info = Self::get_prometheus_info(netuid, &hotkey.clone());
+        let prometheus_info = if skip_fields & NEURON_SKIP_PROMETHEUS == 0 {
+            Self::get_prometheus_info(netuid, &hotkey.clone())
+        } else {
+            PrometheusInfo::default()
+        };
 
         let coldkey = Owner::<T>::get(hotkey.clone()).clone();
 
@@ -220,9 +317,9 @@ impl<T: Config> Pallet<T> {
         let mut neurons: Vec<NeuronInfoLite<T>> = Vec::new();
         let n = Self::get_subnetwork_n(netuid);
         for uid in 0..n {
-            let neuron = match Self::get_neuron_lite_subnet_exists(netuid, uid) {
+            let neuron = match Self::get_neuron_lite_subnet_exists(netuid, uid, 0) {
                 Some(n) => n,
-                None => break, // No more neurons
+                None => continue, // Freed uid
             };
 
             neurons.push(neuron);
@@ -235,6 +332,76 @@ impl<T: Config> Pallet<T> {
             return None;
         }
 
-        Self::get_neuron_lite_subnet_exists(netuid, uid)
+        Self::get_neuron_lite_subnet_exists(netuid, uid, 0)
+    }
+
+    /// Returns up to limit lite neurons of a subnet matching the filters, starting at start_uid.
+    /// Skipped fields are returned empty. The next page starts after the uid of the last neuron.
+    pub fn get_neurons_lite_paged(
+        netuid: u16,
+        start_uid: u16,
+        limit: u16,
+        skip_fields: u8,
+        filters: u8,
+    ) -> Vec<NeuronInfoLite<T>> {
+        if !Self::if_subnet_exist(netuid) {
+            return Vec::new();
+        }
+
+        let mut neurons: Vec<NeuronInfoLite<T>> = Vec::new();
+        let n = Self::get_subnetwork_n(netuid);
+        for uid in start_uid..n {
+            if neurons.len() >= limit as usize {
+                break;
+            }
+            if !Self::neuron_matches_filters(netuid, uid, filters) {
+                continue;
+            }
+            let neuron = match Self::get_neuron_lite_subnet_exists(netuid, uid, skip_fields) {
+                Some(n) => n,
+                None => continue, // Freed uid
+            };
+
+            neurons.push(neuron);
+        }
+        neurons
+    }
+
+    pub fn get_neurons_v2(netuid: u16) -> Vec<NeuronInfoV2<T>> {
+        Self::get_neurons(netuid)
+            .into_iter()
+            .map(Self::with_axon_info_v2)
+            .collect()
+    }
+
+    pub fn get_neuron_v2(netuid: u16, uid: u16) -> Option<NeuronInfoV2<T>> {
+        Self::get_neuron(netuid, uid).map(Self::with_axon_info_v2)
+    }
+
+    pub fn get_neurons_lite_v2(netuid: u16) -> Vec<NeuronInfoLiteV2<T>> {
+        Self::get_neurons_lite(netuid)
+            .into_iter()
+            .map(Self::lite_with_axon_info_v2)
+            .collect()
+    }
+
+    pub fn get_neuron_lite_v2(netuid: u16, uid: u16) -> Option<NeuronInfoLiteV2<T>> {
+        Self::get_neuron_lite(netuid, uid).map(Self::lite_with_axon_info_v2)
+    }
+
+    fn with_axon_info_v2(neuron: NeuronInfo<T>) -> NeuronInfoV2<T> {
+        let axon_info_v2 = Self::get_axon_info_v2(neuron.netuid.0, &neuron.hotkey);
+        NeuronInfoV2 {
+            neuron,
+            axon_info_v2,
+        }
+    }
+
+    fn lite_with_axon_info_v2(neuron: NeuronInfoLite<T>) -> NeuronInfoLiteV2<T> {
+        let axon_info_v2 = Self::get_axon_info_v2(neuron.netuid.0, &neuron.hotkey);
+        NeuronInfoLiteV2 {
+            neuron,
+            axon_info_v2,
+        }
     }
 }
@@ -1,5 +1,8 @@
 use super::*;
 use frame_support::storage::IterableStorageDoubleMap;
+use frame_support::traits::tokens::{fungible::Mutate as _, Preservation};
+use frame_support::traits::EnsureOrigin;
+use frame_support::weights::Weight;
 use sp_core::{Get, H256, U256};
 use sp_io::hashing::{keccak_256, sha2_256};
 use system::pallet_prelude::BlockNumberFor;
@@ -63,6 +66,10 @@ impl<T: Config> Pallet<T> {
             Self::get_network_registration_allowed(netuid),
             Error::<T>::SubNetRegistrationDisabled
         );
+        ensure!(
+            !Self::is_network_dissolving(netuid),
+            Error::<T>::SubnetDissolving
+        );
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per block.
         ensure!(
@@ -124,7 +131,12 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NoNeuronIdAvailable
         );
 
-        if current_subnetwork_n < Self::get_max_allowed_uids(netuid) {
+        if let Some(freed_uid) = Self::take_freed_uid(netuid) {
+            // --- 12.0.1 A uid freed by a deregistration is filled before any other.
+            subnetwork_uid = freed_uid;
+            Self::replace_neuron(netuid, subnetwork_uid, &hotkey, current_block_number);
+            log::info!("fill freed neuron");
+        } else if current_subnetwork_n < Self::get_max_allowed_uids(netuid) {
             // --- 12.1.1 No replacement required, the uid appends the subnetwork.
             // We increment the subnetwork count here but not below.
             subnetwork_uid = current_subnetwork_n;
@@ -143,6 +155,7 @@ impl<T: Config> Pallet<T> {
         }
 
         // --- 14. Record the registration and increment block and interval counters.
+        BurnAtRegistration::<T>::insert(netuid, subnetwork_uid, actual_burn_amount);
         BurnRegistrationsThisInterval::<T>::mutate(netuid, |val| *val += 1);
         RegistrationsThisInterval::<T>::mutate(netuid, |val| *val += 1);
         RegistrationsThisBlock::<T>::mutate(netuid, |val| *val += 1);
@@ -161,6 +174,81 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic deregister: the neuron of the calling hotkey leaves
+    /// the network and its uid is freed for the next registration.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the calling hotkey.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// # Event:
+    /// * NeuronDeregistered;
+    ///     - On successfully freeing the uid, with the amount refunded to the coldkey.
+    ///
+    /// # Raises:
+    /// * 'DeregistrationNotPermittedOnRootSubnet':
+    ///     - Attempting to deregister from the root network.
+    ///
+    /// * 'SubNetworkDoesNotExist':
+    ///     - Attempting to deregister from a non existent network.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The hotkey is not registered on the network.
+    ///
+    pub fn do_deregister(origin: T::RuntimeOrigin, netuid: u16) -> DispatchResult {
+        // --- 1. Check that the hotkey has signed the transaction.
+        let hotkey = ensure_signed(origin)?;
+        log::info!("do_deregister( hotkey:{:?} netuid:{:?} )", hotkey, netuid);
+
+        // --- 2. Ensure the passed network is valid.
+        ensure!(
+            netuid != Self::get_root_netuid(),
+            Error::<T>::DeregistrationNotPermittedOnRootSubnet
+        );
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+
+        // --- 3. Ensure the hotkey holds a uid on the network.
+        let uid: u16 = Self::get_uid_for_net_and_hotkey(netuid, &hotkey)?;
+
+        // --- 4. Free the uid.
+        Self::free_neuron(netuid, uid, &hotkey);
+
+        // --- 5. Refund the governance set fraction of the registration burn to the coldkey.
+        let burn: u64 = BurnAtRegistration::<T>::take(netuid, uid);
+        let refund: u64 = (burn as u128)
+            .saturating_mul(Self::get_deregistration_refund_ratio(netuid) as u128)
+            .checked_div(u16::MAX as u128)
+            .unwrap_or(0) as u64;
+        if refund > 0 {
+            let coldkey: T::AccountId = Owner::<T>::get(&hotkey);
+            Self::add_balance_to_coldkey_account(&coldkey, refund);
+            Self::coinbase(refund);
+            Self::set_rao_recycled(
+                netuid,
+                Self::get_rao_recycled(netuid).saturating_sub(refund),
+            );
+        }
+
+        // --- 6. Deposit successful event.
+        log::info!(
+            "NeuronDeregistered( netuid:{:?} uid:{:?} hotkey:{:?} refund:{:?} ) ",
+            netuid,
+            uid,
+            hotkey,
+            refund
+        );
+        Self::deposit_event(Event::NeuronDeregistered(netuid, uid, hotkey, refund));
+
+        // --- 7. Ok and done.
+        Ok(())
+    }
+
     /// ---- The implementation for the extrinsic do_registration.
     ///
     /// # Args:
@@ -248,6 +336,10 @@ impl<T: Config> Pallet<T> {
             Self::get_network_pow_registration_allowed(netuid),
             Error::<T>::SubNetRegistrationDisabled
         );
+        ensure!(
+            !Self::is_network_dissolving(netuid),
+            Error::<T>::SubnetDissolving
+        );
 
         // --- 4. Ensure we are not exceeding the max allowed registrations per block.
         ensure!(
@@ -319,7 +411,12 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NoNeuronIdAvailable
         );
 
-        if current_subnetwork_n < Self::get_max_allowed_uids(netuid) {
+        if let Some(freed_uid) = Self::take_freed_uid(netuid) {
+            // --- 11.0.1 A uid freed by a deregistration is filled before any other.
+            subnetwork_uid = freed_uid;
+            Self::replace_neuron(netuid, subnetwork_uid, &hotkey, current_block_number);
+            log::info!("fill freed neuron");
+        } else if current_subnetwork_n < Self::get_max_allowed_uids(netuid) {
             // --- 11.1.1 No replacement required, the uid appends the subnetwork.
             // We increment the subnetwork count here but not below.
             subnetwork_uid = current_subnetwork_n;
@@ -631,7 +728,10 @@ impl<T: Config> Pallet<T> {
 
         Owner::<T>::remove(old_hotkey);
         Owner::<T>::insert(new_hotkey, coldkey.clone());
-        weight.saturating_accrue(T::DbWeight::get().writes(2));
+        OwnedHotkeys::<T>::mutate(&coldkey, |hotkeys| {
+            Self::replace_indexed_hotkey(hotkeys, old_hotkey, new_hotkey)
+        });
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
 
         if let Ok(total_hotkey_stake) = TotalHotkeyStake::<T>::try_get(old_hotkey) {
             TotalHotkeyStake::<T>::remove(old_hotkey);
@@ -662,9 +762,39 @@ impl<T: Config> Pallet<T> {
         let _ = Stake::<T>::clear_prefix(old_hotkey, coldkey_stake.len() as u32, None);
         weight.saturating_accrue(T::DbWeight::get().writes(coldkey_stake.len() as u64));
 
+        // Every coldkey with state on the hotkey keeps a stake entry on it, even an empty one,
+        // so the maps keyed by coldkey first are looked up through the stake prefix only.
         for (coldkey, stake_amount) in coldkey_stake {
+            if let Ok(unbonding_period) = BondedUnbondingPeriod::<T>::try_get(&coldkey, old_hotkey)
+            {
+                BondedUnbondingPeriod::<T>::remove(&coldkey, old_hotkey);
+                BondedUnbondingPeriod::<T>::insert(&coldkey, new_hotkey, unbonding_period);
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+            if let Ok(chunks) = Unbonding::<T>::try_get(&coldkey, old_hotkey) {
+                Unbonding::<T>::remove(&coldkey, old_hotkey);
+                Unbonding::<T>::mutate(&coldkey, new_hotkey, |new_chunks| {
+                    Self::merge_unbonding_chunks(new_chunks, chunks)
+                });
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+            if let Ok(destination) = RewardDestinations::<T>::try_get(&coldkey, old_hotkey) {
+                RewardDestinations::<T>::remove(&coldkey, old_hotkey);
+                RewardDestinations::<T>::insert(&coldkey, new_hotkey, destination);
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+            if let Ok(stakes) =
+                TotalHotkeyColdkeyStakesThisInterval::<T>::try_get(&coldkey, old_hotkey)
+            {
+                TotalHotkeyColdkeyStakesThisInterval::<T>::remove(&coldkey, old_hotkey);
+                TotalHotkeyColdkeyStakesThisInterval::<T>::insert(&coldkey, new_hotkey, stakes);
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+            StakingHotkeys::<T>::mutate(&coldkey, |hotkeys| {
+                Self::replace_indexed_hotkey(hotkeys, old_hotkey, new_hotkey)
+            });
             Stake::<T>::insert(new_hotkey, coldkey, stake_amount);
-            weight.saturating_accrue(T::DbWeight::get().writes(1));
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(6, 2));
         }
 
         let mut netuid_is_member: Vec<u16> = vec![];
@@ -685,6 +815,12 @@ impl<T: Config> Pallet<T> {
                 Axons::<T>::remove(netuid, old_hotkey);
                 Axons::<T>::insert(netuid, new_hotkey, axon_info);
 
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+            if let Ok(axon_info) = AxonsV2::<T>::try_get(netuid, old_hotkey) {
+                AxonsV2::<T>::remove(netuid, old_hotkey);
+                AxonsV2::<T>::insert(netuid, new_hotkey, axon_info);
+
                 weight.saturating_accrue(T::DbWeight::get().writes(2));
             }
         }
@@ -714,6 +850,37 @@ impl<T: Config> Pallet<T> {
             }
         }
 
+        for netuid in netuid_is_member.iter() {
+            if let Ok(prometheus_info) = Prometheus::<T>::try_get(netuid, old_hotkey) {
+                Prometheus::<T>::remove(netuid, old_hotkey);
+                Prometheus::<T>::insert(netuid, new_hotkey, prometheus_info);
+
+                weight.saturating_accrue(T::DbWeight::get().writes(2));
+            }
+
+            weight.saturating_accrue(Self::swap_weight_commits(*netuid, old_hotkey, new_hotkey));
+        }
+        weight.saturating_accrue(T::DbWeight::get().reads(netuid_is_member.len() as u64));
+
+        if let Ok(last_tx) = LastTxBlockDelegateTake::<T>::try_get(old_hotkey) {
+            LastTxBlockDelegateTake::<T>::remove(old_hotkey);
+            LastTxBlockDelegateTake::<T>::insert(new_hotkey, last_tx);
+
+            weight.saturating_accrue(T::DbWeight::get().writes(2));
+        }
+
+        if T::SenateMembers::is_member(old_hotkey) {
+            T::SenateMembers::swap_member(old_hotkey, new_hotkey)?;
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        }
+
+        // Let the other pallets move their own per-hotkey state.
+        weight.saturating_accrue(T::OnHotkeySwapped::on_hotkey_swapped(
+            old_hotkey,
+            new_hotkey,
+            &netuid_is_member,
+        ));
+
         Self::set_last_tx_block(&coldkey, block);
         weight.saturating_accrue(T::DbWeight::get().writes(1));
 
@@ -725,4 +892,260 @@ impl<T: Config> Pallet<T> {
 
         Ok(Some(weight).into())
     }
+
+    /// Replaces a hotkey in the hotkey index of a coldkey, without duplicating the new hotkey.
+    fn replace_indexed_hotkey(
+        hotkeys: &mut Vec<T::AccountId>,
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+    ) {
+        hotkeys.retain(|hotkey| hotkey != old_hotkey && hotkey != new_hotkey);
+        hotkeys.push(new_hotkey.clone());
+    }
+
+    /// ---- The implementation for the extrinsic schedule_coldkey_swap.
+    pub fn do_schedule_coldkey_swap(
+        origin: T::RuntimeOrigin,
+        new_coldkey: T::AccountId,
+    ) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        log::info!(
+            "do_schedule_coldkey_swap( coldkey:{:?} new_coldkey:{:?} )",
+            coldkey,
+            new_coldkey
+        );
+
+        ensure!(coldkey != new_coldkey, Error::<T>::NewColdKeyIsSameWithOld);
+        ensure!(
+            !ColdkeySwapScheduled::<T>::contains_key(&coldkey),
+            Error::<T>::ColdkeySwapAlreadyScheduled
+        );
+
+        let execution_block: u64 =
+            Self::get_current_block_as_u64().saturating_add(T::ColdkeySwapAnnouncementDelay::get());
+        ColdkeySwapScheduled::<T>::insert(&coldkey, (new_coldkey.clone(), execution_block));
+
+        Self::deposit_event(Event::ColdkeySwapScheduled(
+            coldkey,
+            new_coldkey,
+            execution_block,
+        ));
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic cancel_coldkey_swap.
+    pub fn do_cancel_coldkey_swap(origin: T::RuntimeOrigin) -> DispatchResult {
+        let coldkey = ensure_signed(origin)?;
+        log::info!("do_cancel_coldkey_swap( coldkey:{:?} )", coldkey);
+
+        let (new_coldkey, _) =
+            ColdkeySwapScheduled::<T>::take(&coldkey).ok_or(Error::<T>::NoColdkeySwapScheduled)?;
+
+        Self::deposit_event(Event::ColdkeySwapCancelled(coldkey, new_coldkey));
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic force_cancel_coldkey_swap.
+    pub fn do_force_cancel_coldkey_swap(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_coldkey_swap_arbiter(origin)?;
+        log::info!("do_force_cancel_coldkey_swap( coldkey:{:?} )", coldkey);
+
+        let (new_coldkey, _) =
+            ColdkeySwapScheduled::<T>::take(&coldkey).ok_or(Error::<T>::NoColdkeySwapScheduled)?;
+
+        Self::deposit_event(Event::ColdkeySwapCancelled(coldkey, new_coldkey));
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic override_coldkey_swap_destination.
+    pub fn do_override_coldkey_swap_destination(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+        new_coldkey: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_coldkey_swap_arbiter(origin)?;
+        log::info!(
+            "do_override_coldkey_swap_destination( coldkey:{:?} new_coldkey:{:?} )",
+            coldkey,
+            new_coldkey
+        );
+
+        ensure!(coldkey != new_coldkey, Error::<T>::NewColdKeyIsSameWithOld);
+        ColdkeySwapScheduled::<T>::try_mutate(&coldkey, |scheduled| -> DispatchResult {
+            let (destination, _) = scheduled
+                .as_mut()
+                .ok_or(Error::<T>::NoColdkeySwapScheduled)?;
+            *destination = new_coldkey.clone();
+            Ok(())
+        })?;
+
+        Self::deposit_event(Event::ColdkeySwapDestinationOverridden(
+            coldkey,
+            new_coldkey,
+        ));
+        Ok(())
+    }
+
+    /// Ensures the origin is root or the council, which arbitrate coldkey swaps.
+    fn ensure_coldkey_swap_arbiter(origin: T::RuntimeOrigin) -> DispatchResult {
+        let raw_origin: Result<frame_system::RawOrigin<T::AccountId>, T::RuntimeOrigin> =
+            origin.clone().into();
+        if let Ok(frame_system::RawOrigin::Root) = raw_origin {
+            return Ok(());
+        }
+        T::CouncilOrigin::ensure_origin(origin)?;
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic execute_coldkey_swap.
+    pub fn do_execute_coldkey_swap(
+        origin: T::RuntimeOrigin,
+        coldkey: T::AccountId,
+    ) -> DispatchResultWithPostInfo {
+        // Signed accounts have to wait for the announcement delay, the council and root arbitrate.
+        let raw_origin: Result<frame_system::RawOrigin<T::AccountId>, T::RuntimeOrigin> =
+            origin.clone().into();
+        let is_arbiter: bool = match raw_origin {
+            Ok(frame_system::RawOrigin::Signed(_)) => false,
+            Ok(frame_system::RawOrigin::Root) => true,
+            _ => {
+                T::CouncilOrigin::ensure_origin(origin)?;
+                true
+            }
+        };
+        log::info!(
+            "do_execute_coldkey_swap( coldkey:{:?} is_arbiter:{:?} )",
+            coldkey,
+            is_arbiter
+        );
+
+        let (new_coldkey, execution_block) =
+            ColdkeySwapScheduled::<T>::get(&coldkey).ok_or(Error::<T>::NoColdkeySwapScheduled)?;
+        ensure!(
+            is_arbiter || Self::get_current_block_as_u64() >= execution_block,
+            Error::<T>::ColdkeySwapNotReady
+        );
+
+        ColdkeySwapScheduled::<T>::remove(&coldkey);
+        let weight: Weight = Self::swap_coldkey(&coldkey, &new_coldkey)?
+            .saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+        Self::deposit_event(Event::ColdkeySwapped(coldkey, new_coldkey));
+        Ok(Some(weight).into())
+    }
+
+    /// Moves the owned hotkeys, the stake, the owned subnets and the free balance of a coldkey
+    /// to a new coldkey. The calls using it are transactional, a failure leaves nothing moved.
+    pub fn swap_coldkey(
+        old_coldkey: &T::AccountId,
+        new_coldkey: &T::AccountId,
+    ) -> Result<Weight, DispatchError> {
+        let mut weight: Weight = T::DbWeight::get().reads(1);
+
+        // --- 1. Move the free balance.
+        let balance: u64 = Self::get_coldkey_balance(old_coldkey);
+        if balance > 0 {
+            T::Currency::transfer(old_coldkey, new_coldkey, balance, Preservation::Expendable)?;
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        }
+
+        // --- 2. Move the ownership of the hotkeys, found through the index of the coldkey.
+        let owned_hotkeys: Vec<T::AccountId> = OwnedHotkeys::<T>::take(old_coldkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        for hotkey in owned_hotkeys {
+            Owner::<T>::insert(&hotkey, new_coldkey);
+            OwnedHotkeys::<T>::mutate(new_coldkey, |hotkeys| {
+                if !hotkeys.contains(&hotkey) {
+                    hotkeys.push(hotkey.clone())
+                }
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+
+        // --- 3. Move the stake, the totals per hotkey do not change.
+        let staking_hotkeys: Vec<T::AccountId> = StakingHotkeys::<T>::take(old_coldkey);
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        for hotkey in staking_hotkeys {
+            let stake: u64 = Stake::<T>::take(&hotkey, old_coldkey);
+            Self::record_staking_hotkey(new_coldkey, &hotkey);
+            Stake::<T>::mutate(&hotkey, new_coldkey, |new_stake| {
+                *new_stake = new_stake.saturating_add(stake)
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(3, 3));
+        }
+
+        let total_coldkey_stake: u64 = TotalColdkeyStake::<T>::take(old_coldkey);
+        TotalColdkeyStake::<T>::mutate(new_coldkey, |total| {
+            *total = total.saturating_add(total_coldkey_stake)
+        });
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+
+        // --- 4. Move the stake that is still unbonding, the bonded unbonding periods and the
+        // reward destinations.
+        let unbonding: Vec<(T::AccountId, Vec<(u64, u64)>)> =
+            Unbonding::<T>::drain_prefix(old_coldkey).collect();
+        for (hotkey, chunks) in unbonding {
+            Unbonding::<T>::mutate(new_coldkey, &hotkey, |new_chunks| {
+                Self::merge_unbonding_chunks(new_chunks, chunks)
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        }
+        let unbonding_periods: Vec<(T::AccountId, u64)> =
+            BondedUnbondingPeriod::<T>::drain_prefix(old_coldkey).collect();
+        for (hotkey, unbonding_period) in unbonding_periods {
+            BondedUnbondingPeriod::<T>::mutate(new_coldkey, &hotkey, |new_period| {
+                *new_period = (*new_period).max(unbonding_period)
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        }
+        let destinations: Vec<(T::AccountId, RewardDestination<T::AccountId>)> =
+            RewardDestinations::<T>::drain_prefix(old_coldkey).collect();
+        for (hotkey, destination) in destinations {
+            RewardDestinations::<T>::insert(new_coldkey, &hotkey, destination);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+
+        // --- 5. Move the staking rate limits, so the swap does not reset them.
+        let stakes_this_interval: Vec<(T::AccountId, (u64, u64))> =
+            TotalHotkeyColdkeyStakesThisInterval::<T>::drain_prefix(old_coldkey).collect();
+        for (hotkey, (stakes, block)) in stakes_this_interval {
+            TotalHotkeyColdkeyStakesThisInterval::<T>::mutate(
+                new_coldkey,
+                &hotkey,
+                |(new_stakes, new_block)| {
+                    *new_stakes = new_stakes.saturating_add(stakes);
+                    *new_block = (*new_block).max(block);
+                },
+            );
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+        }
+        if let Ok(last_tx) = LastTxBlock::<T>::try_get(old_coldkey) {
+            LastTxBlock::<T>::remove(old_coldkey);
+            LastTxBlock::<T>::mutate(new_coldkey, |new_last_tx| {
+                *new_last_tx = (*new_last_tx).max(last_tx)
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        }
+
+        // --- 6. Move the ownership of the subnets and of the registrations waiting for one.
+        for netuid in Self::get_all_subnet_netuids() {
+            if SubnetOwner::<T>::get(netuid) == *old_coldkey {
+                SubnetOwner::<T>::insert(netuid, new_coldkey);
+                weight.saturating_accrue(T::DbWeight::get().writes(1));
+            }
+            PendingNetworkRegistrations::<T>::mutate(netuid, |pending| {
+                if let Some((coldkey, _)) = pending {
+                    if *coldkey == *old_coldkey {
+                        *coldkey = new_coldkey.clone();
+                    }
+                }
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+        }
+
+        Ok(weight)
+    }
 }
@@ -27,6 +27,19 @@ use substrate_fixed::{
     types::{I64F64, I96F32},
 };
 
+/// Terms of a root epoch, computed before the emission values are set.
+pub(crate) struct RootEpochTerms<AccountId> {
+    pub netuids: Vec<u16>,
+    pub hotkeys: Vec<(u16, AccountId)>,
+    pub block_emission: u64,
+    pub stake: Vec<I64F64>, // Normalized root stake, one per root validator.
+    pub weights: Vec<Vec<I64F64>>, // Row-normalized weights, root validators x subnets.
+    pub ranks: Vec<I64F64>,
+    pub trust: Vec<I64F64>,
+    pub consensus: Vec<I64F64>,
+    pub emission: Vec<u64>, // Emission per block of each subnet.
+}
+
 impl<T: Config> Pallet<T> {
     /// Retrieves the unique identifier (UID) for the root network.
     ///
@@ -324,6 +337,21 @@ impl<T: Config> Pallet<T> {
             return Err("");
         }
 
+        // --- 2. Compute the emission of every subnet.
+        let terms: RootEpochTerms<T::AccountId> = Self::root_epoch_terms()?;
+
+        // --- 3. Set the emission values for each subnet directly.
+        log::debug!("netuids: {:?} values: {:?}", terms.netuids, terms.emission);
+
+        Self::set_emission_values(&terms.netuids, terms.emission)
+    }
+
+    /// Computes every term of the root epoch without writing to storage.
+    /// The root epoch sets the resulting emission values, the root epoch explainer reports them.
+    pub(crate) fn root_epoch_terms() -> Result<RootEpochTerms<T::AccountId>, &'static str> {
+        // --- 1. The unique ID associated with the root network.
+        let root_netuid: u16 = Self::get_root_netuid();
+
         // --- 2. Retrieves the number of root validators on subnets.
         let n: u16 = Self::get_num_root_validators();
         log::debug!("n:\n{:?}\n", n);
@@ -383,7 +411,7 @@ impl<T: Config> Pallet<T> {
         let total_networks = Self::get_num_subnets();
         let mut trust = vec![I64F64::from_num(0); total_networks as usize];
         let mut total_stake: I64F64 = I64F64::from_num(0);
-        for (weights, hotkey_stake) in weights.iter().zip(stake_i64) {
+        for (weights, hotkey_stake) in weights.iter().zip(stake_i64.iter().copied()) {
             total_stake += hotkey_stake;
             for (weight, trust_score) in weights.iter().zip(&mut trust) {
                 if *weight > 0 {
@@ -440,11 +468,17 @@ impl<T: Config> Pallet<T> {
         let emission_u64: Vec<u64> = vec_fixed64_to_u64(emission_as_tao);
         log::debug!("Eu64:\n{:?}\n", &emission_u64);
 
-        // --- 13. Set the emission values for each subnet directly.
-        let netuids: Vec<u16> = Self::get_all_subnet_netuids();
-        log::debug!("netuids: {:?} values: {:?}", netuids, emission_u64);
-
-        Self::set_emission_values(&netuids, emission_u64)
+        Ok(RootEpochTerms {
+            netuids: Self::get_all_subnet_netuids(),
+            hotkeys,
+            block_emission: block_emission.to_num::<u64>(),
+            stake: stake_i64,
+            weights,
+            ranks,
+            trust,
+            consensus,
+            emission: emission_u64,
+        })
     }
 
     /// Registers a user's hotkey to the root network.
@@ -768,13 +802,16 @@ impl<T: Config> Pallet<T> {
             .into())
     }
 
-    /// Facilitates user registration of a new subnetwork.
+    /// Facilitates user registration of a new subnetwork. When the subnet limit is reached, the
+    /// subnet to prune is announced as dissolving and the new subnet takes its netuid once it is
+    /// removed.
     ///
     /// # Args:
     /// * 'origin': ('T::RuntimeOrigin'): The calling origin. Must be signed.
     ///
     /// # Event:
     /// * 'NetworkAdded': Emitted when a new network is successfully added.
+    /// * 'NetworkRegistrationQueued': Emitted when the new network waits for a pruned subnet.
     ///
     /// # Raises:
     /// * 'TxRateLimitExceeded': If the rate limit for network registration is exceeded.
@@ -801,73 +838,93 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotEnoughBalanceToStake
         );
 
-        // --- 4. Determine the netuid to register.
-        let netuid_to_register: u16 = {
-            log::debug!(
-                "subnet count: {:?}\nmax subnets: {:?}",
-                Self::get_num_subnets(),
-                Self::get_max_subnets()
-            );
+        // --- 4. Determine the netuid to register, or the subnet to prune for it.
+        log::debug!(
+            "subnet count: {:?}\nmax subnets: {:?}",
+            Self::get_num_subnets(),
+            Self::get_max_subnets()
+        );
+        // We subtract one because we don't want root subnet to count towards total
+        let netuid_to_prune: Option<u16> =
             if Self::get_num_subnets().saturating_sub(1) < Self::get_max_subnets() {
-                // We subtract one because we don't want root subnet to count towards total
+                None
+            } else {
+                let netuid_to_prune = Self::get_subnet_to_prune();
+                ensure!(netuid_to_prune > 0, Error::<T>::AllNetworksInImmunity);
+                Some(netuid_to_prune)
+            };
+
+        // --- 5. Perform the lock operation.
+        let actual_lock_amount = Self::remove_balance_from_coldkey_account(&coldkey, lock_amount)?;
+        Self::set_network_last_lock(actual_lock_amount);
+        NetworkLastRegistered::<T>::set(current_block);
+
+        match netuid_to_prune {
+            None => {
+                // --- 6. Register the network on the next available netuid.
                 let mut next_available_netuid = 0;
-                loop {
+                let netuid_to_register: u16 = loop {
                     next_available_netuid += 1;
                     if !Self::if_subnet_exist(next_available_netuid) {
                         log::debug!("got subnet id: {:?}", next_available_netuid);
                         break next_available_netuid;
                     }
-                }
-            } else {
-                let netuid_to_prune = Self::get_subnet_to_prune();
-                ensure!(netuid_to_prune > 0, Error::<T>::AllNetworksInImmunity);
-
-                Self::remove_network(netuid_to_prune);
-                log::debug!("remove_network: {:?}", netuid_to_prune,);
-                Self::deposit_event(Event::NetworkRemoved(netuid_to_prune));
-                netuid_to_prune
+                };
+                Self::add_network_for_owner(netuid_to_register, coldkey, actual_lock_amount);
             }
-        };
+            Some(netuid_to_prune) => {
+                // --- 6. Dissolve the pruned subnet and register the network once it is removed.
+                PendingNetworkRegistrations::<T>::insert(
+                    netuid_to_prune,
+                    (coldkey.clone(), actual_lock_amount),
+                );
+                Self::announce_network_dissolution(netuid_to_prune);
+                log::info!(
+                    "NetworkRegistrationQueued( netuid:{:?}, coldkey:{:?} )",
+                    netuid_to_prune,
+                    coldkey
+                );
+                Self::deposit_event(Event::NetworkRegistrationQueued(netuid_to_prune, coldkey));
+            }
+        }
 
-        // --- 5. Perform the lock operation.
-        let actual_lock_amount = Self::remove_balance_from_coldkey_account(&coldkey, lock_amount)?;
-        Self::set_subnet_locked_balance(netuid_to_register, actual_lock_amount);
-        Self::set_network_last_lock(actual_lock_amount);
+        // --- 7. Return success.
+        Ok(())
+    }
 
-        // --- 6. Set initial and custom parameters for the network.
-        Self::init_new_network(netuid_to_register, 360);
-        log::debug!("init_new_network: {:?}", netuid_to_register,);
+    /// Registers a network on a free netuid for its owner, who locked the given amount.
+    fn add_network_for_owner(netuid: u16, coldkey: T::AccountId, lock: u64) {
+        // --- 1. Set the locked balance.
+        Self::set_subnet_locked_balance(netuid, lock);
 
-        // --- 7. Set netuid storage.
-        let current_block_number: u64 = Self::get_current_block_as_u64();
-        NetworkLastRegistered::<T>::set(current_block_number);
-        NetworkRegisteredAt::<T>::insert(netuid_to_register, current_block_number);
-        SubnetOwner::<T>::insert(netuid_to_register, coldkey);
+        // --- 2. Set initial and custom parameters for the network.
+        Self::init_new_network(netuid, 360);
+        log::debug!("init_new_network: {:?}", netuid);
 
-        // --- 8. Emit the NetworkAdded event.
-        log::info!(
-            "NetworkAdded( netuid:{:?}, modality:{:?} )",
-            netuid_to_register,
-            0
-        );
-        Self::deposit_event(Event::NetworkAdded(netuid_to_register, 0));
+        // --- 3. Set netuid storage.
+        NetworkRegisteredAt::<T>::insert(netuid, Self::get_current_block_as_u64());
+        SubnetOwner::<T>::insert(netuid, coldkey);
 
-        // --- 9. Return success.
-        Ok(())
+        // --- 4. Emit the NetworkAdded event.
+        log::info!("NetworkAdded( netuid:{:?}, modality:{:?} )", netuid, 0);
+        Self::deposit_event(Event::NetworkAdded(netuid, 0));
     }
 
-    /// Facilitates the removal of a user's subnetwork.
+    /// Facilitates the removal of a user's subnetwork. The subnet is announced as dissolving,
+    /// registrations and emission stop right away and the subnet is removed by the block step
+    /// once the dissolve period has passed.
     ///
     /// # Args:
     /// * 'origin': ('T::RuntimeOrigin'): The calling origin. Must be signed.
     /// * 'netuid': ('u16'): The unique identifier of the network to be removed.
     ///
     /// # Event:
-    /// * 'NetworkRemoved': Emitted when a network is successfully removed.
+    /// * 'NetworkDissolutionAnnounced': Emitted when the dissolution is successfully announced.
     ///
     /// # Raises:
     /// * 'SubNetworkDoesNotExist': If the specified network does not exist.
     /// * 'NotSubnetOwner': If the caller does not own the specified subnet.
+    /// * 'SubnetDissolving': If the specified network is already dissolving.
     ///
     pub fn user_remove_network(origin: T::RuntimeOrigin, netuid: u16) -> dispatch::DispatchResult {
         // --- 1. Ensure the function caller is a signed user.
@@ -885,17 +942,167 @@ impl<T: Config> Pallet<T> {
             Error::<T>::NotSubnetOwner
         );
 
-        // --- 4. Explicitly erase the network and all its parameters.
-        Self::remove_network(netuid);
+        // --- 4. Ensure the dissolution was not announced before.
+        ensure!(
+            !Self::is_network_dissolving(netuid),
+            Error::<T>::SubnetDissolving
+        );
 
-        // --- 5. Emit the NetworkRemoved event.
-        log::info!("NetworkRemoved( netuid:{:?} )", netuid);
-        Self::deposit_event(Event::NetworkRemoved(netuid));
+        // --- 5. Mark the network as dissolving until the end of the dissolve period.
+        Self::announce_network_dissolution(netuid);
 
         // --- 6. Return success.
         Ok(())
     }
 
+    /// Marks a subnet as dissolving until the end of the dissolve period.
+    fn announce_network_dissolution(netuid: u16) {
+        // The block step of the current block has already run, the earliest removal is the next.
+        let removal_block: u64 = Self::get_current_block_as_u64()
+            .saturating_add(Self::get_network_dissolve_period().max(1));
+        DissolvingNetworks::<T>::insert(netuid, removal_block);
+        DissolutionsAt::<T>::append(removal_block, netuid);
+
+        log::info!(
+            "NetworkDissolutionAnnounced( netuid:{:?}, removal_block:{:?} )",
+            netuid,
+            removal_block
+        );
+        Self::deposit_event(Event::NetworkDissolutionAnnounced(netuid, removal_block));
+    }
+
+    /// Removes the dissolving subnets whose dissolve period ends at this block, then registers the
+    /// networks that were waiting for them. Returns the weight used.
+    pub fn remove_dissolved_networks(block_number: u64) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+        for netuid in DissolutionsAt::<T>::take(block_number) {
+            // A subnet removed in the meantime, or dissolving again since, is not due anymore.
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            if DissolvingNetworks::<T>::get(netuid) != Some(block_number) {
+                continue;
+            }
+
+            // --- 1. Explicitly erase the network and all its parameters.
+            Self::remove_network(netuid);
+            weight.saturating_accrue(
+                Weight::from_parts(119_000_000, 0)
+                    .saturating_add(T::DbWeight::get().reads(6))
+                    .saturating_add(T::DbWeight::get().writes(31)),
+            );
+
+            // --- 2. Emit the NetworkRemoved event.
+            log::info!("NetworkRemoved( netuid:{:?} )", netuid);
+            Self::deposit_event(Event::NetworkRemoved(netuid));
+
+            // --- 3. Register the network that pruned this subnet.
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            if let Some((coldkey, lock)) = PendingNetworkRegistrations::<T>::take(netuid) {
+                Self::add_network_for_owner(netuid, coldkey, lock);
+                weight.saturating_accrue(
+                    Weight::from_parts(157_000_000, 0)
+                        .saturating_add(T::DbWeight::get().reads(16))
+                        .saturating_add(T::DbWeight::get().writes(30)),
+                );
+            }
+        }
+        weight
+    }
+
+    pub fn is_network_dissolving(netuid: u16) -> bool {
+        DissolvingNetworks::<T>::contains_key(netuid)
+    }
+    pub fn get_network_dissolve_period() -> u64 {
+        NetworkDissolvePeriod::<T>::get()
+    }
+    pub fn set_network_dissolve_period(dissolve_period: u64) {
+        NetworkDissolvePeriod::<T>::set(dissolve_period);
+        Self::deposit_event(Event::NetworkDissolvePeriodSet(dissolve_period));
+    }
+
+    /// Sets the emission split of a subnet owned by the caller.
+    ///
+    /// # Args:
+    /// * 'origin': ('T::RuntimeOrigin'): The calling origin. Must be signed by the subnet owner.
+    /// * 'netuid': ('u16'): The subnet to set the emission split of.
+    /// * 'owner_cut': ('u16'): The share credited to the subnet owner.
+    /// * 'burn': ('u16'): The share that is burned.
+    /// * 'recipients': ('Vec<(T::AccountId, u16)>'): The extra recipient accounts and their shares.
+    ///
+    /// # Event:
+    /// * 'EmissionSplitSet': Emitted when the emission split is successfully set.
+    ///
+    /// # Raises:
+    /// * 'SubNetworkDoesNotExist': If the specified network does not exist.
+    /// * 'NotSubnetOwner': If the caller does not own the specified subnet.
+    /// * 'TooManyEmissionSplitRecipients': If more than MaxEmissionSplitRecipients recipients are passed.
+    /// * 'DuplicateEmissionSplitRecipient': If a recipient is passed more than once.
+    /// * 'EmissionSplitShareTooHigh': If the shares add up to more than MaxEmissionSplitShare.
+    ///
+    pub fn do_set_emission_split(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        owner_cut: u16,
+        burn: u16,
+        recipients: Vec<(T::AccountId, u16)>,
+    ) -> dispatch::DispatchResult {
+        // --- 1. Ensure the function caller is a signed user.
+        let coldkey = ensure_signed(origin)?;
+
+        // --- 2. Ensure this subnet exists.
+        ensure!(
+            Self::if_subnet_exist(netuid),
+            Error::<T>::SubNetworkDoesNotExist
+        );
+
+        // --- 3. Ensure the caller owns this subnet.
+        ensure!(
+            SubnetOwner::<T>::get(netuid) == coldkey,
+            Error::<T>::NotSubnetOwner
+        );
+
+        // --- 4. Ensure the recipients are bounded and unique.
+        ensure!(
+            recipients.len() <= T::MaxEmissionSplitRecipients::get() as usize,
+            Error::<T>::TooManyEmissionSplitRecipients
+        );
+        for (i, (recipient, _)) in recipients.iter().enumerate() {
+            ensure!(
+                !recipients[..i].iter().any(|(other, _)| other == recipient),
+                Error::<T>::DuplicateEmissionSplitRecipient
+            );
+        }
+
+        // --- 5. Ensure the shares stay within the governance bound.
+        let total_share: u64 = recipients
+            .iter()
+            .fold(owner_cut as u64 + burn as u64, |total, (_, share)| {
+                total + *share as u64
+            });
+        ensure!(
+            total_share <= Self::get_max_emission_split_share() as u64,
+            Error::<T>::EmissionSplitShareTooHigh
+        );
+
+        // --- 6. Store the emission split.
+        let emission_split = EmissionSplit {
+            owner_cut,
+            burn,
+            recipients,
+        };
+        SubnetEmissionSplit::<T>::insert(netuid, emission_split.clone());
+
+        // --- 7. Emit the EmissionSplitSet event.
+        log::info!(
+            "EmissionSplitSet( netuid:{:?}, emission_split:{:?} )",
+            netuid,
+            emission_split
+        );
+        Self::deposit_event(Event::EmissionSplitSet(netuid, emission_split));
+
+        // --- 8. Return success.
+        Ok(())
+    }
+
     /// Sets initial and custom parameters for a new network.
     pub fn init_new_network(netuid: u16, tempo: u16) {
         // --- 1. Set network to 0 size.
@@ -981,12 +1188,17 @@ impl<T: Config> Pallet<T> {
     /// It ensures that all the storage values related to the network are removed, and any
     /// reserved balance is returned to the network owner.
     ///
+    /// The lock is refunded in full to the coldkey owning the subnet at removal, whether the
+    /// subnet was dissolved by its owner or pruned to make room for a new subnet.
+    ///
     /// # Args:
     ///  * 'netuid': ('u16'): The unique identifier of the network to be removed.
     ///
+    /// # Event:
+    ///  * 'NetworkLockRefunded': Emitted with the refunded lock.
+    ///
     /// # Note:
-    /// This function does not emit any events, nor does it raise any errors. It silently
-    /// returns if any internal checks fail.
+    /// This function does not raise any errors. It silently returns if any internal checks fail.
     ///
     pub fn remove_network(netuid: u16) {
         // --- 1. Return balance to subnet owner.
@@ -1055,16 +1267,32 @@ impl<T: Config> Pallet<T> {
         ImmunityPeriod::<T>::remove(netuid);
         ActivityCutoff::<T>::remove(netuid);
         EmissionValues::<T>::remove(netuid);
+        PendingEmission::<T>::remove(netuid);
+        LoadedEmission::<T>::remove(netuid);
         MaxWeightsLimit::<T>::remove(netuid);
         MinAllowedWeights::<T>::remove(netuid);
         RegistrationsThisInterval::<T>::remove(netuid);
         POWRegistrationsThisInterval::<T>::remove(netuid);
         BurnRegistrationsThisInterval::<T>::remove(netuid);
+        SubnetEmissionSplit::<T>::remove(netuid);
+        DissolvingNetworks::<T>::remove(netuid);
+        AllowPrivateIps::<T>::remove(netuid);
+        DeregistrationRefundRatio::<T>::remove(netuid);
+        FreedUids::<T>::remove(netuid);
+        let _ = BurnAtRegistration::<T>::clear_prefix(netuid, u32::MAX, None);
+        EpochSnapshotRetention::<T>::remove(netuid);
+        EpochSnapshotsTaken::<T>::remove(netuid);
+        let _ = EpochSnapshots::<T>::clear_prefix(netuid, u32::MAX, None);
 
         // --- 12. Add the balance back to the owner.
         Self::add_balance_to_coldkey_account(&owner_coldkey, reserved_amount);
         Self::set_subnet_locked_balance(netuid, 0);
         SubnetOwner::<T>::remove(netuid);
+        Self::deposit_event(Event::NetworkLockRefunded(
+            netuid,
+            owner_coldkey,
+            reserved_amount,
+        ));
     }
 
     /// This function calculates the lock cost for a network based on the last lock amount, minimum lock cost, last lock block, and current block.
@@ -1110,7 +1338,8 @@ impl<T: Config> Pallet<T> {
     }
 
     /// This function is used to determine which subnet to prune when the total number of networks has reached the limit.
-    /// It iterates over all the networks and finds the oldest subnet with the minimum emission value that is not in the immunity period.
+    /// It iterates over all the networks and finds the oldest subnet with the minimum emission value that is not in the immunity period
+    /// nor already dissolving.
     ///
     /// # Returns:
     /// * 'u16':
@@ -1124,6 +1353,7 @@ impl<T: Config> Pallet<T> {
         for netuid in NetworksAdded::<T>::iter_keys_from(NetworksAdded::<T>::hashed_key_for(0)) {
             if current_block.saturating_sub(Self::get_network_registered_block(netuid))
                 < Self::get_network_immunity_period()
+                || Self::is_network_dissolving(netuid)
             {
                 continue;
             }
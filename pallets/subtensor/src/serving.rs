@@ -1,4 +1,160 @@
 use super::*;
+use frame_support::BoundedVec;
+
+/// Class of an address in the IANA special-purpose address registries.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IpClass {
+    /// Globally reachable.
+    Global,
+    /// Loopback, private-use, shared, link-local or unique-local: only reachable on a local
+    /// network, which subnets may allow for testing.
+    Private,
+    /// Never a reachable unicast address: unspecified, documentation, benchmarking, reserved,
+    /// broadcast, multicast and deprecated ranges.
+    Reserved,
+}
+
+/// IPv4 special-purpose ranges as ( prefix, prefix length, class ), the first match applies.
+/// https://www.iana.org/assignments/iana-ipv4-special-registry
+const IPV4_SPECIAL_PURPOSE: [(u32, u32, IpClass); 17] = [
+    // 192.0.0.9/32 Port Control Protocol Anycast
+    (0xc000_0009, 32, IpClass::Global),
+    // 192.0.0.10/32 Traversal Using Relays around NAT Anycast
+    (0xc000_000a, 32, IpClass::Global),
+    // 0.0.0.0/8 "This network"
+    (0x0000_0000, 8, IpClass::Reserved),
+    // 10.0.0.0/8 Private-Use
+    (0x0a00_0000, 8, IpClass::Private),
+    // 100.64.0.0/10 Shared Address Space
+    (0x6440_0000, 10, IpClass::Private),
+    // 127.0.0.0/8 Loopback
+    (0x7f00_0000, 8, IpClass::Private),
+    // 169.254.0.0/16 Link Local
+    (0xa9fe_0000, 16, IpClass::Private),
+    // 172.16.0.0/12 Private-Use
+    (0xac10_0000, 12, IpClass::Private),
+    // 192.0.0.0/24 IETF Protocol Assignments
+    (0xc000_0000, 24, IpClass::Reserved),
+    // 192.0.2.0/24 Documentation (TEST-NET-1)
+    (0xc000_0200, 24, IpClass::Reserved),
+    // 192.88.99.0/24 Deprecated 6to4 Relay Anycast
+    (0xc058_6300, 24, IpClass::Reserved),
+    // 192.168.0.0/16 Private-Use
+    (0xc0a8_0000, 16, IpClass::Private),
+    // 198.18.0.0/15 Benchmarking
+    (0xc612_0000, 15, IpClass::Reserved),
+    // 198.51.100.0/24 Documentation (TEST-NET-2)
+    (0xc633_6400, 24, IpClass::Reserved),
+    // 203.0.113.0/24 Documentation (TEST-NET-3)
+    (0xcb00_7100, 24, IpClass::Reserved),
+    // 224.0.0.0/4 Multicast
+    (0xe000_0000, 4, IpClass::Reserved),
+    // 240.0.0.0/4 Reserved, incl. Limited Broadcast
+    (0xf000_0000, 4, IpClass::Reserved),
+];
+
+/// IPv6 special-purpose ranges as ( prefix, prefix length, class ), the first match applies.
+/// https://www.iana.org/assignments/iana-ipv6-special-registry
+const IPV6_SPECIAL_PURPOSE: [(u128, u32, IpClass); 20] = [
+    // ::1/128 Loopback Address
+    (1, 128, IpClass::Private),
+    // ::/128 Unspecified Address
+    (0, 128, IpClass::Reserved),
+    // ::ffff:0:0/96 IPv4-mapped Address
+    (0xffff << 32, 96, IpClass::Reserved),
+    // 64:ff9b:1::/48 IPv4-IPv6 Translat., local use
+    (0x0064_ff9b_0001 << 80, 48, IpClass::Private),
+    // 100::/64 Discard-Only Address Block
+    (0x0100 << 112, 64, IpClass::Reserved),
+    // 2001:1::1/128 Port Control Protocol Anycast
+    ((0x2001_0001 << 96) | 1, 128, IpClass::Global),
+    // 2001:1::2/128 Traversal Using Relays around NAT Anycast
+    ((0x2001_0001 << 96) | 2, 128, IpClass::Global),
+    // 2001:1::3/128 DNS-SD Service Registration Protocol Anycast
+    ((0x2001_0001 << 96) | 3, 128, IpClass::Global),
+    // 2001:3::/32 AMT
+    (0x2001_0003 << 96, 32, IpClass::Global),
+    // 2001:4:112::/48 AS112-v6
+    (0x2001_0004_0112 << 80, 48, IpClass::Global),
+    // 2001:20::/28 ORCHIDv2
+    (0x2001_0020 << 96, 28, IpClass::Global),
+    // 2001:30::/28 Drone Remote ID Protocol Entity Tags
+    (0x2001_0030 << 96, 28, IpClass::Global),
+    // 2001::/23 IETF Protocol Assignments, incl. TEREDO and Benchmarking
+    (0x2001 << 112, 23, IpClass::Reserved),
+    // 2001:db8::/32 Documentation
+    (0x2001_0db8 << 96, 32, IpClass::Reserved),
+    // 2002::/16 6to4
+    (0x2002 << 112, 16, IpClass::Reserved),
+    // 3fff::/20 Documentation
+    (0x3fff << 112, 20, IpClass::Reserved),
+    // 5f00::/16 Segment Routing (SRv6) SIDs
+    (0x5f00 << 112, 16, IpClass::Reserved),
+    // fc00::/7 Unique-Local
+    (0xfc00 << 112, 7, IpClass::Private),
+    // fe80::/10 Link-Local Unicast
+    (0xfe80 << 112, 10, IpClass::Private),
+    // ff00::/8 Multicast
+    (0xff00 << 112, 8, IpClass::Reserved),
+];
+
+/// Top-level domains of names that only resolve on a local network.
+/// https://www.iana.org/assignments/special-use-domain-names
+const PRIVATE_TLDS: [&[u8]; 3] = [b"localhost", b"local", b"internal"];
+/// Top-level domains reserved for testing, documentation and invalid names.
+const RESERVED_TLDS: [&[u8]; 4] = [b"test", b"example", b"invalid", b"onion"];
+
+impl From<&AxonInfo> for AxonInfoV2 {
+    /// A served axon has a single ipv4 or ipv6 endpoint, without certificate digest.
+    fn from(axon: &AxonInfo) -> Self {
+        let address = match axon.ip_type {
+            4 => u32::try_from(axon.ip).ok().map(AxonAddress::Ipv4),
+            6 => Some(AxonAddress::Ipv6(axon.ip)),
+            _ => None,
+        };
+        let endpoints: Vec<AxonEndpoint> = address
+            .map(|address| AxonEndpoint {
+                address,
+                port: axon.port,
+                protocol: axon.protocol,
+                cert_digest: None,
+            })
+            .into_iter()
+            .collect();
+        AxonInfoV2 {
+            block: axon.block,
+            version: axon.version,
+            endpoints: BoundedVec::truncate_from(endpoints),
+        }
+    }
+}
+
+impl From<&AxonInfoV2> for AxonInfo {
+    /// The first ipv4 or ipv6 endpoint of the axon, an axon advertising only DNS names has an
+    /// unset ip.
+    fn from(axon: &AxonInfoV2) -> Self {
+        let mut legacy = AxonInfo {
+            block: axon.block,
+            version: axon.version,
+            ..Default::default()
+        };
+        let endpoint = axon
+            .endpoints
+            .iter()
+            .find_map(|endpoint| match endpoint.address {
+                AxonAddress::Ipv4(ip) => Some((ip as u128, 4, endpoint)),
+                AxonAddress::Ipv6(ip) => Some((ip, 6, endpoint)),
+                AxonAddress::Dns(_) => None,
+            });
+        if let Some((ip, ip_type, endpoint)) = endpoint {
+            legacy.ip = ip;
+            legacy.ip_type = ip_type;
+            legacy.port = endpoint.port;
+            legacy.protocol = endpoint.protocol;
+        }
+        legacy
+    }
+}
 
 impl<T: Config> Pallet<T> {
     /// ---- The implementation for the extrinsic serve_axon which sets the ip endpoint information for a uid on a network.
@@ -46,7 +202,8 @@ impl<T: Config> Pallet<T> {
     ///     - The ip type is not 4 or 6.
     ///
     /// * 'InvalidIpAddress':
-    ///     - The numerically encoded ip address does not resolve to a proper ip.
+    ///     - The numerically encoded ip address does not resolve to a proper ip, or is in a
+    ///       special-purpose range that is not allowed on the subnet.
     ///
     /// * 'ServingRateLimitExceeded':
     ///     - Attempting to set prometheus information withing the rate limit min.
@@ -74,7 +231,7 @@ impl<T: Config> Pallet<T> {
         // Check the ip signature validity.
         ensure!(Self::is_valid_ip_type(ip_type), Error::<T>::InvalidIpType);
         ensure!(
-            Self::is_valid_ip_address(ip_type, ip),
+            Self::is_allowed_ip_address(netuid, ip_type, ip),
             Error::<T>::InvalidIpAddress
         );
 
@@ -103,6 +260,7 @@ impl<T: Config> Pallet<T> {
             axon_validated.err().unwrap_or(Error::<T>::InvalidPort)
         );
 
+        AxonsV2::<T>::insert(netuid, hotkey_id.clone(), AxonInfoV2::from(&prev_axon));
         Axons::<T>::insert(netuid, hotkey_id.clone(), prev_axon);
 
         // We deposit axon served event.
@@ -113,6 +271,196 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic serve_axon_v2 which sets the endpoints of the axon
+    /// for a uid on a network.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the caller.
+    ///
+    /// * 'netuid' (u16):
+    ///     - The u16 network identifier.
+    ///
+    /// * 'version' (u32):
+    ///     - The bittensor version identifier.
+    ///
+    /// * 'endpoints' (BoundedVec<AxonEndpoint>):
+    ///     - The endpoints of the axon, in order of preference.
+    ///
+    /// # Event:
+    /// * AxonServed;
+    ///     - On successfully serving the axon info.
+    ///
+    /// # Raises:
+    /// * 'NotRegistered':
+    ///     - Attempting to serve an axon from a non registered account.
+    ///
+    /// * 'NoAxonEndpoints':
+    ///     - No endpoint is passed.
+    ///
+    /// * 'InvalidIpAddress':
+    ///     - An ip endpoint is in a special-purpose range that is not allowed on the subnet.
+    ///
+    /// * 'InvalidHostname':
+    ///     - A DNS endpoint is not a valid hostname, or is a special-use name that is not
+    ///       allowed on the subnet.
+    ///
+    /// * 'InvalidPort':
+    ///     - An endpoint has port 0.
+    ///
+    /// * 'ServingRateLimitExceeded':
+    ///     - Attempting to serve the axon within the rate limit min.
+    ///
+    pub fn do_serve_axon_v2(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        version: u32,
+        endpoints: BoundedVec<AxonEndpoint, MaxAxonEndpoints>,
+    ) -> dispatch::DispatchResult {
+        // We check the callers (hotkey) signature.
+        let hotkey_id = ensure_signed(origin)?;
+
+        // Ensure the hotkey is registered somewhere.
+        ensure!(
+            Self::is_hotkey_registered_on_any_network(&hotkey_id),
+            Error::<T>::HotKeyNotRegisteredInNetwork
+        );
+
+        Self::serve_axon_v2_on_network(netuid, &hotkey_id, version, endpoints)?;
+
+        // Return is successful dispatch.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic serve_axon_multi which sets the endpoints of the
+    /// axon of the caller on several networks. Each network applies its own rate limit and private
+    /// ip policy, a network where the axon cannot be served is skipped. The call fails if the axon
+    /// is served on none of them.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     - The signature of the caller.
+    ///
+    /// * 'netuids' (BoundedVec<u16>):
+    ///     - The u16 network identifiers, the caller must be registered on each of them.
+    ///
+    /// * 'version' (u32):
+    ///     - The bittensor version identifier.
+    ///
+    /// * 'endpoints' (BoundedVec<AxonEndpoint>):
+    ///     - The endpoints of the axon, in order of preference.
+    ///
+    /// # Event:
+    /// * AxonServed;
+    ///     - For every network the axon info is served on.
+    ///
+    /// * AxonServedMulti;
+    ///     - With the outcome for every network, in the order they were passed.
+    ///
+    /// # Raises:
+    /// * 'DuplicateServeAxonNetuid':
+    ///     - A network is passed more than once.
+    ///
+    /// * 'HotKeyNotRegisteredInSubNet':
+    ///     - The caller is not registered on one of the networks.
+    ///
+    /// * 'AxonNotServedOnAnySubnet':
+    ///     - The axon could not be served on any of the networks.
+    ///
+    pub fn do_serve_axon_multi(
+        origin: T::RuntimeOrigin,
+        netuids: BoundedVec<u16, T::MaxServeAxonNetuids>,
+        version: u32,
+        endpoints: BoundedVec<AxonEndpoint, MaxAxonEndpoints>,
+    ) -> dispatch::DispatchResult {
+        // We check the callers (hotkey) signature.
+        let hotkey_id = ensure_signed(origin)?;
+
+        // Ensure the hotkey is registered on every network, passed once.
+        for (index, netuid) in netuids.iter().enumerate() {
+            ensure!(
+                !netuids[..index].contains(netuid),
+                Error::<T>::DuplicateServeAxonNetuid
+            );
+            ensure!(
+                Self::is_hotkey_registered_on_network(*netuid, &hotkey_id),
+                Error::<T>::HotKeyNotRegisteredInSubNet
+            );
+        }
+
+        // Serve the axon on every network independently.
+        let outcomes: Vec<(u16, AxonServeOutcome)> = netuids
+            .into_iter()
+            .map(|netuid| {
+                let outcome = match Self::serve_axon_v2_on_network(
+                    netuid,
+                    &hotkey_id,
+                    version,
+                    endpoints.clone(),
+                ) {
+                    Ok(()) => AxonServeOutcome::Served,
+                    Err(error) => AxonServeOutcome::Failed(error),
+                };
+                (netuid, outcome)
+            })
+            .collect();
+        ensure!(
+            outcomes
+                .iter()
+                .any(|(_, outcome)| *outcome == AxonServeOutcome::Served),
+            Error::<T>::AxonNotServedOnAnySubnet
+        );
+
+        // We deposit a single event with the outcome for every network.
+        log::info!(
+            "AxonServedMulti( hotkey:{:?}, outcomes:{:?} ) ",
+            hotkey_id,
+            outcomes
+        );
+        Self::deposit_event(Event::AxonServedMulti(hotkey_id, outcomes));
+
+        // Return is successful dispatch.
+        Ok(())
+    }
+
+    /// Serves the endpoints as the axon of the hotkey on a network, if they are valid on the
+    /// network and its rate limit has passed.
+    fn serve_axon_v2_on_network(
+        netuid: u16,
+        hotkey_id: &T::AccountId,
+        version: u32,
+        endpoints: BoundedVec<AxonEndpoint, MaxAxonEndpoints>,
+    ) -> dispatch::DispatchResult {
+        // Check the endpoints validity.
+        Self::validate_axon_endpoints(netuid, &endpoints)?;
+
+        // Both axon versions are served on the same block, the rate limit applies to either.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        ensure!(
+            Self::axon_passes_rate_limit(
+                netuid,
+                &Self::get_axon_info(netuid, hotkey_id),
+                current_block
+            ),
+            Error::<T>::ServingRateLimitExceeded
+        );
+
+        // We insert the axon meta, and its first ip endpoint for the clients of the first version.
+        let axon_info = AxonInfoV2 {
+            block: current_block,
+            version,
+            endpoints,
+        };
+        Axons::<T>::insert(netuid, hotkey_id.clone(), AxonInfo::from(&axon_info));
+        AxonsV2::<T>::insert(netuid, hotkey_id.clone(), axon_info);
+
+        // We deposit axon served event.
+        log::info!("AxonServed( hotkey:{:?} ) ", hotkey_id.clone());
+        Self::deposit_event(Event::AxonServed(netuid, hotkey_id.clone()));
+
+        Ok(())
+    }
+
     /// ---- The implementation for the extrinsic serve_prometheus.
     ///
     /// # Args:
@@ -149,7 +497,8 @@ impl<T: Config> Pallet<T> {
     ///     - The ip type is not 4 or 6.
     ///
     /// * 'InvalidIpAddress':
-    ///     - The numerically encoded ip address does not resolve to a proper ip.
+    ///     - The numerically encoded ip address does not resolve to a proper ip, or is in a
+    ///       special-purpose range that is not allowed on the subnet.
     ///
     /// * 'ServingRateLimitExceeded':
     ///     - Attempting to set prometheus information withing the rate limit min.
@@ -174,7 +523,7 @@ impl<T: Config> Pallet<T> {
         // Check the ip signature validity.
         ensure!(Self::is_valid_ip_type(ip_type), Error::<T>::InvalidIpType);
         ensure!(
-            Self::is_valid_ip_address(ip_type, ip),
+            Self::is_allowed_ip_address(netuid, ip_type, ip),
             Error::<T>::InvalidIpAddress
         );
 
@@ -260,6 +609,10 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    pub fn get_axon_info_v2(netuid: u16, hotkey: &T::AccountId) -> AxonInfoV2 {
+        AxonsV2::<T>::get(netuid, hotkey).unwrap_or_default()
+    }
+
     pub fn get_prometheus_info(netuid: u16, hotkey: &T::AccountId) -> PrometheusInfoOf {
         if let Some(prometheus) = Prometheus::<T>::get(netuid, hotkey) {
             prometheus
@@ -279,37 +632,113 @@ impl<T: Config> Pallet<T> {
         allowed_values.contains(&ip_type)
     }
 
-    // @todo (Parallax 2-1-2021) : Implement exclusion of private IP ranges
+    /// Classifies an address of type v4 or v6 against the IANA special-purpose address
+    /// registries. Returns None for an invalid ip type or an ipv4 address out of range.
+    pub fn classify_ip_address(ip_type: u8, addr: u128) -> Option<IpClass> {
+        match ip_type {
+            4 => {
+                let addr: u32 = u32::try_from(addr).ok()?;
+                Some(
+                    IPV4_SPECIAL_PURPOSE
+                        .iter()
+                        .find(|(prefix, len, _)| addr >> (32 - len) == prefix >> (32 - len))
+                        .map_or(IpClass::Global, |(_, _, class)| *class),
+                )
+            }
+            6 => Some(
+                IPV6_SPECIAL_PURPOSE
+                    .iter()
+                    .find(|(prefix, len, _)| addr >> (128 - len) == prefix >> (128 - len))
+                    .map_or(IpClass::Global, |(_, _, class)| *class),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the address is a globally reachable address of type v4 or v6.
     pub fn is_valid_ip_address(ip_type: u8, addr: u128) -> bool {
-        if !Self::is_valid_ip_type(ip_type) {
-            return false;
+        Self::classify_ip_address(ip_type, addr) == Some(IpClass::Global)
+    }
+
+    /// Returns true if the address can be served on the subnet: it is globally reachable, or it is
+    /// private and the subnet allows private addresses.
+    pub fn is_allowed_ip_address(netuid: u16, ip_type: u8, addr: u128) -> bool {
+        Self::is_allowed_class(netuid, Self::classify_ip_address(ip_type, addr))
+    }
+
+    fn is_allowed_class(netuid: u16, class: Option<IpClass>) -> bool {
+        match class {
+            Some(IpClass::Global) => true,
+            Some(IpClass::Private) => Self::get_allow_private_ips(netuid),
+            _ => false,
         }
-        if addr == 0 {
-            return false;
+    }
+
+    /// Classifies a DNS name by its top-level domain against the special-use domain names.
+    /// Returns None if the name is not a valid hostname: dot separated labels of 1 to 63
+    /// letters, digits and hyphens, not starting or ending with a hyphen, with a top-level
+    /// domain that is not numeric. Single label names only resolve on a local network.
+    pub fn classify_hostname(hostname: &[u8]) -> Option<IpClass> {
+        if hostname.is_empty() || hostname.len() > 253 {
+            return None;
         }
-        if ip_type == 4 {
-            if addr == 0 {
-                return false;
-            }
-            if addr >= u32::MAX as u128 {
-                return false;
-            }
-            if addr == 0x7f000001 {
-                return false;
-            } // Localhost
+        let labels: Vec<&[u8]> = hostname.split(|byte| *byte == b'.').collect();
+        let valid_label = |label: &&[u8]| {
+            (1..=63).contains(&label.len())
+                && label
+                    .iter()
+                    .all(|byte| byte.is_ascii_alphanumeric() || *byte == b'-')
+                && label.first() != Some(&b'-')
+                && label.last() != Some(&b'-')
+        };
+        if !labels.iter().all(valid_label) {
+            return None;
         }
-        if ip_type == 6 {
-            if addr == 0x0 {
-                return false;
-            }
-            if addr == u128::MAX {
-                return false;
+        let tld: &[u8] = labels.last()?;
+        if tld.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        if labels.len() == 1
+            || PRIVATE_TLDS
+                .iter()
+                .any(|name| name.eq_ignore_ascii_case(tld))
+        {
+            Some(IpClass::Private)
+        } else if RESERVED_TLDS
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(tld))
+        {
+            Some(IpClass::Reserved)
+        } else {
+            Some(IpClass::Global)
+        }
+    }
+
+    /// Checks every endpoint of an axon: its port is set and its address can be served on the
+    /// subnet.
+    pub fn validate_axon_endpoints(
+        netuid: u16,
+        endpoints: &[AxonEndpoint],
+    ) -> Result<(), pallet::Error<T>> {
+        ensure!(!endpoints.is_empty(), Error::<T>::NoAxonEndpoints);
+        for endpoint in endpoints {
+            ensure!(endpoint.port != 0, Error::<T>::InvalidPort);
+            match &endpoint.address {
+                AxonAddress::Ipv4(ip) => ensure!(
+                    Self::is_allowed_ip_address(netuid, 4, *ip as u128),
+                    Error::<T>::InvalidIpAddress
+                ),
+                AxonAddress::Ipv6(ip) => ensure!(
+                    Self::is_allowed_ip_address(netuid, 6, *ip),
+                    Error::<T>::InvalidIpAddress
+                ),
+                AxonAddress::Dns(hostname) => ensure!(
+                    Self::is_allowed_class(netuid, Self::classify_hostname(hostname)),
+                    Error::<T>::InvalidHostname
+                ),
             }
-            if addr == 1 {
-                return false;
-            } // IPv6 localhost
         }
-        true
+        Ok(())
     }
 
     pub fn validate_axon_data(axon_info: &AxonInfoOf) -> Result<bool, pallet::Error<T>> {
@@ -9,6 +9,15 @@ pub struct StakeInfo<T: Config> {
     hotkey: T::AccountId,
     coldkey: T::AccountId,
     stake: Compact<u64>,
+    reward_destination: RewardDestination<T::AccountId>,
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
+pub struct UnbondingInfo<T: Config> {
+    hotkey: T::AccountId,
+    coldkey: T::AccountId,
+    amount: Compact<u64>,
+    unlock_block: Compact<u64>,
 }
 
 impl<T: Config> Pallet<T> {
@@ -26,6 +35,7 @@ impl<T: Config> Pallet<T> {
             for (hotkey, coldkey, stake) in <Stake<T>>::iter() {
                 if coldkey == coldkey_ {
                     stake_info_for_coldkey.push(StakeInfo {
+                        reward_destination: RewardDestinations::<T>::get(&coldkey, &hotkey),
                         hotkey,
                         coldkey,
                         stake: stake.into(),
@@ -80,4 +90,28 @@ impl<T: Config> Pallet<T> {
             first.1.clone()
         }
     }
+
+    pub fn get_unbonding_info_for_coldkey(coldkey_account_vec: Vec<u8>) -> Vec<UnbondingInfo<T>> {
+        if coldkey_account_vec.len() != 32 {
+            return Vec::new(); // Invalid coldkey
+        }
+
+        let Ok(coldkey) = T::AccountId::decode(&mut coldkey_account_vec.as_bytes_ref()) else {
+            return Vec::new();
+        };
+
+        let mut unbonding_info: Vec<UnbondingInfo<T>> = Vec::new();
+        for (hotkey, chunks) in Unbonding::<T>::iter_prefix(&coldkey) {
+            for (amount, unlock_block) in chunks {
+                unbonding_info.push(UnbondingInfo {
+                    hotkey: hotkey.clone(),
+                    coldkey: coldkey.clone(),
+                    amount: amount.into(),
+                    unlock_block: unlock_block.into(),
+                });
+            }
+        }
+
+        unbonding_info
+    }
 }
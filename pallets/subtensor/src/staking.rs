@@ -6,9 +6,10 @@ use frame_support::{
             fungible::{Balanced as _, Inspect as _, Mutate as _},
             Fortitude, Precision, Preservation,
         },
-        Imbalance,
+        Get, Imbalance,
     },
 };
+use sp_std::collections::btree_set::BTreeSet;
 
 impl<T: Config> Pallet<T> {
     /// ---- The implementation for the extrinsic become_delegate: signals that this hotkey allows delegated stake.
@@ -337,6 +338,7 @@ impl<T: Config> Pallet<T> {
 
         // If we reach here, add the balance to the hotkey.
         Self::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, actual_amount_to_stake);
+        Self::bond_unbonding_period(&coldkey, &hotkey);
 
         // Set last block for rate limiting
         let block: u64 = Self::get_current_block_as_u64();
@@ -432,11 +434,28 @@ impl<T: Config> Pallet<T> {
             Error::<T>::UnstakeRateLimitExceeded
         );
 
+        // If any subnet of the hotkey requires unbonding, ensure there is room in the queue.
+        let unbonding_period: u64 = Self::get_unbonding_period_for_stake(&coldkey, &hotkey);
+        ensure!(
+            unbonding_period == 0
+                || (Unbonding::<T>::decode_len(&coldkey, &hotkey).unwrap_or(0) as u32)
+                    < T::MaxUnbondingChunks::get(),
+            Error::<T>::TooManyUnbondingChunks
+        );
+
         // We remove the balance from the hotkey.
         Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, stake_to_be_removed);
 
-        // We add the balance to the coldkey.  If the above fails we will not credit this coldkey.
-        Self::add_balance_to_coldkey_account(&coldkey, stake_to_be_removed);
+        // We add the balance to the coldkey, or queue it until the unbonding period has passed.
+        // If the above fails we will not credit this coldkey.
+        let block: u64 = Self::get_current_block_as_u64();
+        Self::credit_removed_stake(
+            &coldkey,
+            &hotkey,
+            stake_to_be_removed,
+            unbonding_period,
+            block,
+        );
 
         // If the stake is below the minimum, we clear the nomination from storage.
         // This only applies to nominator stakes.
@@ -445,7 +464,6 @@ impl<T: Config> Pallet<T> {
         Self::clear_small_nomination_if_required(&hotkey, &coldkey, new_stake);
 
         // Set last block for rate limiting
-        let block: u64 = Self::get_current_block_as_u64();
         Self::set_last_tx_block(&coldkey, block);
 
         // Emit the unstaking event.
@@ -466,6 +484,585 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// ---- The implementation for the extrinsic move_stake: Moves stake of the caller from one hotkey to another.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'origin_hotkey' (T::AccountId):
+    ///     -  The hotkey the stake is moved away from.
+    ///
+    /// * 'destination_hotkey' (T::AccountId):
+    ///     -  The hotkey the stake is moved onto.
+    ///
+    /// * 'stake_to_be_moved' (u64):
+    ///     -  The amount of stake to be moved.
+    ///
+    /// # Event:
+    /// * StakeMoved;
+    ///     -  On successfully moving the stake.
+    ///
+    /// # Raises:
+    /// * 'SameHotkeyStakeMove':
+    ///     -  Thrown if the origin and destination hotkeys are the same.
+    ///
+    /// * 'HotKeyAccountNotExists':
+    ///     -  Thrown if either hotkey does not exist.
+    ///
+    /// * 'HotKeyNotDelegateAndSignerNotOwnHotKey':
+    ///     -  Thrown if either hotkey is neither a delegate nor owned by the caller.
+    ///
+    /// * 'StakeToWithdrawIsZero':
+    ///     -  Thrown if the amount to move is zero.
+    ///
+    /// * 'NotEnoughStakeToWithdraw':
+    ///     -  Thrown if there is not enough stake on the origin hotkey.
+    ///
+    /// * 'NomStakeBelowMinimumThreshold':
+    ///     -  Thrown if either resulting nomination would be below the minimum required stake.
+    ///
+    /// * 'UnstakeRateLimitExceeded':
+    ///     -  Thrown if key has hit the stake rate limit on the origin hotkey.
+    ///
+    /// * 'StakeRateLimitExceeded':
+    ///     -  Thrown if key has hit the stake rate limit on the destination hotkey.
+    ///
+    pub fn do_move_stake(
+        origin: T::RuntimeOrigin,
+        origin_hotkey: T::AccountId,
+        destination_hotkey: T::AccountId,
+        stake_to_be_moved: u64,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the transaction is signed by the caller and retrieve the coldkey.
+        let coldkey = ensure_signed(origin)?;
+        log::info!(
+            "do_move_stake( origin:{:?} origin_hotkey:{:?}, destination_hotkey:{:?}, stake_to_be_moved:{:?} )",
+            coldkey,
+            origin_hotkey,
+            destination_hotkey,
+            stake_to_be_moved
+        );
+
+        // --- 2. Ensure the move is between two distinct, existing hotkeys.
+        ensure!(
+            origin_hotkey != destination_hotkey,
+            Error::<T>::SameHotkeyStakeMove
+        );
+        ensure!(
+            Self::hotkey_account_exists(&origin_hotkey)
+                && Self::hotkey_account_exists(&destination_hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+
+        // --- 3. Ensure both hotkeys allow delegation or are owned by the calling coldkey.
+        ensure!(
+            Self::hotkey_is_delegate(&origin_hotkey)
+                || Self::coldkey_owns_hotkey(&coldkey, &origin_hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+        ensure!(
+            Self::hotkey_is_delegate(&destination_hotkey)
+                || Self::coldkey_owns_hotkey(&coldkey, &destination_hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+
+        // --- 4. Ensure the amount is positive and available on the origin hotkey.
+        ensure!(stake_to_be_moved > 0, Error::<T>::StakeToWithdrawIsZero);
+        ensure!(
+            Self::has_enough_stake(&coldkey, &origin_hotkey, stake_to_be_moved),
+            Error::<T>::NotEnoughStakeToWithdraw
+        );
+
+        // --- 5. Ensure neither nomination ends up below the minimum required stake.
+        let min_stake: u64 = Self::get_nominator_min_required_stake();
+        let origin_stake_after_move: u64 =
+            Self::get_stake_for_coldkey_and_hotkey(&coldkey, &origin_hotkey)
+                .saturating_sub(stake_to_be_moved);
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &origin_hotkey)
+                || origin_stake_after_move == 0
+                || origin_stake_after_move >= min_stake,
+            Error::<T>::NomStakeBelowMinimumThreshold
+        );
+        let destination_stake_after_move: u64 =
+            Self::get_stake_for_coldkey_and_hotkey(&coldkey, &destination_hotkey)
+                .saturating_add(stake_to_be_moved);
+        ensure!(
+            Self::coldkey_owns_hotkey(&coldkey, &destination_hotkey)
+                || destination_stake_after_move >= min_stake,
+            Error::<T>::NomStakeBelowMinimumThreshold
+        );
+
+        // --- 6. Ensure we don't exceed the stake rate limit on either hotkey, the move counts as
+        // an unstake from the origin and a stake onto the destination.
+        let unstakes_this_interval =
+            Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &origin_hotkey);
+        ensure!(
+            unstakes_this_interval < Self::get_target_stakes_per_interval(),
+            Error::<T>::UnstakeRateLimitExceeded
+        );
+        let stakes_this_interval =
+            Self::get_stakes_this_interval_for_coldkey_hotkey(&coldkey, &destination_hotkey);
+        ensure!(
+            stakes_this_interval < Self::get_target_stakes_per_interval(),
+            Error::<T>::StakeRateLimitExceeded
+        );
+
+        // --- 7. Move the stake. The coldkey total is left unchanged by the pair of updates.
+        // The moved stake keeps the unbonding period of the origin hotkey, unstaking it from the
+        // destination unbonds it for at least as long.
+        let unbonding_period: u64 = Self::get_unbonding_period_for_stake(&coldkey, &origin_hotkey);
+        Self::decrease_stake_on_coldkey_hotkey_account(&coldkey, &origin_hotkey, stake_to_be_moved);
+        Self::increase_stake_on_coldkey_hotkey_account(
+            &coldkey,
+            &destination_hotkey,
+            stake_to_be_moved,
+        );
+        Self::bond_unbonding_period(&coldkey, &destination_hotkey);
+        BondedUnbondingPeriod::<T>::mutate(&coldkey, &destination_hotkey, |bonded_period| {
+            *bonded_period = (*bonded_period).max(unbonding_period)
+        });
+
+        // --- 8. Set last block for rate limiting.
+        let block: u64 = Self::get_current_block_as_u64();
+        Self::set_last_tx_block(&coldkey, block);
+        Self::set_stakes_this_interval_for_coldkey_hotkey(
+            &coldkey,
+            &origin_hotkey,
+            unstakes_this_interval + 1,
+            block,
+        );
+        Self::set_stakes_this_interval_for_coldkey_hotkey(
+            &coldkey,
+            &destination_hotkey,
+            stakes_this_interval + 1,
+            block,
+        );
+
+        // --- 9. Emit the move event.
+        log::info!(
+            "StakeMoved( coldkey:{:?}, origin_hotkey:{:?}, destination_hotkey:{:?}, stake_to_be_moved:{:?} )",
+            coldkey,
+            origin_hotkey,
+            destination_hotkey,
+            stake_to_be_moved
+        );
+        Self::deposit_event(Event::StakeMoved(
+            coldkey,
+            origin_hotkey,
+            destination_hotkey,
+            stake_to_be_moved,
+        ));
+
+        // --- 10. Ok and return.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic withdraw_unbonded: Moves all matured unbonding chunks onto the coldkey.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The hotkey the stake was unbonded from.
+    ///
+    /// # Event:
+    /// * UnbondedStakeWithdrawn;
+    ///     -  On successfully withdrawing the matured chunks.
+    ///
+    /// # Raises:
+    /// * 'NoUnbondedStakeToWithdraw':
+    ///     -  Thrown if no chunk has reached its unlock block.
+    ///
+    pub fn do_withdraw_unbonded(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the transaction is signed by the caller and retrieve the coldkey.
+        let coldkey = ensure_signed(origin)?;
+        log::info!(
+            "do_withdraw_unbonded( origin:{:?} hotkey:{:?} )",
+            coldkey,
+            hotkey
+        );
+
+        // --- 2. Split the queue into matured and pending chunks.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let (matured, pending): (Vec<(u64, u64)>, Vec<(u64, u64)>) =
+            Unbonding::<T>::get(&coldkey, &hotkey)
+                .into_iter()
+                .partition(|(_, unlock_block)| *unlock_block <= current_block);
+        let amount: u64 = matured
+            .iter()
+            .fold(0u64, |acc, (chunk, _)| acc.saturating_add(*chunk));
+        ensure!(amount > 0, Error::<T>::NoUnbondedStakeToWithdraw);
+
+        // --- 3. Keep the pending chunks and credit the matured ones to the coldkey.
+        if pending.is_empty() {
+            Unbonding::<T>::remove(&coldkey, &hotkey);
+        } else {
+            Unbonding::<T>::insert(&coldkey, &hotkey, pending);
+        }
+        Self::add_balance_to_coldkey_account(&coldkey, amount);
+
+        // --- 4. Emit the withdrawal event.
+        log::info!(
+            "UnbondedStakeWithdrawn( coldkey:{:?}, hotkey:{:?}, amount:{:?} )",
+            coldkey,
+            hotkey,
+            amount
+        );
+        Self::deposit_event(Event::UnbondedStakeWithdrawn(coldkey, hotkey, amount));
+
+        // --- 5. Ok and return.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic set_reward_destination: Sets where the nominator rewards
+    /// of the caller on a hotkey are paid.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'hotkey' (T::AccountId):
+    ///     -  The hotkey the rewards are earned on.
+    ///
+    /// * 'destination' (RewardDestination<T::AccountId>):
+    ///     -  Where the rewards are paid.
+    ///
+    /// # Event:
+    /// * RewardDestinationSet;
+    ///     -  On successfully setting the reward destination.
+    ///
+    /// # Raises:
+    /// * 'HotKeyAccountNotExists':
+    ///     -  Thrown if the hotkey does not exist.
+    ///
+    /// * 'NoStakeOnHotkey':
+    ///     -  Thrown if the caller has no stake on the hotkey.
+    ///
+    pub fn do_set_reward_destination(
+        origin: T::RuntimeOrigin,
+        hotkey: T::AccountId,
+        destination: RewardDestination<T::AccountId>,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the transaction is signed by the caller and retrieve the coldkey.
+        let coldkey = ensure_signed(origin)?;
+
+        // --- 2. Ensure that the hotkey account exists.
+        ensure!(
+            Self::hotkey_account_exists(&hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+
+        // --- 3. Ensure the coldkey stakes on the hotkey, the destination is moved with its stake.
+        ensure!(
+            Stake::<T>::contains_key(&hotkey, &coldkey),
+            Error::<T>::NoStakeOnHotkey
+        );
+
+        // --- 4. Store the destination, compounding is the default and is not stored.
+        if destination == RewardDestination::Compound {
+            RewardDestinations::<T>::remove(&coldkey, &hotkey);
+        } else {
+            RewardDestinations::<T>::insert(&coldkey, &hotkey, destination.clone());
+        }
+
+        // --- 5. Emit the event.
+        log::info!(
+            "RewardDestinationSet( coldkey:{:?}, hotkey:{:?}, destination:{:?} )",
+            coldkey,
+            hotkey,
+            destination
+        );
+        Self::deposit_event(Event::RewardDestinationSet(coldkey, hotkey, destination));
+
+        // --- 6. Ok and return.
+        Ok(())
+    }
+
+    /// ---- The implementation for the extrinsic rebalance_stake: Brings the stake of the caller on each hotkey to its target.
+    ///
+    /// # Args:
+    /// * 'origin': (<T as frame_system::Config>RuntimeOrigin):
+    ///     -  The signature of the caller's coldkey.
+    ///
+    /// * 'targets' (Vec<(T::AccountId, u64)>):
+    ///     -  The hotkeys and the target stake of the caller on each of them.
+    ///
+    /// # Event:
+    /// * StakeRebalanced;
+    ///     -  With the outcome for every hotkey, in the order they were passed.
+    ///
+    /// # Raises:
+    /// * 'DuplicateRebalanceTarget':
+    ///     -  Thrown if a hotkey is passed more than once.
+    ///
+    pub fn do_rebalance_stake(
+        origin: T::RuntimeOrigin,
+        targets: Vec<(T::AccountId, u64)>,
+    ) -> dispatch::DispatchResult {
+        // --- 1. We check the transaction is signed by the caller and retrieve the coldkey.
+        let coldkey = ensure_signed(origin)?;
+        log::info!(
+            "do_rebalance_stake( origin:{:?} targets:{:?} )",
+            coldkey,
+            targets
+        );
+
+        // --- 2. Ensure every hotkey appears once, the length is bounded when the call is decoded.
+        let mut seen: BTreeSet<T::AccountId> = BTreeSet::new();
+        for (hotkey, _) in targets.iter() {
+            ensure!(
+                seen.insert(hotkey.clone()),
+                Error::<T>::DuplicateRebalanceTarget
+            );
+        }
+
+        // --- 3. Apply all decreases first, the released stake funds the increases unless it is unbonding.
+        let block: u64 = Self::get_current_block_as_u64();
+        let mut outcomes: Vec<Option<StakeRebalanceOutcome>> = vec![None; targets.len()];
+        for (index, (hotkey, target)) in targets.iter().enumerate() {
+            let current: u64 = Self::get_stake_for_coldkey_and_hotkey(&coldkey, hotkey);
+            if *target < current {
+                let outcome =
+                    match Self::rebalance_decrease(&coldkey, hotkey, current - target, block) {
+                        Ok(()) => StakeRebalanceOutcome::Decreased(current - target),
+                        Err(error) => StakeRebalanceOutcome::Failed(error),
+                    };
+                outcomes[index] = Some(outcome);
+            } else if *target == current {
+                outcomes[index] = Some(StakeRebalanceOutcome::Unchanged);
+            }
+        }
+
+        // --- 4. Apply the increases with the balance now available on the coldkey.
+        for (index, (hotkey, target)) in targets.iter().enumerate() {
+            if outcomes[index].is_some() {
+                continue;
+            }
+            let current: u64 = Self::get_stake_for_coldkey_and_hotkey(&coldkey, hotkey);
+            let outcome = match Self::rebalance_increase(
+                &coldkey,
+                hotkey,
+                target.saturating_sub(current),
+                block,
+            ) {
+                Ok(added) => StakeRebalanceOutcome::Increased(added),
+                Err(error) => StakeRebalanceOutcome::Failed(error),
+            };
+            outcomes[index] = Some(outcome);
+        }
+
+        // --- 5. Set last block for rate limiting.
+        Self::set_last_tx_block(&coldkey, block);
+
+        // --- 6. Emit a single event with the outcome for every hotkey.
+        let results: Vec<(T::AccountId, StakeRebalanceOutcome)> = targets
+            .into_iter()
+            .zip(outcomes)
+            .map(|((hotkey, _), outcome)| {
+                (hotkey, outcome.unwrap_or(StakeRebalanceOutcome::Unchanged))
+            })
+            .collect();
+        log::info!(
+            "StakeRebalanced( coldkey:{:?}, results:{:?} )",
+            coldkey,
+            results
+        );
+        Self::deposit_event(Event::StakeRebalanced(coldkey, results));
+
+        // --- 7. Ok and return.
+        Ok(())
+    }
+
+    /// Removes `decrement` of the coldkey's stake from the hotkey as part of a rebalance.
+    /// All checks of remove_stake apply, except that a nomination may not be left below the minimum.
+    ///
+    fn rebalance_decrease(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        decrement: u64,
+        block: u64,
+    ) -> dispatch::DispatchResult {
+        ensure!(
+            Self::hotkey_account_exists(hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            Self::hotkey_is_delegate(hotkey) || Self::coldkey_owns_hotkey(coldkey, hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+        let remaining: u64 =
+            Self::get_stake_for_coldkey_and_hotkey(coldkey, hotkey).saturating_sub(decrement);
+        ensure!(
+            Self::coldkey_owns_hotkey(coldkey, hotkey)
+                || remaining == 0
+                || remaining >= Self::get_nominator_min_required_stake(),
+            Error::<T>::NomStakeBelowMinimumThreshold
+        );
+        let unstakes_this_interval =
+            Self::get_stakes_this_interval_for_coldkey_hotkey(coldkey, hotkey);
+        ensure!(
+            unstakes_this_interval < Self::get_target_stakes_per_interval(),
+            Error::<T>::UnstakeRateLimitExceeded
+        );
+        let unbonding_period: u64 = Self::get_unbonding_period_for_stake(coldkey, hotkey);
+        ensure!(
+            unbonding_period == 0
+                || (Unbonding::<T>::decode_len(coldkey, hotkey).unwrap_or(0) as u32)
+                    < T::MaxUnbondingChunks::get(),
+            Error::<T>::TooManyUnbondingChunks
+        );
+
+        Self::decrease_stake_on_coldkey_hotkey_account(coldkey, hotkey, decrement);
+        Self::credit_removed_stake(coldkey, hotkey, decrement, unbonding_period, block);
+        Self::set_stakes_this_interval_for_coldkey_hotkey(
+            coldkey,
+            hotkey,
+            unstakes_this_interval + 1,
+            block,
+        );
+        Ok(())
+    }
+
+    /// Adds `increment` of the coldkey's balance onto the hotkey as part of a rebalance.
+    /// All checks of add_stake apply. Returns the amount actually staked.
+    ///
+    fn rebalance_increase(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        increment: u64,
+        block: u64,
+    ) -> Result<u64, DispatchError> {
+        ensure!(
+            Self::hotkey_account_exists(hotkey),
+            Error::<T>::HotKeyAccountNotExists
+        );
+        ensure!(
+            Self::hotkey_is_delegate(hotkey) || Self::coldkey_owns_hotkey(coldkey, hotkey),
+            Error::<T>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+        ensure!(
+            Self::coldkey_owns_hotkey(coldkey, hotkey)
+                || Self::get_stake_for_coldkey_and_hotkey(coldkey, hotkey)
+                    .saturating_add(increment)
+                    >= Self::get_nominator_min_required_stake(),
+            Error::<T>::NomStakeBelowMinimumThreshold
+        );
+        let stakes_this_interval =
+            Self::get_stakes_this_interval_for_coldkey_hotkey(coldkey, hotkey);
+        ensure!(
+            stakes_this_interval < Self::get_target_stakes_per_interval(),
+            Error::<T>::StakeRateLimitExceeded
+        );
+        ensure!(
+            Self::can_remove_balance_from_coldkey_account(coldkey, increment),
+            Error::<T>::NotEnoughBalanceToStake
+        );
+
+        let actual_amount_to_stake = Self::remove_balance_from_coldkey_account(coldkey, increment)?;
+        Self::increase_stake_on_coldkey_hotkey_account(coldkey, hotkey, actual_amount_to_stake);
+        Self::bond_unbonding_period(coldkey, hotkey);
+        Self::set_stakes_this_interval_for_coldkey_hotkey(
+            coldkey,
+            hotkey,
+            stakes_this_interval + 1,
+            block,
+        );
+        Ok(actual_amount_to_stake)
+    }
+
+    /// Credits stake removed from a hotkey onto the coldkey balance, or queues it as an
+    /// unbonding chunk when the unbonding period is non-zero.
+    ///
+    fn credit_removed_stake(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        amount: u64,
+        unbonding_period: u64,
+        block: u64,
+    ) {
+        if unbonding_period == 0 {
+            Self::add_balance_to_coldkey_account(coldkey, amount);
+            return;
+        }
+
+        let unlock_block: u64 = block.saturating_add(unbonding_period);
+        Unbonding::<T>::mutate(coldkey, hotkey, |chunks| {
+            Self::merge_unbonding_chunks(chunks, vec![(amount, unlock_block)])
+        });
+        log::info!(
+            "StakeUnbonding( coldkey:{:?}, hotkey:{:?}, amount:{:?}, unlock_block:{:?} )",
+            coldkey,
+            hotkey,
+            amount,
+            unlock_block
+        );
+        Self::deposit_event(Event::StakeUnbonding(
+            coldkey.clone(),
+            hotkey.clone(),
+            amount,
+            unlock_block,
+        ));
+    }
+
+    /// Appends the chunks to the unbonding queue. Past MaxUnbondingChunks the latest chunks are
+    /// merged, the merged chunk unlocks at the later of their unlock blocks.
+    ///
+    pub fn merge_unbonding_chunks(queue: &mut Vec<(u64, u64)>, chunks: Vec<(u64, u64)>) {
+        queue.extend(chunks);
+        let max_chunks: usize = (T::MaxUnbondingChunks::get() as usize).max(1);
+        while queue.len() > max_chunks {
+            if let (Some((amount, unlock_block)), Some(last)) = (queue.pop(), queue.last_mut()) {
+                last.0 = last.0.saturating_add(amount);
+                last.1 = last.1.max(unlock_block);
+            }
+        }
+    }
+
+    /// Returns the longest unbonding period over the subnets the hotkey is registered on.
+    ///
+    pub fn get_unbonding_period_for_hotkey(hotkey: &T::AccountId) -> u64 {
+        Self::get_registered_networks_for_hotkey(hotkey)
+            .into_iter()
+            .map(Self::get_unbonding_period)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the unbonding period of stake the coldkey removes from the hotkey: the longer of
+    /// the period recorded when the stake was bonded and the current period of the hotkey.
+    ///
+    pub fn get_unbonding_period_for_stake(coldkey: &T::AccountId, hotkey: &T::AccountId) -> u64 {
+        BondedUnbondingPeriod::<T>::get(coldkey, hotkey)
+            .max(Self::get_unbonding_period_for_hotkey(hotkey))
+    }
+
+    /// Records the unbonding period of the hotkey for the stake the coldkey bonds onto it, so
+    /// leaving the subnets of the hotkey does not shorten it.
+    ///
+    pub fn bond_unbonding_period(coldkey: &T::AccountId, hotkey: &T::AccountId) {
+        let unbonding_period: u64 = Self::get_unbonding_period_for_stake(coldkey, hotkey);
+        if unbonding_period > 0 {
+            BondedUnbondingPeriod::<T>::insert(coldkey, hotkey, unbonding_period);
+        }
+    }
+
+    /// Returns the total amount of stake waiting to be withdrawn for the coldkey and hotkey.
+    ///
+    pub fn get_total_unbonding_for_coldkey_hotkey(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+    ) -> u64 {
+        Unbonding::<T>::get(coldkey, hotkey)
+            .iter()
+            .fold(0u64, |acc, (amount, _)| acc.saturating_add(*amount))
+    }
+
     // Returns true if the passed hotkey allow delegative staking.
     //
     pub fn hotkey_is_delegate(hotkey: &T::AccountId) -> bool {
@@ -558,8 +1155,19 @@ impl<T: Config> Pallet<T> {
     //
     pub fn create_account_if_non_existent(coldkey: &T::AccountId, hotkey: &T::AccountId) {
         if !Self::hotkey_account_exists(hotkey) {
+            Self::record_staking_hotkey(coldkey, hotkey);
             Stake::<T>::insert(hotkey, coldkey, 0);
             Owner::<T>::insert(hotkey, coldkey);
+            OwnedHotkeys::<T>::append(coldkey, hotkey.clone());
+        }
+    }
+
+    // Records the hotkey among the staking hotkeys of the coldkey, if the pairing has no stake
+    // entry yet. Called before a stake entry is written.
+    //
+    pub fn record_staking_hotkey(coldkey: &T::AccountId, hotkey: &T::AccountId) {
+        if !Stake::<T>::contains_key(hotkey, coldkey) {
+            StakingHotkeys::<T>::append(coldkey, hotkey.clone());
         }
     }
 
@@ -624,6 +1232,18 @@ impl<T: Config> Pallet<T> {
         coldkey: &T::AccountId,
         hotkey: &T::AccountId,
         increment: u64,
+    ) {
+        Self::record_staking_hotkey(coldkey, hotkey);
+        Self::increase_stake_on_staked_coldkey_hotkey_account(coldkey, hotkey, increment);
+    }
+
+    // Increases the stake on a cold - hot pairing that already has a stake entry, which is
+    // therefore already indexed in StakingHotkeys.
+    //
+    pub fn increase_stake_on_staked_coldkey_hotkey_account(
+        coldkey: &T::AccountId,
+        hotkey: &T::AccountId,
+        increment: u64,
     ) {
         TotalColdkeyStake::<T>::insert(
             coldkey,
@@ -653,11 +1273,11 @@ impl<T: Config> Pallet<T> {
             hotkey,
             TotalHotkeyStake::<T>::get(hotkey).saturating_sub(decrement),
         );
-        Stake::<T>::insert(
-            hotkey,
-            coldkey,
-            Stake::<T>::get(hotkey, coldkey).saturating_sub(decrement),
-        );
+        let remaining: u64 = Stake::<T>::get(hotkey, coldkey).saturating_sub(decrement);
+        Stake::<T>::insert(hotkey, coldkey, remaining);
+        if remaining == 0 {
+            BondedUnbondingPeriod::<T>::remove(coldkey, hotkey);
+        }
         TotalStake::<T>::put(TotalStake::<T>::get().saturating_sub(decrement));
     }
 
@@ -680,7 +1300,17 @@ impl<T: Config> Pallet<T> {
         let current_stake: u64 = Stake::<T>::get(hotkey, coldkey);
         TotalColdkeyStake::<T>::mutate(coldkey, |old| *old = old.saturating_sub(current_stake));
         TotalHotkeyStake::<T>::mutate(hotkey, |stake| *stake = stake.saturating_sub(current_stake));
-        Stake::<T>::remove(hotkey, coldkey);
+        // The pairing stays while it holds state the hotkey swap has to move.
+        if Unbonding::<T>::contains_key(coldkey, hotkey)
+            || TotalHotkeyColdkeyStakesThisInterval::<T>::contains_key(coldkey, hotkey)
+        {
+            Stake::<T>::insert(hotkey, coldkey, 0);
+        } else {
+            Stake::<T>::remove(hotkey, coldkey);
+            StakingHotkeys::<T>::mutate(coldkey, |hotkeys| hotkeys.retain(|h| h != hotkey));
+        }
+        BondedUnbondingPeriod::<T>::remove(coldkey, hotkey);
+        RewardDestinations::<T>::remove(coldkey, hotkey);
         TotalStake::<T>::mutate(|stake| *stake = stake.saturating_sub(current_stake));
         TotalIssuance::<T>::mutate(|issuance| *issuance = issuance.saturating_sub(current_stake));
 
@@ -725,6 +1355,15 @@ impl<T: Config> Pallet<T> {
         let _ = T::Currency::deposit(coldkey, amount, Precision::BestEffort);
     }
 
+    /// Deposits the amount to the free balance of a coldkey. Returns false when nothing was deposited
+    /// because the amount is below the existential deposit of an account that does not exist yet.
+    pub fn try_add_balance_to_coldkey_account(
+        coldkey: &T::AccountId,
+        amount: <<T as Config>::Currency as fungible::Inspect<<T as system::Config>::AccountId>>::Balance,
+    ) -> bool {
+        T::Currency::deposit(coldkey, amount, Precision::Exact).is_ok()
+    }
+
     pub fn set_balance_on_coldkey_account(
         coldkey: &T::AccountId,
         amount: <<T as Config>::Currency as fungible::Inspect<<T as system::Config>::AccountId>>::Balance,
@@ -783,16 +1422,27 @@ impl<T: Config> Pallet<T> {
 
     pub fn unstake_all_coldkeys_from_hotkey_account(hotkey: &T::AccountId) {
         // Iterate through all coldkeys that have a stake on this hotkey account.
+        let block: u64 = Self::get_current_block_as_u64();
         for (delegate_coldkey_i, stake_i) in
             <Stake<T> as IterableStorageDoubleMap<T::AccountId, T::AccountId, u64>>::iter_prefix(
                 hotkey,
             )
         {
+            // The stake keeps the unbonding period it was bonded with.
+            let unbonding_period: u64 =
+                Self::get_unbonding_period_for_stake(&delegate_coldkey_i, hotkey);
+
             // Remove the stake from the coldkey - hotkey pairing.
             Self::decrease_stake_on_coldkey_hotkey_account(&delegate_coldkey_i, hotkey, stake_i);
 
-            // Add the balance to the coldkey account.
-            Self::add_balance_to_coldkey_account(&delegate_coldkey_i, stake_i);
+            // Add the balance to the coldkey account, or queue it until the unbonding period has passed.
+            Self::credit_removed_stake(
+                &delegate_coldkey_i,
+                hotkey,
+                stake_i,
+                unbonding_period,
+                block,
+            );
         }
     }
 }
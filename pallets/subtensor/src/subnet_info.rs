@@ -24,6 +24,9 @@ pub struct SubnetInfo<T: Config> {
     emission_values: Compact<u64>,
     burn: Compact<u64>,
     owner: T::AccountId,
+    owner_cut: Compact<u16>,
+    emission_burn: Compact<u16>,
+    emission_recipients: Vec<(T::AccountId, Compact<u16>)>,
 }
 
 #[derive(Decode, Encode, PartialEq, Eq, Clone, Debug)]
@@ -52,6 +55,11 @@ pub struct SubnetHyperparams {
     difficulty: Compact<u64>,
     commit_reveal_weights_interval: Compact<u64>,
     commit_reveal_weights_enabled: bool,
+    liquid_alpha_enabled: bool,
+    alpha_low: Compact<u16>,
+    alpha_high: Compact<u16>,
+    alpha_sigmoid_steepness: Compact<u16>,
+    allow_private_ips: bool,
 }
 
 impl<T: Config> Pallet<T> {
@@ -75,6 +83,7 @@ impl<T: Config> Pallet<T> {
         let network_modality = <NetworkModality<T>>::get(netuid);
         let emission_values = Self::get_emission_value(netuid);
         let burn: Compact<u64> = Self::get_burn_as_u64(netuid).into();
+        let emission_split = Self::get_emission_split(netuid);
 
         // DEPRECATED
         let network_connect: Vec<[u16; 2]> = Vec::<[u16; 2]>::new();
@@ -101,6 +110,13 @@ impl<T: Config> Pallet<T> {
             emission_values: emission_values.into(),
             burn,
             owner: Self::get_subnet_owner(netuid),
+            owner_cut: emission_split.owner_cut.into(),
+            emission_burn: emission_split.burn.into(),
+            emission_recipients: emission_split
+                .recipients
+                .into_iter()
+                .map(|(recipient, share)| (recipient, share.into()))
+                .collect(),
         })
     }
 
@@ -155,6 +171,10 @@ impl<T: Config> Pallet<T> {
         let difficulty = Self::get_difficulty_as_u64(netuid);
         let commit_reveal_weights_interval = Self::get_commit_reveal_weights_interval(netuid);
         let commit_reveal_weights_enabled = Self::get_commit_reveal_weights_enabled(netuid);
+        let liquid_alpha_enabled = Self::get_liquid_alpha_enabled(netuid);
+        let (alpha_low, alpha_high) = Self::get_alpha_values(netuid);
+        let alpha_sigmoid_steepness = Self::get_alpha_sigmoid_steepness(netuid);
+        let allow_private_ips = Self::get_allow_private_ips(netuid);
 
         Some(SubnetHyperparams {
             rho: rho.into(),
@@ -181,6 +201,11 @@ impl<T: Config> Pallet<T> {
             difficulty: difficulty.into(),
             commit_reveal_weights_interval: commit_reveal_weights_interval.into(),
             commit_reveal_weights_enabled,
+            liquid_alpha_enabled,
+            alpha_low: alpha_low.into(),
+            alpha_high: alpha_high.into(),
+            alpha_sigmoid_steepness: alpha_sigmoid_steepness.into(),
+            allow_private_ips,
         })
     }
 }
@@ -0,0 +1,54 @@
+use super::*;
+use sp_io::hashing::blake2_256;
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::vec::Vec;
+
+/// Deterministic stand-in for a timelock key source, meant for tests and local networks.
+/// The key of a round is published once the chain reaches the block with the same number,
+/// but since every key can be derived up front it offers no secrecy at all.
+pub struct DeterministicTimelock<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> DeterministicTimelock<T> {
+    /// Returns the key of the round, whether it has been published or not.
+    pub fn key_for_round(round: u64) -> [u8; 32] {
+        blake2_256(&(b"subtensor/timelock", round).encode())
+    }
+
+    /// Encrypts a payload against the key of the round.
+    pub fn encrypt(round: u64, plaintext: &[u8]) -> Vec<u8> {
+        Self::apply_keystream(&Self::key_for_round(round), plaintext)
+    }
+
+    /// XORs the data with a keystream of chained blake2 blocks derived from the key.
+    fn apply_keystream(key: &[u8], data: &[u8]) -> Vec<u8> {
+        data.chunks(32)
+            .enumerate()
+            .flat_map(|(counter, chunk)| {
+                let block: [u8; 32] = blake2_256(&(key, counter as u64).encode());
+                chunk
+                    .iter()
+                    .zip(block)
+                    .map(|(byte, key_byte)| byte ^ key_byte)
+                    .collect::<Vec<u8>>()
+            })
+            .collect()
+    }
+}
+
+impl<T: frame_system::Config> TimelockKeySource for DeterministicTimelock<T> {
+    fn is_available() -> bool {
+        true
+    }
+
+    fn round_key(round: u64) -> Option<Vec<u8>> {
+        let current_block: u64 = frame_system::Pallet::<T>::block_number().unique_saturated_into();
+        if current_block < round {
+            return None;
+        }
+        Some(Self::key_for_round(round).to_vec())
+    }
+
+    fn decrypt(_round: u64, round_key: &[u8], ciphertext: &[u8]) -> Option<Vec<u8>> {
+        Some(Self::apply_keystream(round_key, ciphertext))
+    }
+}
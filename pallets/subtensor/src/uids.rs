@@ -3,6 +3,13 @@ use frame_support::storage::IterableStorageDoubleMap;
 use frame_support::storage::IterableStorageMap;
 use sp_std::vec;
 
+/// Resets the value of the uid in a per-uid vector to its default.
+fn reset_at<V: Default>(values: &mut [V], uid: u16) {
+    if let Some(value) = values.get_mut(uid as usize) {
+        *value = V::default();
+    }
+}
+
 impl<T: Config> Pallet<T> {
     /// Returns the number of filled slots on a network.
     pub fn get_subnetwork_n(netuid: u16) -> u16 {
@@ -23,20 +30,20 @@ impl<T: Config> Pallet<T> {
             new_hotkey
         );
 
-        // 1. Get the old hotkey under this position.
-        let old_hotkey: T::AccountId = Keys::<T>::get(netuid, uid_to_replace);
-
-        // 2. Remove previous set memberships.
-        Uids::<T>::remove(netuid, old_hotkey.clone());
-        IsNetworkMember::<T>::remove(old_hotkey.clone(), netuid);
-        Keys::<T>::remove(netuid, uid_to_replace);
-
-        // 2a. Check if the uid is registered in any other subnetworks.
-        let hotkey_is_registered_on_any_network: bool =
-            Self::is_hotkey_registered_on_any_network(&old_hotkey.clone());
-        if !hotkey_is_registered_on_any_network {
-            // If not, unstake all coldkeys under this hotkey.
-            Self::unstake_all_coldkeys_from_hotkey_account(&old_hotkey.clone());
+        // 1. Get the old hotkey under this position, a uid freed by a deregistration has none.
+        if let Ok(old_hotkey) = Keys::<T>::try_get(netuid, uid_to_replace) {
+            // 2. Remove previous set memberships.
+            Uids::<T>::remove(netuid, old_hotkey.clone());
+            IsNetworkMember::<T>::remove(old_hotkey.clone(), netuid);
+            Keys::<T>::remove(netuid, uid_to_replace);
+
+            // 2a. Check if the uid is registered in any other subnetworks.
+            let hotkey_is_registered_on_any_network: bool =
+                Self::is_hotkey_registered_on_any_network(&old_hotkey.clone());
+            if !hotkey_is_registered_on_any_network {
+                // If not, unstake all coldkeys under this hotkey.
+                Self::unstake_all_coldkeys_from_hotkey_account(&old_hotkey.clone());
+            }
         }
 
         // 3. Create new set memberships.
@@ -44,6 +51,7 @@ impl<T: Config> Pallet<T> {
         Keys::<T>::insert(netuid, uid_to_replace, new_hotkey.clone()); // Make hotkey - uid association.
         Uids::<T>::insert(netuid, new_hotkey.clone(), uid_to_replace); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, uid_to_replace, block_number); // Fill block at registration.
+        BurnAtRegistration::<T>::remove(netuid, uid_to_replace); // Set by burned registrations only.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
     }
 
@@ -78,9 +86,69 @@ impl<T: Config> Pallet<T> {
         Keys::<T>::insert(netuid, next_uid, new_hotkey.clone()); // Make hotkey - uid association.
         Uids::<T>::insert(netuid, new_hotkey.clone(), next_uid); // Make uid - hotkey association.
         BlockAtRegistration::<T>::insert(netuid, next_uid, block_number); // Fill block at registration.
+        BurnAtRegistration::<T>::remove(netuid, next_uid); // Set by burned registrations only.
         IsNetworkMember::<T>::insert(new_hotkey.clone(), netuid, true); // Fill network is member.
     }
 
+    /// Frees the uid of the hotkey on the network, leaving its slot empty until the next
+    /// registration fills it. Clears the axon, prometheus, weights and bonds of the neuron and
+    /// zeroes its consensus values. The block at registration of the uid is moved to the current
+    /// block, so the weights set on it so far are outdated.
+    pub fn free_neuron(netuid: u16, uid: u16, hotkey: &T::AccountId) {
+        log::debug!(
+            "free_neuron( netuid: {:?} | uid: {:?} | hotkey: {:?} ) ",
+            netuid,
+            uid,
+            hotkey
+        );
+
+        // 1. Remove set memberships.
+        Uids::<T>::remove(netuid, hotkey);
+        IsNetworkMember::<T>::remove(hotkey, netuid);
+        Keys::<T>::remove(netuid, uid);
+
+        // 1a. Unstake all coldkeys if the hotkey is not registered in any other subnetwork.
+        if !Self::is_hotkey_registered_on_any_network(hotkey) {
+            Self::unstake_all_coldkeys_from_hotkey_account(hotkey);
+        }
+
+        // 2. Clear the serving information, weights and bonds of the neuron.
+        Axons::<T>::remove(netuid, hotkey);
+        AxonsV2::<T>::remove(netuid, hotkey);
+        Prometheus::<T>::remove(netuid, hotkey);
+        WeightCommits::<T>::remove(netuid, hotkey);
+        Weights::<T>::remove(netuid, uid);
+        Bonds::<T>::remove(netuid, uid);
+
+        // 3. Reset the Yuma Consensus position.
+        Rank::<T>::mutate(netuid, |v| reset_at(v, uid));
+        Trust::<T>::mutate(netuid, |v| reset_at(v, uid));
+        Active::<T>::mutate(netuid, |v| reset_at(v, uid));
+        Emission::<T>::mutate(netuid, |v| reset_at(v, uid));
+        Consensus::<T>::mutate(netuid, |v| reset_at(v, uid));
+        Incentive::<T>::mutate(netuid, |v| reset_at(v, uid));
+        Dividends::<T>::mutate(netuid, |v| reset_at(v, uid));
+        PruningScores::<T>::mutate(netuid, |v| reset_at(v, uid));
+        ValidatorTrust::<T>::mutate(netuid, |v| reset_at(v, uid));
+        ValidatorPermit::<T>::mutate(netuid, |v| reset_at(v, uid));
+        LastUpdate::<T>::mutate(netuid, |v| reset_at(v, uid));
+        BlockAtRegistration::<T>::insert(netuid, uid, Self::get_current_block_as_u64());
+
+        // 4. Queue the uid for the next registration.
+        FreedUids::<T>::mutate(netuid, |uids| uids.push(uid));
+    }
+
+    /// Takes the uid freed first on the network, if any is waiting for a registration.
+    pub fn take_freed_uid(netuid: u16) -> Option<u16> {
+        FreedUids::<T>::mutate(netuid, |uids| {
+            if uids.is_empty() {
+                None
+            } else {
+                Some(uids.remove(0))
+            }
+        })
+    }
+
     /// Returns true if the uid is set on the network.
     ///
     pub fn is_uid_exist_on_network(netuid: u16, uid: u16) -> bool {
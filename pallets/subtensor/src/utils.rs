@@ -1,5 +1,6 @@
 use super::*;
 use crate::system::{ensure_root, ensure_signed_or_root};
+use frame_support::traits::Get;
 use sp_core::U256;
 
 impl<T: Config> Pallet<T> {
@@ -373,6 +374,22 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::ServingRateLimitSet(netuid, serving_rate_limit));
     }
 
+    pub fn get_deregistration_refund_ratio(netuid: u16) -> u16 {
+        DeregistrationRefundRatio::<T>::get(netuid)
+    }
+    pub fn set_deregistration_refund_ratio(netuid: u16, ratio: u16) {
+        DeregistrationRefundRatio::<T>::insert(netuid, ratio);
+        Self::deposit_event(Event::DeregistrationRefundRatioSet(netuid, ratio));
+    }
+
+    pub fn get_allow_private_ips(netuid: u16) -> bool {
+        AllowPrivateIps::<T>::get(netuid)
+    }
+    pub fn set_allow_private_ips(netuid: u16, allow_private_ips: bool) {
+        AllowPrivateIps::<T>::insert(netuid, allow_private_ips);
+        Self::deposit_event(Event::AllowPrivateIpsSet(netuid, allow_private_ips));
+    }
+
     pub fn get_min_difficulty(netuid: u16) -> u64 {
         MinDifficulty::<T>::get(netuid)
     }
@@ -464,6 +481,22 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::MinAllowedWeightSet(netuid, min_allowed_weights));
     }
 
+    pub fn get_max_epoch_snapshot_retention() -> u16 {
+        T::MaxEpochSnapshotRetention::get()
+    }
+    pub fn get_epoch_snapshot_retention(netuid: u16) -> u16 {
+        EpochSnapshotRetention::<T>::get(netuid)
+    }
+    /// Changing the retention drops the snapshots taken so far, their slots no longer line up.
+    pub fn set_epoch_snapshot_retention(netuid: u16, retention: u16) {
+        if retention != Self::get_epoch_snapshot_retention(netuid) {
+            EpochSnapshotRetention::<T>::insert(netuid, retention);
+            EpochSnapshotsTaken::<T>::remove(netuid);
+            let _ = EpochSnapshots::<T>::clear_prefix(netuid, u32::MAX, None);
+        }
+        Self::deposit_event(Event::EpochSnapshotRetentionSet(netuid, retention));
+    }
+
     pub fn get_max_allowed_uids(netuid: u16) -> u16 {
         MaxAllowedUids::<T>::get(netuid)
     }
@@ -493,6 +526,73 @@ impl<T: Config> Pallet<T> {
         CommitRevealWeightsEnabled::<T>::set(netuid, enabled);
     }
 
+    pub fn get_unbonding_period(netuid: u16) -> u64 {
+        UnbondingPeriod::<T>::get(netuid)
+    }
+    pub fn set_unbonding_period(netuid: u16, unbonding_period: u64) {
+        UnbondingPeriod::<T>::insert(netuid, unbonding_period);
+        Self::deposit_event(Event::UnbondingPeriodSet(netuid, unbonding_period));
+    }
+
+    pub fn get_dense_epoch_threshold() -> u16 {
+        DenseEpochThreshold::<T>::get()
+    }
+    pub fn set_dense_epoch_threshold(threshold: u16) {
+        DenseEpochThreshold::<T>::put(threshold);
+        Self::deposit_event(Event::DenseEpochThresholdSet(threshold));
+    }
+
+    pub fn get_liquid_alpha_enabled(netuid: u16) -> bool {
+        LiquidAlphaOn::<T>::get(netuid)
+    }
+    pub fn set_liquid_alpha_enabled(netuid: u16, enabled: bool) {
+        LiquidAlphaOn::<T>::insert(netuid, enabled);
+        Self::deposit_event(Event::LiquidAlphaEnabledSet(netuid, enabled));
+    }
+
+    pub fn get_alpha_values(netuid: u16) -> (u16, u16) {
+        AlphaValues::<T>::get(netuid)
+    }
+    pub fn set_alpha_values(netuid: u16, alpha_low: u16, alpha_high: u16) {
+        AlphaValues::<T>::insert(netuid, (alpha_low, alpha_high));
+        Self::deposit_event(Event::AlphaValuesSet(netuid, alpha_low, alpha_high));
+    }
+
+    pub fn get_alpha_values_bounds() -> (u16, u16) {
+        AlphaValuesBounds::<T>::get()
+    }
+    pub fn set_alpha_values_bounds(min_alpha_low: u16, max_alpha_high: u16) {
+        AlphaValuesBounds::<T>::put((min_alpha_low, max_alpha_high));
+        Self::deposit_event(Event::AlphaValuesBoundsSet(min_alpha_low, max_alpha_high));
+    }
+
+    pub fn get_alpha_sigmoid_steepness(netuid: u16) -> u16 {
+        AlphaSigmoidSteepness::<T>::get(netuid)
+    }
+    pub fn set_alpha_sigmoid_steepness(netuid: u16, steepness: u16) {
+        AlphaSigmoidSteepness::<T>::insert(netuid, steepness);
+        Self::deposit_event(Event::AlphaSigmoidSteepnessSet(netuid, steepness));
+    }
+
+    /// Returns the maximum total share of an emission split, the subnet owner cut until governance
+    /// sets one.
+    pub fn get_max_emission_split_share() -> u16 {
+        MaxEmissionSplitShare::<T>::get().unwrap_or_else(Self::get_subnet_owner_cut)
+    }
+    pub fn set_max_emission_split_share(max_share: u16) {
+        MaxEmissionSplitShare::<T>::put(max_share);
+        Self::deposit_event(Event::MaxEmissionSplitShareSet(max_share));
+    }
+
+    /// Returns the emission split of a subnet, the global subnet owner cut when its owner has not set one.
+    pub fn get_emission_split(netuid: u16) -> EmissionSplit<T::AccountId> {
+        SubnetEmissionSplit::<T>::get(netuid).unwrap_or_else(|| EmissionSplit {
+            owner_cut: Self::get_subnet_owner_cut(),
+            burn: 0,
+            recipients: Vec::new(),
+        })
+    }
+
     pub fn get_rho(netuid: u16) -> u16 {
         Rho::<T>::get(netuid)
     }
@@ -0,0 +1,60 @@
+//! Weights for `pallet_subtensor` extrinsics benchmarked in `benchmarks.rs`.
+//!
+//! These values are estimated from the storage each extrinsic accesses and have not been
+//! produced by the benchmark CLI yet. Regenerate the file with `benchmark pallet` against
+//! `benchmarks.rs` whenever the benchmarked extrinsics change.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_subtensor`.
+pub trait WeightInfo {
+	fn rebalance_stake(n: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_subtensor` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: SubtensorModule LastTxBlock (r:0 w:1)
+	/// Storage: SubtensorModule Owner (r:1 w:0)
+	/// Storage: SubtensorModule Delegates (r:1 w:0)
+	/// Storage: SubtensorModule Stake (r:2 w:1)
+	/// Storage: SubtensorModule TotalHotkeyColdkeyStakesThisInterval (r:1 w:1)
+	/// Storage: SubtensorModule StakeInterval (r:1 w:0)
+	/// Storage: SubtensorModule TargetStakesPerInterval (r:1 w:0)
+	/// Storage: SubtensorModule NominatorMinRequiredStake (r:1 w:0)
+	/// Storage: SubtensorModule BondedUnbondingPeriod (r:1 w:1)
+	/// Storage: SubtensorModule IsNetworkMember (r:1 w:0)
+	/// Storage: SubtensorModule UnbondingPeriod (r:1 w:0)
+	/// Storage: SubtensorModule Unbonding (r:1 w:1)
+	/// Storage: SubtensorModule StakingHotkeys (r:1 w:1)
+	/// Storage: SubtensorModule TotalColdkeyStake (r:1 w:1)
+	/// Storage: SubtensorModule TotalHotkeyStake (r:1 w:1)
+	/// Storage: SubtensorModule TotalStake (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[1, 64]`.
+	fn rebalance_stake(n: u32, ) -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(116_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((17_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((10_u64).saturating_mul(n.into())))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// The range of component `n` is `[1, 64]`.
+	fn rebalance_stake(n: u32, ) -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(116_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((17_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((10_u64).saturating_mul(n.into())))
+	}
+}
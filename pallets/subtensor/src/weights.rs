@@ -1,5 +1,7 @@
 use super::*;
 use crate::math::*;
+use frame_support::traits::Get;
+use frame_support::weights::Weight;
 use sp_core::H256;
 use sp_runtime::traits::{BlakeTwo256, Hash};
 use sp_std::vec;
@@ -19,7 +21,10 @@ impl<T: Config> Pallet<T> {
     ///
     /// # Raises:
     /// * `WeightsCommitNotAllowed`:
-    ///   - Attempting to commit when it is not allowed.
+    ///   - Attempting to commit a hash that is already pending.
+    ///
+    /// * `TooManyPendingWeightCommits`:
+    ///   - Attempting to commit while the queue of pending commits is full.
     ///
     pub fn do_commit_weights(
         origin: T::RuntimeOrigin,
@@ -36,15 +41,21 @@ impl<T: Config> Pallet<T> {
         );
 
         ensure!(
-            Self::can_commit(netuid, &who),
+            Self::can_commit(netuid, &who, &commit_hash),
             Error::<T>::WeightsCommitNotAllowed
         );
 
-        WeightCommits::<T>::insert(
-            netuid,
-            &who,
-            (commit_hash, Self::get_current_block_as_u64()),
+        // Commits that can no longer be revealed do not take up room in the queue.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        Self::drop_expired_weight_commits(netuid, &who, current_block);
+        ensure!(
+            (WeightCommits::<T>::decode_len(netuid, &who).unwrap_or(0) as u32)
+                < T::MaxPendingWeightCommits::get(),
+            Error::<T>::TooManyPendingWeightCommits
         );
+
+        WeightCommits::<T>::append(netuid, &who, (commit_hash, current_block));
+        Self::schedule_weight_commit_expiry(netuid, &who, current_block);
         Ok(())
     }
 
@@ -96,16 +107,11 @@ impl<T: Config> Pallet<T> {
             Error::<T>::CommitRevealDisabled
         );
 
-        WeightCommits::<T>::try_mutate_exists(netuid, &who, |maybe_commit| -> DispatchResult {
-            let (commit_hash, commit_block) = maybe_commit
-                .as_ref()
+        WeightCommits::<T>::try_mutate_exists(netuid, &who, |maybe_commits| -> DispatchResult {
+            let commits = maybe_commits
+                .as_mut()
                 .ok_or(Error::<T>::NoWeightsCommitFound)?;
 
-            ensure!(
-                Self::is_reveal_block_range(netuid, *commit_block),
-                Error::<T>::InvalidRevealCommitTempo
-            );
-
             let provided_hash: H256 = BlakeTwo256::hash_of(&(
                 who.clone(),
                 netuid,
@@ -114,15 +120,111 @@ impl<T: Config> Pallet<T> {
                 salt.clone(),
                 version_key,
             ));
+            let position: usize = commits
+                .iter()
+                .position(|(commit_hash, _)| *commit_hash == provided_hash)
+                .ok_or(Error::<T>::InvalidRevealCommitHashNotMatch)?;
+
+            let (_, commit_block) = commits[position];
             ensure!(
-                provided_hash == *commit_hash,
-                Error::<T>::InvalidRevealCommitHashNotMatch
+                Self::is_reveal_block_range(netuid, commit_block),
+                Error::<T>::InvalidRevealCommitTempo
             );
 
-            Self::do_set_weights(origin, netuid, uids, values, version_key)
+            Self::do_set_weights(origin, netuid, uids, values, version_key)?;
+
+            // The commit is consumed by the reveal.
+            commits.remove(position);
+            if commits.is_empty() {
+                *maybe_commits = None;
+            }
+            Ok(())
         })
     }
 
+    /// ---- The implementation for committing timelocked weights.
+    ///
+    /// # Args:
+    /// * `origin`: (`<T as frame_system::Config>::RuntimeOrigin`):
+    ///   - The signature of the committing hotkey.
+    ///
+    /// * `netuid` (`u16`):
+    ///   - The u16 network identifier.
+    ///
+    /// * `ciphertext` (`Vec<u8>`):
+    ///   - The SCALE encoded ( uids, values, version_key ) encrypted against the round key.
+    ///
+    /// * `round` (`u64`):
+    ///   - The round whose key decrypts the payload.
+    ///
+    /// # Raises:
+    /// * `TimelockUnavailable`:
+    ///   - The runtime has no source of round keys.
+    ///
+    /// * `HotKeyNotRegisteredInSubNet`:
+    ///   - The hotkey is not registered on the network.
+    ///
+    /// * `InvalidTimelockRound`:
+    ///   - The round is not inside the reveal window of a commit made now.
+    ///
+    /// * `TooManyPendingWeightCommits`:
+    ///   - Attempting to commit while the queue of pending commits is full.
+    ///
+    pub fn do_commit_timelocked_weights(
+        origin: T::RuntimeOrigin,
+        netuid: u16,
+        ciphertext: Vec<u8>,
+        round: u64,
+    ) -> DispatchResult {
+        let who = ensure_signed(origin)?;
+
+        log::info!(
+            "do_commit_timelocked_weights( hotkey:{:?} netuid:{:?} round:{:?} )",
+            who,
+            netuid,
+            round
+        );
+
+        // Without round keys the commit could never be applied.
+        ensure!(
+            T::TimelockKeySource::is_available(),
+            Error::<T>::TimelockUnavailable
+        );
+
+        ensure!(
+            Self::get_commit_reveal_weights_enabled(netuid),
+            Error::<T>::CommitRevealDisabled
+        );
+
+        ensure!(
+            Self::is_hotkey_registered_on_network(netuid, &who),
+            Error::<T>::HotKeyNotRegisteredInSubNet
+        );
+
+        // The payload must stay sealed until the round, and be applied where a reveal would be.
+        let current_block: u64 = Self::get_current_block_as_u64();
+        let (_, reveal_end) = Self::get_reveal_block_range(netuid, current_block);
+        ensure!(
+            round > current_block && round < reveal_end,
+            Error::<T>::InvalidTimelockRound
+        );
+
+        ensure!(
+            (PendingTimelockedWeightCommits::<T>::decode_len(netuid, &who).unwrap_or(0) as u32)
+                < T::MaxPendingWeightCommits::get(),
+            Error::<T>::TooManyPendingWeightCommits
+        );
+
+        TimelockedWeightCommits::<T>::append(
+            round,
+            (netuid, who.clone(), round, current_block, ciphertext),
+        );
+        PendingTimelockedWeightCommits::<T>::append(netuid, &who, round);
+
+        Self::deposit_event(Event::TimelockedWeightsCommitted(netuid, who, round));
+        Ok(())
+    }
+
     /// ---- The implementation for the extrinsic set_weights.
     ///
     /// # Args:
@@ -448,47 +550,309 @@ impl<T: Config> Pallet<T> {
         uids.len() <= subnetwork_n as usize
     }
 
-    pub fn can_commit(netuid: u16, who: &T::AccountId) -> bool {
-        if let Some((_hash, commit_block)) = WeightCommits::<T>::get(netuid, who) {
-            let interval: u64 = Self::get_commit_reveal_weights_interval(netuid);
-            if interval == 0 {
-                return true; //prevent division by 0
-            }
-
-            let current_block: u64 = Self::get_current_block_as_u64();
-            let interval_start: u64 = current_block - (current_block % interval);
-            let last_commit_interval_start: u64 = commit_block - (commit_block % interval);
-
-            // Allow commit if we're within the interval bounds
-            if current_block <= interval_start + interval
-                && interval_start > last_commit_interval_start
-            {
-                return true;
-            }
-
-            false
-        } else {
-            true
-        }
+    /// Returns false if the same hash is already pending for this hotkey on the network.
+    pub fn can_commit(netuid: u16, who: &T::AccountId, commit_hash: &H256) -> bool {
+        !WeightCommits::<T>::get(netuid, who)
+            .unwrap_or_default()
+            .iter()
+            .any(|(pending_hash, _)| pending_hash == commit_hash)
     }
 
-    pub fn is_reveal_block_range(netuid: u16, commit_block: u64) -> bool {
+    /// Returns the [start, end) block range in which a commit made at commit_block can be revealed.
+    /// With an interval of zero the commit can be revealed at any time.
+    pub fn get_reveal_block_range(netuid: u16, commit_block: u64) -> (u64, u64) {
         let interval: u64 = Self::get_commit_reveal_weights_interval(netuid);
         if interval == 0 {
-            return true; //prevent division by 0
+            return (0, u64::MAX); //prevent division by 0
         }
 
         let commit_interval_start: u64 = commit_block - (commit_block % interval); // Find the start of the interval in which the commit occurred
-        let reveal_interval_start: u64 = commit_interval_start + interval; // Start of the next interval after the commit interval
+        let reveal_interval_start: u64 = commit_interval_start.saturating_add(interval); // Start of the next interval after the commit interval
+        (
+            reveal_interval_start,
+            reveal_interval_start.saturating_add(interval),
+        )
+    }
+
+    pub fn is_reveal_block_range(netuid: u16, commit_block: u64) -> bool {
+        let (reveal_start, reveal_end) = Self::get_reveal_block_range(netuid, commit_block);
         let current_block: u64 = Self::get_current_block_as_u64();
 
         // Allow reveal if the current block is within the interval following the commit's interval
-        if current_block >= reveal_interval_start
-            && current_block < reveal_interval_start + interval
-        {
-            return true;
+        current_block >= reveal_start && current_block < reveal_end
+    }
+
+    /// Queues the commit made at commit_block for an expiry check two intervals later, once its
+    /// reveal window has ended. Checks are spread like the commits instead of piling up on the
+    /// interval boundary. Commits never expire with an interval of zero.
+    pub fn schedule_weight_commit_expiry(netuid: u16, who: &T::AccountId, commit_block: u64) {
+        let interval: u64 = Self::get_commit_reveal_weights_interval(netuid);
+        if interval == 0 {
+            return;
         }
+        let check_block: u64 = commit_block.saturating_add(interval.saturating_mul(2));
+        WeightCommitExpiries::<T>::append(check_block, (netuid, who.clone(), commit_block));
+    }
 
-        false
+    /// Drops the weight commits of the hotkey whose reveal window has passed.
+    fn drop_expired_weight_commits(netuid: u16, who: &T::AccountId, current_block: u64) {
+        WeightCommits::<T>::mutate_exists(netuid, who, |maybe_commits| {
+            let Some(commits) = maybe_commits.as_mut() else {
+                return;
+            };
+            commits.retain(|(commit_hash, commit_block)| {
+                let (_, reveal_end) = Self::get_reveal_block_range(netuid, *commit_block);
+                if reveal_end > current_block {
+                    return true;
+                }
+                log::debug!(
+                    "CommitExpired( netuid:{:?}, hotkey:{:?}, commit_hash:{:?} )",
+                    netuid,
+                    who,
+                    commit_hash
+                );
+                Self::deposit_event(Event::CommitExpired(netuid, who.clone(), *commit_hash));
+                false
+            });
+            if commits.is_empty() {
+                *maybe_commits = None;
+            }
+        });
+    }
+
+    /// Drops the weight commits queued for an expiry check at this block. Only the commits made
+    /// in a single block are visited. Returns the weight used.
+    pub fn expire_weight_commits(current_block: u64) -> Weight {
+        let expiring = WeightCommitExpiries::<T>::take(current_block);
+        let mut weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+        for (netuid, hotkey, commit_block) in expiring {
+            Self::drop_expired_weight_commits(netuid, &hotkey, current_block);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+
+            // The interval may have grown since the commit, check it again when its window ends.
+            let still_pending: bool = WeightCommits::<T>::get(netuid, &hotkey)
+                .unwrap_or_default()
+                .iter()
+                .any(|(_, block)| *block == commit_block);
+            if still_pending {
+                Self::schedule_weight_commit_expiry(netuid, &hotkey, commit_block);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            }
+        }
+        weight
+    }
+
+    /// Decrypts the timelocked weight commits due at this block and sets their weights.
+    /// Commits whose round key is not published yet are retried on the next block until
+    /// the reveal window of the commit has passed. At most `MaxTimelockedCommitsPerBlock`
+    /// commits are processed, the rest are carried to the front of the next block's queue.
+    /// Returns the weight used.
+    pub fn apply_timelocked_weight_commits(current_block: u64) -> Weight {
+        let mut due = TimelockedWeightCommits::<T>::take(current_block);
+        let mut weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+        let next_block: u64 = current_block.saturating_add(1);
+
+        let max_per_block: usize = T::MaxTimelockedCommitsPerBlock::get() as usize;
+        let overflow = due.split_off(due.len().min(max_per_block));
+
+        for (netuid, hotkey, round, commit_block, ciphertext) in due {
+            match T::TimelockKeySource::round_key(round) {
+                Some(round_key) => {
+                    // Charged as the set_weights call the commit stands in for.
+                    weight.saturating_accrue(
+                        Weight::from_parts(22_060_000_000, 0)
+                            .saturating_add(T::DbWeight::get().reads(4106))
+                            .saturating_add(T::DbWeight::get().writes(2)),
+                    );
+                    match Self::apply_timelocked_weights(
+                        netuid,
+                        &hotkey,
+                        round,
+                        &round_key,
+                        &ciphertext,
+                    ) {
+                        Ok(()) => Self::deposit_event(Event::TimelockedWeightsApplied(
+                            netuid,
+                            hotkey.clone(),
+                            round,
+                        )),
+                        Err(e) => {
+                            log::debug!(
+                                "TimelockedWeightsFailed( netuid:{:?}, hotkey:{:?}, round:{:?}, error:{:?} )",
+                                netuid,
+                                hotkey,
+                                round,
+                                e
+                            );
+                            Self::deposit_event(Event::TimelockedWeightsFailed(
+                                netuid,
+                                hotkey.clone(),
+                                round,
+                                e,
+                            ))
+                        }
+                    }
+                }
+                None => {
+                    // The window is the one checked when the commit was made.
+                    let (_, reveal_end) = Self::get_reveal_block_range(netuid, commit_block);
+                    weight.saturating_accrue(T::DbWeight::get().reads(1));
+                    if next_block < reveal_end {
+                        Self::move_pending_timelocked_commit(
+                            netuid,
+                            &hotkey,
+                            current_block,
+                            Some(next_block),
+                        );
+                        TimelockedWeightCommits::<T>::append(
+                            next_block,
+                            (netuid, hotkey, round, commit_block, ciphertext),
+                        );
+                        weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+                        continue;
+                    }
+                    Self::deposit_event(Event::TimelockedWeightsExpired(
+                        netuid,
+                        hotkey.clone(),
+                        round,
+                    ));
+                }
+            }
+            Self::move_pending_timelocked_commit(netuid, &hotkey, current_block, None);
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+
+        // The commits over the bound go ahead of the ones queued for the next block.
+        if !overflow.is_empty() {
+            for (netuid, hotkey, _, _, _) in overflow.iter() {
+                Self::move_pending_timelocked_commit(
+                    *netuid,
+                    hotkey,
+                    current_block,
+                    Some(next_block),
+                );
+            }
+            weight.saturating_accrue(
+                T::DbWeight::get().reads_writes(overflow.len() as u64, overflow.len() as u64),
+            );
+            TimelockedWeightCommits::<T>::mutate(next_block, |queued| {
+                let mut carried = overflow;
+                carried.append(queued);
+                *queued = carried;
+            });
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+        weight
+    }
+
+    /// Moves one pending timelocked commit of the hotkey from the block it is queued at to
+    /// another block, or forgets it when no block is given.
+    fn move_pending_timelocked_commit(
+        netuid: u16,
+        hotkey: &T::AccountId,
+        from_block: u64,
+        to_block: Option<u64>,
+    ) {
+        PendingTimelockedWeightCommits::<T>::mutate_exists(netuid, hotkey, |maybe_blocks| {
+            let Some(blocks) = maybe_blocks.as_mut() else {
+                return;
+            };
+            if let Some(index) = blocks.iter().position(|block| *block == from_block) {
+                match to_block {
+                    Some(to_block) => blocks[index] = to_block,
+                    None => {
+                        blocks.swap_remove(index);
+                    }
+                }
+            }
+            if blocks.is_empty() {
+                *maybe_blocks = None;
+            }
+        });
+    }
+
+    /// Moves the weight commits of a hotkey on a network to another hotkey, along with the
+    /// expiry checks and the queued timelocked commits. Returns the weight used.
+    pub fn swap_weight_commits(
+        netuid: u16,
+        old_hotkey: &T::AccountId,
+        new_hotkey: &T::AccountId,
+    ) -> Weight {
+        let mut weight: Weight = T::DbWeight::get().reads(2);
+
+        if let Some(commits) = WeightCommits::<T>::take(netuid, old_hotkey) {
+            let interval: u64 = Self::get_commit_reveal_weights_interval(netuid);
+            for (_, commit_block) in commits.iter().filter(|_| interval > 0) {
+                // The expiry check is queued where it was scheduled, unless the interval changed since.
+                let check_block: u64 = commit_block.saturating_add(interval.saturating_mul(2));
+                let mut moved: bool = false;
+                WeightCommitExpiries::<T>::mutate_exists(check_block, |maybe_expiries| {
+                    for (expiry_netuid, who, expiry_commit_block) in
+                        maybe_expiries.iter_mut().flatten()
+                    {
+                        if *expiry_netuid == netuid
+                            && *who == *old_hotkey
+                            && *expiry_commit_block == *commit_block
+                        {
+                            *who = new_hotkey.clone();
+                            moved = true;
+                        }
+                    }
+                });
+                if !moved {
+                    Self::schedule_weight_commit_expiry(netuid, new_hotkey, *commit_block);
+                }
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+            }
+            WeightCommits::<T>::insert(netuid, new_hotkey, commits);
+            weight.saturating_accrue(T::DbWeight::get().writes(2));
+        }
+
+        if let Ok(blocks) = PendingTimelockedWeightCommits::<T>::try_get(netuid, old_hotkey) {
+            // Only the blocks the commits of the hotkey are queued at are visited.
+            let mut queued: Vec<u64> = blocks.clone();
+            queued.sort_unstable();
+            queued.dedup();
+            for block in queued.iter() {
+                TimelockedWeightCommits::<T>::mutate(block, |commits| {
+                    for (commit_netuid, who, _, _, _) in commits.iter_mut() {
+                        if *commit_netuid == netuid && *who == *old_hotkey {
+                            *who = new_hotkey.clone();
+                        }
+                    }
+                });
+            }
+            weight.saturating_accrue(
+                T::DbWeight::get().reads_writes(queued.len() as u64, queued.len() as u64),
+            );
+            PendingTimelockedWeightCommits::<T>::remove(netuid, old_hotkey);
+            PendingTimelockedWeightCommits::<T>::insert(netuid, new_hotkey, blocks);
+            weight.saturating_accrue(T::DbWeight::get().writes(2));
+        }
+
+        weight
+    }
+
+    /// Decrypts a single timelocked weight commit and sets the weights on behalf of the hotkey.
+    fn apply_timelocked_weights(
+        netuid: u16,
+        hotkey: &T::AccountId,
+        round: u64,
+        round_key: &[u8],
+        ciphertext: &[u8],
+    ) -> DispatchResult {
+        let plaintext: Vec<u8> = T::TimelockKeySource::decrypt(round, round_key, ciphertext)
+            .ok_or(Error::<T>::TimelockDecryptionFailed)?;
+        let (uids, values, version_key) = <(Vec<u16>, Vec<u16>, u64)>::decode(&mut &plaintext[..])
+            .map_err(|_| Error::<T>::TimelockDecryptionFailed)?;
+
+        // Commit/reveal may have been switched off since the commit, the weights still apply.
+        Self::do_set_weights(
+            frame_system::RawOrigin::Signed(hotkey.clone()).into(),
+            netuid,
+            uids,
+            values,
+            version_key,
+        )
     }
 }
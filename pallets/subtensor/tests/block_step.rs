@@ -1,8 +1,9 @@
 mod mock;
+use codec::Encode;
 use frame_support::assert_ok;
 use frame_system::Config;
 use mock::*;
-use sp_core::U256;
+use sp_core::{Get, U256};
 
 #[test]
 #[allow(clippy::unwrap_used)]
@@ -895,3 +896,258 @@ fn test_emission_based_on_registration_status() {
         );
     });
 }
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_drain_emission_records_ledger() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let nominator = U256::from(2);
+        let delegate = U256::from(10);
+        let server = U256::from(11);
+        add_network(netuid, 10, 0);
+
+        // A delegate hotkey with a nominator and a plain hotkey.
+        SubtensorModule::create_account_if_non_existent(&owner, &delegate);
+        SubtensorModule::create_account_if_non_existent(&owner, &server);
+        SubtensorModule::delegate_hotkey(&delegate, u16::MAX / 10);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&owner, &delegate, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &delegate, 3_000);
+
+        for coldkey in [owner, nominator] {
+            assert_ok!(SubtensorModule::set_emission_ledger_enabled(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                true
+            ));
+        }
+
+        pallet_subtensor::LoadedEmission::<Test>::insert(
+            netuid,
+            vec![(delegate, 100, 1_000), (server, 50, 500)],
+        );
+        SubtensorModule::drain_emission(5);
+
+        let owner_records = SubtensorModule::get_emission_records(&owner, 0, u64::MAX);
+        let nominator_records = SubtensorModule::get_emission_records(&nominator, 0, u64::MAX);
+        assert_eq!(owner_records.len(), 2);
+        assert_eq!(nominator_records.len(), 1);
+
+        // The delegate's owner gets the server emission and the take, plus its own share as a nominator.
+        let owner_delegate = owner_records
+            .iter()
+            .find(|record| record.hotkey == delegate)
+            .unwrap();
+        assert_eq!(owner_delegate.block, 5);
+        assert_eq!(owner_delegate.netuid, netuid);
+        assert_eq!(owner_delegate.server_emission, 100);
+        assert_eq!(owner_delegate.validator_emission, 0);
+        assert!(owner_delegate.delegate_take > 0);
+        assert!(owner_delegate.nominator_share > 0);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&owner, &delegate),
+            1_000
+                + owner_delegate.server_emission
+                + owner_delegate.delegate_take
+                + owner_delegate.nominator_share
+        );
+        let nominator_delegate = &nominator_records[0];
+        assert_eq!(nominator_delegate.hotkey, delegate);
+        assert_eq!(nominator_delegate.server_emission, 0);
+        assert_eq!(nominator_delegate.delegate_take, 0);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &delegate),
+            3_000 + nominator_delegate.nominator_share
+        );
+        assert_eq!(
+            owner_delegate.delegate_take
+                + owner_delegate.nominator_share
+                + nominator_delegate.nominator_share,
+            1_000
+        );
+
+        // The owner of a hotkey that is not a delegate gets everything.
+        let owner_server = owner_records
+            .iter()
+            .find(|record| record.hotkey == server)
+            .unwrap();
+        assert_eq!(
+            (
+                owner_server.server_emission,
+                owner_server.validator_emission,
+                owner_server.delegate_take,
+                owner_server.nominator_share
+            ),
+            (50, 500, 0, 0)
+        );
+
+        // The runtime API view filters on the block range.
+        assert_eq!(
+            SubtensorModule::get_emission_records_for_coldkey(owner.encode(), 5, 5).len(),
+            2
+        );
+        assert!(
+            SubtensorModule::get_emission_records_for_coldkey(owner.encode(), 6, 10).is_empty()
+        );
+    });
+}
+
+#[test]
+fn test_emission_ledger_is_bounded() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let max_entries: u64 =
+            <Test as pallet_subtensor::Config>::MaxEmissionLedgerEntries::get() as u64;
+        let written: u64 = max_entries + 3;
+        assert_ok!(SubtensorModule::set_emission_ledger_enabled(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            true
+        ));
+        for block in 1..=written {
+            SubtensorModule::append_emission_record(
+                &coldkey,
+                pallet_subtensor::EmissionRecord {
+                    block,
+                    netuid: 1,
+                    hotkey: U256::from(10),
+                    server_emission: block,
+                    validator_emission: 0,
+                    delegate_take: 0,
+                    nominator_share: 0,
+                },
+            );
+        }
+
+        // Only the most recent records are kept, oldest first.
+        let blocks: Vec<u64> = SubtensorModule::get_emission_records(&coldkey, 0, u64::MAX)
+            .iter()
+            .map(|record| record.block)
+            .collect();
+        assert_eq!(blocks, (4..=written).collect::<Vec<u64>>());
+        assert_eq!(
+            SubtensorModule::get_emission_records(&coldkey, 5, 6).len(),
+            2
+        );
+    });
+}
+
+#[test]
+fn test_emission_ledger_is_opt_in() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let hotkey = U256::from(10);
+        add_network(netuid, 10, 0);
+        SubtensorModule::create_account_if_non_existent(&coldkey, &hotkey);
+
+        // Nothing is recorded for a coldkey that did not enable its ledger.
+        pallet_subtensor::LoadedEmission::<Test>::insert(netuid, vec![(hotkey, 50, 500)]);
+        SubtensorModule::drain_emission(5);
+        assert!(SubtensorModule::get_emission_records(&coldkey, 0, u64::MAX).is_empty());
+
+        assert_ok!(SubtensorModule::set_emission_ledger_enabled(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            true
+        ));
+        pallet_subtensor::LoadedEmission::<Test>::insert(netuid, vec![(hotkey, 50, 500)]);
+        SubtensorModule::drain_emission(6);
+        assert_eq!(
+            SubtensorModule::get_emission_records(&coldkey, 0, u64::MAX).len(),
+            1
+        );
+
+        // Disabling the ledger removes the records already written.
+        assert_ok!(SubtensorModule::set_emission_ledger_enabled(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            false
+        ));
+        assert!(SubtensorModule::get_emission_records(&coldkey, 0, u64::MAX).is_empty());
+        assert_eq!(
+            pallet_subtensor::EmissionLedgerLength::<Test>::get(coldkey),
+            0
+        );
+        pallet_subtensor::LoadedEmission::<Test>::insert(netuid, vec![(hotkey, 50, 500)]);
+        SubtensorModule::drain_emission(7);
+        assert!(SubtensorModule::get_emission_records(&coldkey, 0, u64::MAX).is_empty());
+    });
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_generate_emission_applies_emission_split() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let dev_fund = U256::from(2);
+        add_network(netuid, 10, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_emission_values(&[netuid], vec![1_000_000]).unwrap();
+        SubtensorModule::set_max_emission_split_share(u16::MAX);
+
+        // A fifth to the owner, a fifth burned and a fifth to the dev fund.
+        assert_ok!(SubtensorModule::set_emission_split(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            13_107,
+            13_107,
+            vec![(dev_fund, 13_107)].try_into().unwrap(),
+        ));
+
+        let issuance_before = SubtensorModule::get_total_issuance();
+        SubtensorModule::generate_emission(0);
+
+        assert_eq!(SubtensorModule::get_coldkey_balance(&owner), 200_000);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&dev_fund), 200_000);
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 400_000);
+        // Only the owner cut and the recipient share are minted.
+        assert_eq!(
+            SubtensorModule::get_total_issuance(),
+            issuance_before + 400_000
+        );
+    });
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_generate_emission_accumulates_emission_split_cuts_below_existential_deposit() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        let dev_fund = U256::from(2);
+        add_network(netuid, 10, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_emission_values(&[netuid], vec![1_000]).unwrap();
+        SubtensorModule::set_max_emission_split_share(u16::MAX);
+        ExistentialDeposit::set(&500);
+
+        // A fifth to the owner and a fifth to the dev fund, 200 each per block.
+        assert_ok!(SubtensorModule::set_emission_split(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            netuid,
+            13_107,
+            0,
+            vec![(dev_fund, 13_107)].try_into().unwrap(),
+        ));
+
+        // Below the existential deposit nothing is paid nor minted, the cuts are owed.
+        let issuance_before = SubtensorModule::get_total_issuance();
+        SubtensorModule::generate_emission(0);
+        SubtensorModule::generate_emission(0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&dev_fund), 0);
+        assert_eq!(
+            pallet_subtensor::PendingEmissionSplitPayouts::<Test>::get(dev_fund),
+            400
+        );
+        assert_eq!(SubtensorModule::get_total_issuance(), issuance_before);
+
+        // Once the owed cuts reach the existential deposit they are paid and minted together.
+        SubtensorModule::generate_emission(0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&dev_fund), 600);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&owner), 600);
+        assert!(!pallet_subtensor::PendingEmissionSplitPayouts::<Test>::contains_key(dev_fund));
+        assert_eq!(
+            SubtensorModule::get_total_issuance(),
+            issuance_before + 1_200
+        );
+    });
+}
@@ -828,6 +828,201 @@ fn test_512_graph_random_weights() {
     }
 }
 
+// Property test: on randomly generated subnets (size, validator set, stake, weight density, liquid alpha, replaced neurons with outdated weights and neurons past the activity cutoff), dense and sparse epochs must agree bit-for-bit.
+#[test]
+fn test_dense_sparse_epoch_equivalence() {
+    let netuid: u16 = 1;
+    let mut rng = StdRng::seed_from_u64(0); // constant seed so failing cases are reproducible
+    for case in 0..32 {
+        let n: u16 = rng.gen_range(2..=24);
+        let mut uids: Vec<u16> = (0..n).collect();
+        uids.shuffle(&mut rng);
+        let validators_n: usize = rng.gen_range(1..=n as usize);
+        let mut validators: Vec<u16> = uids[..validators_n].to_vec();
+        validators.sort();
+        let servers: Vec<u16> = (0..n).filter(|uid| !validators.contains(uid)).collect();
+        let stake: Vec<u64> = (0..n)
+            .map(|uid| {
+                if validators.contains(&uid) {
+                    rng.gen_range(1..=1_000_000)
+                } else {
+                    0
+                }
+            })
+            .collect();
+        let density: f64 = rng.gen_range(0.0..=1.0);
+        let weights: Vec<Vec<(u16, u16)>> = (0..n)
+            .map(|_| {
+                let mut row: Vec<(u16, u16)> = (0..n)
+                    .filter(|_| rng.gen_bool(density))
+                    .map(|uid| (uid, rng.gen_range(1..=u16::MAX)))
+                    .collect();
+                if row.is_empty() {
+                    row.push((rng.gen_range(0..n), rng.gen_range(1..=u16::MAX)));
+                }
+                row
+            })
+            .collect();
+        let epochs: u16 = rng.gen_range(1..=3);
+        let liquid_alpha: bool = rng.gen_bool(0.5);
+        // Neurons replaced after the weights were set, the weights on them are outdated.
+        let replaced: Vec<u16> = (0..n).filter(|_| rng.gen_bool(0.2)).collect();
+        // Neurons that stay active once the other ones fall past the activity cutoff.
+        let activity_cutoff: u16 = rng.gen_range(1..=5);
+        let refreshed: Vec<u16> = (0..n).filter(|_| rng.gen_bool(0.5)).collect();
+        log::info!(
+            "case {case}: n={n}, validators={validators:?}, density={density:.2}, epochs={epochs}, liquid_alpha={liquid_alpha}, replaced={replaced:?}, activity_cutoff={activity_cutoff}, refreshed={refreshed:?}"
+        );
+
+        let run = |sparse: bool| -> (Vec<(u16, u16, u16, u16, u16, u64)>, Vec<Vec<I32F32>>) {
+            let mut stats = vec![];
+            let mut bonds = vec![];
+            new_test_ext(1).execute_with(|| {
+                SubtensorModule::set_liquid_alpha_enabled(netuid, liquid_alpha);
+                init_run_epochs(
+                    netuid,
+                    n,
+                    &validators,
+                    &servers,
+                    epochs,
+                    1,
+                    false,
+                    &stake,
+                    true,
+                    &weights,
+                    true,
+                    false,
+                    0,
+                    sparse,
+                );
+
+                // Replace neurons, then let the neurons that were not refreshed become inactive.
+                run_to_block(2);
+                for uid in replaced.iter() {
+                    SubtensorModule::replace_neuron(
+                        netuid,
+                        *uid,
+                        &U256::from(1_000 + *uid as u64),
+                        2,
+                    );
+                }
+                let current_block: u64 = 3 + activity_cutoff as u64;
+                SubtensorModule::set_activity_cutoff(netuid, activity_cutoff);
+                run_to_block(current_block);
+                for uid in refreshed.iter() {
+                    SubtensorModule::set_last_update_for_uid(netuid, *uid, current_block);
+                }
+                if sparse {
+                    SubtensorModule::epoch(netuid, 1_000_000_000);
+                } else {
+                    SubtensorModule::epoch_dense(netuid, 1_000_000_000);
+                }
+
+                stats = (0..n)
+                    .map(|uid| {
+                        (
+                            SubtensorModule::get_rank_for_uid(netuid, uid),
+                            SubtensorModule::get_trust_for_uid(netuid, uid),
+                            SubtensorModule::get_consensus_for_uid(netuid, uid),
+                            SubtensorModule::get_incentive_for_uid(netuid, uid),
+                            SubtensorModule::get_dividends_for_uid(netuid, uid),
+                            SubtensorModule::get_emission_for_uid(netuid, uid),
+                        )
+                    })
+                    .collect();
+                bonds = SubtensorModule::get_bonds(netuid);
+            });
+            (stats, bonds)
+        };
+
+        let (dense_stats, dense_bonds) = run(false);
+        let (sparse_stats, sparse_bonds) = run(true);
+        assert_eq!(dense_stats, sparse_stats, "case {case}: stats diverge");
+        assert_eq!(dense_bonds, sparse_bonds, "case {case}: bonds diverge");
+    }
+}
+
+// Test that the weights density reflects the number of weight entries in the weight matrix.
+#[test]
+fn test_get_weights_density() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let n: u16 = 4;
+        init_run_epochs(
+            netuid,
+            n,
+            &[0],
+            &[1, 2, 3],
+            1,
+            1,
+            false,
+            &[],
+            false,
+            &[],
+            false,
+            false,
+            0,
+            true,
+        );
+        // One validator weighting three servers: 3 of 16 possible entries.
+        assert_eq!(
+            SubtensorModule::get_weights_density(netuid),
+            (3 * u16::MAX as u64 / 16) as u16
+        );
+
+        // Below the default threshold the sparse epoch is selected.
+        assert!(
+            SubtensorModule::get_weights_density(netuid)
+                < SubtensorModule::get_dense_epoch_threshold()
+        );
+    });
+}
+
+// Test that the epoch dry run reports the epoch without writing to storage.
+#[test]
+fn test_epoch_dry_run_does_not_write() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        init_run_epochs(
+            netuid,
+            4,
+            &[0],
+            &[1, 2, 3],
+            2,
+            1,
+            false,
+            &[],
+            false,
+            &[],
+            false,
+            false,
+            0,
+            true,
+        );
+
+        // The dry run follows run_epoch onto both the sparse and the dense implementation.
+        let mut dry_runs = vec![];
+        for threshold in [u16::MAX, 0] {
+            SubtensorModule::set_dense_epoch_threshold(threshold);
+            assert_eq!(SubtensorModule::runs_dense_epoch(netuid), threshold == 0);
+
+            let root_before = sp_io::storage::root(sp_runtime::StateVersion::V1);
+            let dry_run = SubtensorModule::get_epoch_dry_run(netuid, 1_000_000_000);
+            assert!(dry_run.is_some());
+            assert_eq!(
+                sp_io::storage::root(sp_runtime::StateVersion::V1),
+                root_before
+            );
+            dry_runs.push(dry_run);
+        }
+
+        // Both implementations report the same terms, sparse matrices included.
+        assert_eq!(dry_runs[0], dry_runs[1]);
+
+        assert!(SubtensorModule::get_epoch_dry_run(netuid + 1, 1_000_000_000).is_none());
+    });
+}
+
 // Test an epoch on a graph with 4096 nodes, of which the first 256 are validators setting non-self weights, and the rest servers setting only self-weights.
 // #[test]
 #[allow(dead_code)]
@@ -1654,6 +1849,67 @@ fn test_outdated_weights() {
     });
 }
 
+// Test that the weights set on a uid before it was freed by a deregistration are outdated.
+#[test]
+fn test_freed_uid_outdates_weights() {
+    new_test_ext(1).execute_with(|| {
+        let n: u16 = 4;
+        let netuid: u16 = 1;
+        let tempo: u16 = u16::MAX - 1; // high tempo to skip automatic epochs in on_initialize, use manual epochs instead
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, n);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, n);
+        SubtensorModule::set_target_registrations_per_interval(netuid, n);
+        SubtensorModule::set_min_allowed_weights(netuid, 0);
+        SubtensorModule::set_max_weight_limit(netuid, u16::MAX);
+
+        // === Register [validator1, validator2, server1, server2]
+        for key in 0..n as u64 {
+            register_ok_neuron(netuid, U256::from(key), U256::from(key), key * 1_000_000);
+            SubtensorModule::increase_stake_on_coldkey_hotkey_account(
+                &U256::from(key),
+                &U256::from(key),
+                1,
+            );
+        }
+        SubtensorModule::set_max_allowed_validators(netuid, n);
+        SubtensorModule::epoch(netuid, 1_000_000_000); // run first epoch to set allowed validators
+        next_block(); // run to next block to ensure weights are set on nodes after their registration block
+
+        // === Set weights [val1->srv1: 2/3, val1->srv2: 1/3, val2->srv1: 2/3, val2->srv2: 1/3]
+        for uid in 0..(n / 2) as u64 {
+            assert_ok!(SubtensorModule::set_weights(
+                RuntimeOrigin::signed(U256::from(uid)),
+                netuid,
+                ((n / 2)..n).collect(),
+                vec![2 * (u16::MAX / 3), u16::MAX / 3],
+                0
+            ));
+        }
+        SubtensorModule::epoch(netuid, 1_000_000_000);
+        assert!(SubtensorModule::get_incentive_for_uid(netuid, 3) > 0);
+
+        // === Server2 deregisters, its uid stays empty
+        next_block();
+        assert_ok!(SubtensorModule::deregister(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(3)),
+            netuid
+        ));
+        assert_eq!(
+            SubtensorModule::get_neuron_block_at_registration(netuid, 3),
+            System::block_number()
+        );
+        assert_eq!(SubtensorModule::get_last_update(netuid)[3], 0);
+
+        // The weights of the validators on the freed uid are outdated.
+        SubtensorModule::epoch(netuid, 1_000_000_000);
+        assert_eq!(SubtensorModule::get_incentive_for_uid(netuid, 3), 0);
+        assert_eq!(SubtensorModule::get_emission_for_uid(netuid, 3), 0);
+        assert_eq!(SubtensorModule::get_incentive_for_uid(netuid, 2), u16::MAX);
+    });
+}
+
 // Test the zero emission handling and fallback under zero effective weight conditions, to ensure non-zero effective emission.
 #[test]
 fn test_zero_weights() {
@@ -2070,3 +2326,44 @@ fn test_validator_permits() {
 //     }
 //     println!("]");
 // }
+
+// Test that the latest epochs of a subnet are snapshotted in a ring buffer of the retained size.
+#[test]
+fn test_epoch_snapshots() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 1, 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 0);
+
+        // Snapshots are disabled by default.
+        SubtensorModule::run_epoch(netuid, 1_000);
+        assert_eq!(SubtensorModule::get_epoch_snapshot(netuid, 0), None);
+
+        SubtensorModule::set_epoch_snapshot_retention(netuid, 2);
+        for block in 2..=4 {
+            System::set_block_number(block);
+            SubtensorModule::run_epoch(netuid, 1_000);
+        }
+        let latest = SubtensorModule::get_epoch_snapshot(netuid, 0).unwrap();
+        assert_eq!(latest.block, 4);
+        assert_eq!(latest.hotkeys, vec![Some(U256::from(1))]);
+        assert_eq!(
+            latest.incentive,
+            vec![SubtensorModule::get_incentive_for_uid(netuid, 0)]
+        );
+        assert_eq!(
+            SubtensorModule::get_epoch_snapshot(netuid, 1)
+                .unwrap()
+                .block,
+            3
+        );
+        // The snapshot of block 2 has been overwritten.
+        assert_eq!(SubtensorModule::get_epoch_snapshot(netuid, 2), None);
+        assert!(SubtensorModule::get_metagraph_at_epoch(netuid, 1).is_some());
+        assert!(SubtensorModule::get_metagraph_at_epoch(netuid, 2).is_none());
+
+        // Changing the retention drops the snapshots taken so far.
+        SubtensorModule::set_epoch_snapshot_retention(netuid, 3);
+        assert_eq!(SubtensorModule::get_epoch_snapshot(netuid, 0), None);
+    });
+}
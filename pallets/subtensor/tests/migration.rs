@@ -1,8 +1,11 @@
 mod mock;
-use frame_support::assert_ok;
+use frame_support::{
+    assert_ok,
+    traits::{GetStorageVersion, StorageVersion},
+};
 use frame_system::Config;
 use mock::*;
-use sp_core::U256;
+use sp_core::{H256, U256};
 
 #[test]
 fn test_migration_fix_total_stake_maps() {
@@ -274,3 +277,115 @@ fn test_migration_delete_subnet_21() {
         assert!(!SubtensorModule::if_subnet_exist(21));
     })
 }
+
+#[test]
+fn test_migration_weight_commit_queue() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let commit: (H256, u64) = (H256::from_low_u64_be(7), 42);
+
+        // Write a commit in the single (hash, block) format.
+        frame_support::storage::unhashed::put(
+            &pallet_subtensor::WeightCommits::<Test>::hashed_key_for(netuid, hotkey),
+            &commit,
+        );
+        StorageVersion::new(6).put::<SubtensorModule>();
+
+        pallet_subtensor::migration::migrate_to_v7_weight_commit_queue::<Test>();
+
+        assert_eq!(
+            pallet_subtensor::WeightCommits::<Test>::get(netuid, hotkey),
+            Some(vec![commit])
+        );
+        assert_eq!(
+            SubtensorModule::on_chain_storage_version(),
+            StorageVersion::new(7)
+        );
+    })
+}
+
+#[test]
+fn test_migration_axon_info_v2() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let unserved_hotkey = U256::from(2);
+        let axon_info = pallet_subtensor::AxonInfo {
+            block: 5,
+            version: 2,
+            ip: 1676056785,
+            port: 128,
+            ip_type: 4,
+            protocol: 0,
+            placeholder1: 0,
+            placeholder2: 0,
+        };
+        pallet_subtensor::Axons::<Test>::insert(netuid, hotkey, axon_info.clone());
+        pallet_subtensor::Axons::<Test>::insert(
+            netuid,
+            unserved_hotkey,
+            pallet_subtensor::AxonInfo::default(),
+        );
+        StorageVersion::new(7).put::<SubtensorModule>();
+
+        pallet_subtensor::migration::migrate_to_v8_axon_info_v2::<Test>();
+
+        let axon_info_v2 = SubtensorModule::get_axon_info_v2(netuid, &hotkey);
+        assert_eq!(axon_info_v2.block, 5);
+        assert_eq!(axon_info_v2.version, 2);
+        assert_eq!(
+            axon_info_v2.endpoints.into_inner(),
+            vec![pallet_subtensor::AxonEndpoint {
+                address: pallet_subtensor::AxonAddress::Ipv4(1676056785),
+                port: 128,
+                protocol: 0,
+                cert_digest: None,
+            }]
+        );
+        assert!(SubtensorModule::get_axon_info_v2(netuid, &unserved_hotkey)
+            .endpoints
+            .is_empty());
+        // The axons of the first version are kept.
+        assert_eq!(SubtensorModule::get_axon_info(netuid, &hotkey), axon_info);
+        assert_eq!(
+            SubtensorModule::on_chain_storage_version(),
+            StorageVersion::new(8)
+        );
+    })
+}
+
+#[test]
+fn test_migration_coldkey_hotkey_indexes() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let nominator = U256::from(2);
+        let hotkey = U256::from(3);
+        let other_hotkey = U256::from(4);
+
+        // Write the owners and the stake without going through the pallet.
+        pallet_subtensor::Owner::<Test>::insert(hotkey, coldkey);
+        pallet_subtensor::Owner::<Test>::insert(other_hotkey, coldkey);
+        pallet_subtensor::Stake::<Test>::insert(hotkey, coldkey, 100);
+        pallet_subtensor::Stake::<Test>::insert(hotkey, nominator, 10);
+        pallet_subtensor::Stake::<Test>::insert(other_hotkey, nominator, 0);
+        StorageVersion::new(8).put::<SubtensorModule>();
+
+        pallet_subtensor::migration::migrate_to_v9_coldkey_hotkey_indexes::<Test>();
+
+        let mut owned = pallet_subtensor::OwnedHotkeys::<Test>::get(coldkey);
+        owned.sort();
+        assert_eq!(owned, vec![hotkey, other_hotkey]);
+        assert_eq!(
+            pallet_subtensor::StakingHotkeys::<Test>::get(coldkey),
+            vec![hotkey]
+        );
+        let mut staking = pallet_subtensor::StakingHotkeys::<Test>::get(nominator);
+        staking.sort();
+        assert_eq!(staking, vec![hotkey, other_hotkey]);
+        assert_eq!(
+            SubtensorModule::on_chain_storage_version(),
+            StorageVersion::new(9)
+        );
+    })
+}
@@ -108,12 +108,15 @@ impl system::Config for Test {
     type Block = Block;
 }
 
+parameter_types! {
+    pub static ExistentialDeposit: Balance = 1;
+}
+
 parameter_types! {
     pub const InitialMinAllowedWeights: u16 = 0;
     pub const InitialEmissionValue: u16 = 0;
     pub const InitialMaxWeightsLimit: u16 = u16::MAX;
     pub BlockWeights: limits::BlockWeights = limits::BlockWeights::simple_max(weights::Weight::from_parts(1024, 0));
-    pub const ExistentialDeposit: Balance = 1;
     pub const TransactionByteFee: Balance = 100;
     pub const SDebug:u64 = 1;
     pub const InitialRho: u16 = 30;
@@ -158,6 +161,17 @@ parameter_types! {
     pub const InitialSubnetLimit: u16 = 10; // Max 10 subnets.
     pub const InitialNetworkRateLimit: u64 = 0;
     pub const InitialTargetStakesPerInterval: u16 = 2;
+    pub const MaxUnbondingChunks: u32 = 32;
+    pub const MaxRebalanceTargets: u32 = 64;
+    pub const MaxPendingWeightCommits: u32 = 10;
+    pub const MaxTimelockedCiphertextLength: u32 = 1024;
+    pub const MaxTimelockedCommitsPerBlock: u32 = 16;
+    pub const ColdkeySwapAnnouncementDelay: u64 = 10;
+    pub const MaxEmissionLedgerEntries: u32 = 8;
+    pub const MaxEmissionSplitRecipients: u32 = 4;
+    pub const MaxEpochSnapshotRetention: u16 = 4;
+    pub const ExpectedBlockTime: u64 = 12_000;
+    pub const MaxServeAxonNetuids: u32 = 16;
 }
 
 // Configure collective pallet for council
@@ -317,6 +331,8 @@ impl pallet_subtensor::Config for Test {
     type CouncilOrigin = frame_system::EnsureSigned<AccountId>;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
+    type OnHotkeySwapped = ();
+    type TimelockKeySource = pallet_subtensor::timelock::DeterministicTimelock<Test>;
 
     type InitialMinAllowedWeights = InitialMinAllowedWeights;
     type InitialEmissionValue = InitialEmissionValue;
@@ -358,6 +374,18 @@ impl pallet_subtensor::Config for Test {
     type InitialSubnetLimit = InitialSubnetLimit;
     type InitialNetworkRateLimit = InitialNetworkRateLimit;
     type InitialTargetStakesPerInterval = InitialTargetStakesPerInterval;
+    type MaxUnbondingChunks = MaxUnbondingChunks;
+    type MaxRebalanceTargets = MaxRebalanceTargets;
+    type WeightInfo = ();
+    type MaxPendingWeightCommits = MaxPendingWeightCommits;
+    type MaxTimelockedCiphertextLength = MaxTimelockedCiphertextLength;
+    type MaxTimelockedCommitsPerBlock = MaxTimelockedCommitsPerBlock;
+    type ColdkeySwapAnnouncementDelay = ColdkeySwapAnnouncementDelay;
+    type MaxEmissionLedgerEntries = MaxEmissionLedgerEntries;
+    type MaxEmissionSplitRecipients = MaxEmissionSplitRecipients;
+    type MaxEpochSnapshotRetention = MaxEpochSnapshotRetention;
+    type ExpectedBlockTime = ExpectedBlockTime;
+    type MaxServeAxonNetuids = MaxServeAxonNetuids;
 }
 
 impl pallet_utility::Config for Test {
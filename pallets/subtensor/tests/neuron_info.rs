@@ -1,5 +1,11 @@
 mod mock;
+use codec::{Compact, Decode, Encode};
+use frame_support::assert_ok;
+use frame_system::Config;
 use mock::*;
+use pallet_subtensor::neuron_info::{NEURON_FILTER_VALIDATORS, NEURON_SKIP_AXON};
+use pallet_subtensor::subnet_info::SubnetHyperparams;
+use pallet_subtensor::{AxonInfo, AxonInfoV2};
 
 use sp_core::U256;
 
@@ -71,3 +77,233 @@ fn test_get_neurons_empty() {
         assert_eq!(neurons.len(), neuron_count as usize);
     });
 }
+
+#[test]
+fn test_get_neurons_after_deregistering_a_middle_uid() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 2, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 3);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 3);
+        for index in 0..3 {
+            register_ok_neuron(
+                netuid,
+                U256::from(index),
+                U256::from(index),
+                39420842 + index,
+            );
+        }
+
+        assert_ok!(SubtensorModule::deregister(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+            netuid
+        ));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 3);
+        assert_eq!(SubtensorModule::get_neuron(netuid, 1), None);
+
+        // The neurons after the freed uid are still returned.
+        let neurons = SubtensorModule::get_neurons(netuid);
+        assert_eq!(
+            neurons,
+            vec![
+                SubtensorModule::get_neuron(netuid, 0).unwrap(),
+                SubtensorModule::get_neuron(netuid, 2).unwrap()
+            ]
+        );
+        assert_eq!(
+            SubtensorModule::get_neurons_paged(netuid, 0, 10, 0, 0),
+            neurons
+        );
+        assert_eq!(
+            SubtensorModule::get_neurons_paged(netuid, 1, 1, 0, 0),
+            neurons[1..2]
+        );
+        assert_eq!(
+            SubtensorModule::get_neurons_lite(netuid),
+            vec![
+                SubtensorModule::get_neuron_lite(netuid, 0).unwrap(),
+                SubtensorModule::get_neuron_lite(netuid, 2).unwrap()
+            ]
+        );
+        assert_eq!(
+            SubtensorModule::get_neurons_lite_paged(netuid, 0, 10, 0, 0),
+            SubtensorModule::get_neurons_lite(netuid)
+        );
+    });
+}
+
+#[test]
+fn test_get_neurons_paged() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        add_network(netuid, 2, 0);
+        SubtensorModule::set_max_registrations_per_block(netuid, 4);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 4);
+
+        for index in 0..4 {
+            register_ok_neuron(
+                netuid,
+                U256::from(index),
+                U256::from(index),
+                39420842 + index,
+            );
+        }
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 3, true);
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(0)),
+            netuid,
+            2,
+            1676056785,
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+
+        let neurons = SubtensorModule::get_neurons(netuid);
+        assert_eq!(
+            SubtensorModule::get_neurons_paged(netuid, 0, 2, 0, 0),
+            neurons[0..2]
+        );
+        assert_eq!(
+            SubtensorModule::get_neurons_paged(netuid, 2, 10, 0, 0),
+            neurons[2..4]
+        );
+        assert!(SubtensorModule::get_neurons_paged(netuid, 4, 10, 0, 0).is_empty());
+        assert!(SubtensorModule::get_neurons_paged(2, 0, 10, 0, 0).is_empty());
+
+        // Only validators are returned.
+        assert_eq!(
+            SubtensorModule::get_neurons_paged(netuid, 0, 10, 0, NEURON_FILTER_VALIDATORS),
+            vec![neurons[1].clone(), neurons[3].clone()]
+        );
+        assert_eq!(
+            SubtensorModule::get_neurons_lite_paged(netuid, 2, 10, 0, NEURON_FILTER_VALIDATORS),
+            vec![SubtensorModule::get_neuron_lite(netuid, 3).unwrap()]
+        );
+
+        // The axon is skipped.
+        assert_ne!(
+            SubtensorModule::get_neurons_paged(netuid, 0, 1, NEURON_SKIP_AXON, 0),
+            neurons[0..1]
+        );
+        assert_ne!(
+            SubtensorModule::get_neurons_lite_paged(netuid, 0, 1, NEURON_SKIP_AXON, 0),
+            vec![SubtensorModule::get_neuron_lite(netuid, 0).unwrap()]
+        );
+    });
+}
+
+// Mirrors the layout of Metagraph, whose fields are private.
+#[derive(Decode)]
+#[allow(dead_code)]
+struct MetagraphView {
+    netuid: Compact<u16>,
+    block: Compact<u64>,
+    n: Compact<u16>,
+    hyperparams: SubnetHyperparams,
+    hotkeys: Vec<Option<U256>>,
+    coldkeys: Vec<Option<U256>>,
+    active: Vec<bool>,
+    last_update: Vec<Compact<u64>>,
+    validator_permit: Vec<bool>,
+    stake: Vec<Compact<u64>>,
+    rank: Vec<Compact<u16>>,
+    trust: Vec<Compact<u16>>,
+    consensus: Vec<Compact<u16>>,
+    validator_trust: Vec<Compact<u16>>,
+    incentive: Vec<Compact<u16>>,
+    dividends: Vec<Compact<u16>>,
+    emission: Vec<Compact<u64>>,
+    axons: Vec<AxonInfo>,
+    axons_v2: Vec<AxonInfoV2>,
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_get_metagraph() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+
+        assert_eq!(SubtensorModule::get_metagraph(netuid), None);
+
+        add_network(netuid, 2, 0);
+        assert_ne!(SubtensorModule::get_metagraph(netuid), None);
+
+        SubtensorModule::set_max_registrations_per_block(netuid, 3);
+        SubtensorModule::set_target_registrations_per_interval(netuid, 3);
+        for index in 0..3 {
+            register_ok_neuron(
+                netuid,
+                U256::from(index),
+                U256::from(10 + index),
+                39420842 + index,
+            );
+        }
+        SubtensorModule::add_balance_to_coldkey_account(&U256::from(10), 1_000);
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(10)),
+            U256::from(0),
+            1_000
+        ));
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(2)),
+            netuid,
+            2,
+            1676056785,
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+        assert_ok!(SubtensorModule::deregister(
+            <<Test as Config>::RuntimeOrigin>::signed(U256::from(1)),
+            netuid
+        ));
+
+        let metagraph = SubtensorModule::get_metagraph(netuid).unwrap();
+        let metagraph = MetagraphView::decode(&mut &metagraph.encode()[..]).unwrap();
+        assert_eq!(metagraph.n.0, 3);
+
+        // The freed uid has no keys, no stake and no axon.
+        assert_eq!(
+            metagraph.hotkeys,
+            vec![Some(U256::from(0)), None, Some(U256::from(2))]
+        );
+        assert_eq!(
+            metagraph.coldkeys,
+            vec![Some(U256::from(10)), None, Some(U256::from(12))]
+        );
+        let stake: Vec<u64> = metagraph.stake.iter().map(|stake| stake.0).collect();
+        assert_eq!(
+            stake,
+            vec![
+                SubtensorModule::get_total_stake_for_hotkey(&U256::from(0)),
+                0,
+                SubtensorModule::get_total_stake_for_hotkey(&U256::from(2))
+            ]
+        );
+        assert!(stake[0] >= 1_000);
+        assert_eq!(
+            metagraph.axons,
+            vec![
+                SubtensorModule::get_axon_info(netuid, &U256::from(0)),
+                AxonInfo::default(),
+                SubtensorModule::get_axon_info(netuid, &U256::from(2))
+            ]
+        );
+        assert_eq!(metagraph.axons[2].ip, 1676056785);
+        assert_eq!(
+            metagraph.axons_v2,
+            vec![
+                SubtensorModule::get_axon_info_v2(netuid, &U256::from(0)),
+                AxonInfoV2::default(),
+                SubtensorModule::get_axon_info_v2(netuid, &U256::from(2))
+            ]
+        );
+        assert_eq!(metagraph.axons_v2[2].endpoints.len(), 1);
+    });
+}
@@ -1,3 +1,4 @@
+use codec::Encode;
 use frame_support::traits::Currency;
 
 use crate::mock::*;
@@ -5,8 +6,8 @@ use frame_support::dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays
 use frame_support::sp_runtime::{transaction_validity::InvalidTransaction, DispatchError};
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::Config;
-use pallet_subtensor::{AxonInfoOf, Error, SubtensorSignedExtension};
-use sp_core::U256;
+use pallet_subtensor::{AxonInfoOf, Error, Event, MemberManagement, SubtensorSignedExtension};
+use sp_core::{Get, U256};
 use sp_runtime::traits::{DispatchInfoOf, SignedExtension};
 
 mod mock;
@@ -1906,6 +1907,184 @@ fn test_hotkey_swap_ok() {
     });
 }
 
+#[test]
+fn test_hotkey_swap_leaves_nothing_behind() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        // Bytes that do not show up by chance in any other key or value.
+        let old_hotkey = U256::from_little_endian(&[0xa5; 32]);
+        let new_hotkey = U256::from(1337);
+        let coldkey = U256::from(667);
+        let nominator = U256::from(668);
+
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000_000_000);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            old_hotkey
+        ));
+
+        // Fill every storage item that holds the hotkey.
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &old_hotkey, 1_000);
+        pallet_subtensor::Delegates::<Test>::insert(old_hotkey, 11_796);
+        pallet_subtensor::TotalHotkeyColdkeyStakesThisInterval::<Test>::insert(
+            nominator,
+            old_hotkey,
+            (1, 1),
+        );
+        pallet_subtensor::Unbonding::<Test>::insert(nominator, old_hotkey, vec![(1_000, 100)]);
+        pallet_subtensor::BondedUnbondingPeriod::<Test>::insert(nominator, old_hotkey, 100);
+        pallet_subtensor::RewardDestinations::<Test>::insert(
+            nominator,
+            old_hotkey,
+            pallet_subtensor::RewardDestination::Free,
+        );
+        pallet_subtensor::LastTxBlock::<Test>::insert(old_hotkey, 1);
+        pallet_subtensor::LastTxBlockDelegateTake::<Test>::insert(old_hotkey, 1);
+        pallet_subtensor::Axons::<Test>::insert(netuid, old_hotkey, AxonInfoOf::default());
+        pallet_subtensor::Prometheus::<Test>::insert(
+            netuid,
+            old_hotkey,
+            pallet_subtensor::PrometheusInfoOf::default(),
+        );
+        pallet_subtensor::WeightCommits::<Test>::insert(
+            netuid,
+            old_hotkey,
+            vec![(sp_core::H256::from_low_u64_be(1), 1)],
+        );
+        pallet_subtensor::WeightCommitExpiries::<Test>::insert(11, vec![(netuid, old_hotkey, 1)]);
+        pallet_subtensor::TimelockedWeightCommits::<Test>::insert(
+            5,
+            vec![(netuid, old_hotkey, 5, 1, vec![0u8])],
+        );
+        pallet_subtensor::PendingTimelockedWeightCommits::<Test>::insert(
+            netuid,
+            old_hotkey,
+            vec![5],
+        );
+        pallet_subtensor::LoadedEmission::<Test>::insert(netuid, vec![(old_hotkey, 1, 1)]);
+        assert_ok!(ManageSenateMembers::add_member(&old_hotkey));
+
+        assert_ok!(SubtensorModule::swap_hotkey(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            old_hotkey,
+            new_hotkey
+        ));
+
+        // Walk every key and value stored by the pallet, none of them may hold the old hotkey.
+        let prefix: [u8; 16] = sp_io::hashing::twox_128(b"SubtensorModule");
+        let encoded: Vec<u8> = old_hotkey.encode();
+        let holds_old_hotkey =
+            |bytes: &[u8]| bytes.windows(encoded.len()).any(|window| window == encoded);
+        let mut key: Vec<u8> = prefix.to_vec();
+        while let Some(next_key) = sp_io::storage::next_key(&key) {
+            if !next_key.starts_with(&prefix) {
+                break;
+            }
+            let value: Vec<u8> = frame_support::storage::unhashed::get_raw(&next_key).unwrap();
+            assert!(
+                !holds_old_hotkey(&next_key) && !holds_old_hotkey(&value),
+                "storage key {:?} still holds the old hotkey",
+                next_key
+            );
+            key = next_key;
+        }
+        assert!(!Senate::members().contains(&old_hotkey));
+
+        // And the state moved to the new hotkey.
+        assert_eq!(
+            SubtensorModule::get_owning_coldkey_for_hotkey(&new_hotkey),
+            coldkey
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &new_hotkey),
+            1_000
+        );
+        assert_eq!(
+            pallet_subtensor::TotalHotkeyColdkeyStakesThisInterval::<Test>::get(
+                nominator, new_hotkey
+            ),
+            (1, 1)
+        );
+        assert_eq!(
+            pallet_subtensor::Unbonding::<Test>::get(nominator, new_hotkey),
+            vec![(1_000, 100)]
+        );
+        assert_eq!(
+            pallet_subtensor::BondedUnbondingPeriod::<Test>::get(nominator, new_hotkey),
+            100
+        );
+        assert_eq!(
+            pallet_subtensor::RewardDestinations::<Test>::get(nominator, new_hotkey),
+            pallet_subtensor::RewardDestination::Free
+        );
+        assert!(pallet_subtensor::Axons::<Test>::contains_key(
+            netuid, new_hotkey
+        ));
+        assert!(pallet_subtensor::WeightCommits::<Test>::contains_key(
+            netuid, new_hotkey
+        ));
+        assert_eq!(
+            pallet_subtensor::WeightCommitExpiries::<Test>::get(11),
+            vec![(netuid, new_hotkey, 1)]
+        );
+        assert_eq!(
+            pallet_subtensor::TimelockedWeightCommits::<Test>::get(5),
+            vec![(netuid, new_hotkey, 5, 1, vec![0u8])]
+        );
+        assert_eq!(
+            pallet_subtensor::PendingTimelockedWeightCommits::<Test>::get(netuid, new_hotkey),
+            vec![5]
+        );
+        assert_eq!(
+            pallet_subtensor::LoadedEmission::<Test>::get(netuid),
+            Some(vec![(new_hotkey, 1, 1)])
+        );
+        assert!(Senate::members().contains(&new_hotkey));
+    });
+}
+
+#[test]
+fn test_hotkey_swap_merges_unbonding_chunks() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let old_hotkey = U256::from(1);
+        let new_hotkey = U256::from(1337);
+        let coldkey = U256::from(667);
+        let nominator = U256::from(668);
+        let max_chunks: u32 = <Test as pallet_subtensor::Config>::MaxUnbondingChunks::get();
+
+        add_network(netuid, 13, 0);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000_000_000);
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            netuid,
+            old_hotkey
+        ));
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &old_hotkey, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&nominator, &new_hotkey, 1_000);
+        let chunks: Vec<(u64, u64)> = (0..max_chunks as u64).map(|i| (1, 100 + i)).collect();
+        pallet_subtensor::Unbonding::<Test>::insert(nominator, old_hotkey, chunks.clone());
+        pallet_subtensor::Unbonding::<Test>::insert(nominator, new_hotkey, chunks);
+
+        assert_ok!(SubtensorModule::swap_hotkey(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            old_hotkey,
+            new_hotkey
+        ));
+
+        let merged: Vec<(u64, u64)> =
+            pallet_subtensor::Unbonding::<Test>::get(nominator, new_hotkey);
+        assert_eq!(merged.len() as u32, max_chunks);
+        assert_eq!(
+            merged.iter().map(|(amount, _)| amount).sum::<u64>(),
+            2 * max_chunks as u64
+        );
+    });
+}
+
 #[ignore]
 #[test]
 fn test_hotkey_swap_not_owner() {
@@ -2016,3 +2195,396 @@ fn test_hotkey_swap_registered_key() {
         );
     });
 }
+
+#[test]
+fn test_schedule_and_cancel_coldkey_swap() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+
+        assert_err!(
+            SubtensorModule::schedule_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                coldkey
+            ),
+            Error::<Test>::NewColdKeyIsSameWithOld
+        );
+        assert_err!(
+            SubtensorModule::cancel_coldkey_swap(<<Test as Config>::RuntimeOrigin>::signed(
+                coldkey
+            )),
+            Error::<Test>::NoColdkeySwapScheduled
+        );
+
+        assert_ok!(SubtensorModule::schedule_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            new_coldkey
+        ));
+        let execution_block: u64 = 1 + ColdkeySwapAnnouncementDelay::get();
+        assert_eq!(
+            pallet_subtensor::ColdkeySwapScheduled::<Test>::get(coldkey),
+            Some((new_coldkey, execution_block))
+        );
+        System::assert_last_event(
+            pallet_subtensor::Event::ColdkeySwapScheduled(coldkey, new_coldkey, execution_block)
+                .into(),
+        );
+        assert_err!(
+            SubtensorModule::schedule_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                U256::from(3)
+            ),
+            Error::<Test>::ColdkeySwapAlreadyScheduled
+        );
+
+        assert_ok!(SubtensorModule::cancel_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey)
+        ));
+        assert!(!pallet_subtensor::ColdkeySwapScheduled::<Test>::contains_key(coldkey));
+        System::assert_last_event(
+            pallet_subtensor::Event::ColdkeySwapCancelled(coldkey, new_coldkey).into(),
+        );
+    });
+}
+
+#[test]
+fn test_execute_coldkey_swap_after_delay() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        let other_hotkey = U256::from(4);
+        let executor = U256::from(5);
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        register_ok_neuron(netuid, other_hotkey, U256::from(6), 100000);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, coldkey);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &other_hotkey, 500);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+
+        assert_ok!(SubtensorModule::schedule_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            new_coldkey
+        ));
+
+        step_block(ColdkeySwapAnnouncementDelay::get() as u16 - 1);
+        assert_err!(
+            SubtensorModule::execute_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::signed(executor),
+                coldkey
+            ),
+            Error::<Test>::ColdkeySwapNotReady
+        );
+
+        step_block(1);
+        assert_ok!(SubtensorModule::execute_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::signed(executor),
+            coldkey
+        ));
+        System::assert_last_event(
+            pallet_subtensor::Event::ColdkeySwapped(coldkey, new_coldkey).into(),
+        );
+
+        assert_eq!(
+            SubtensorModule::get_owning_coldkey_for_hotkey(&hotkey),
+            new_coldkey
+        );
+        assert_eq!(
+            SubtensorModule::get_owning_coldkey_for_hotkey(&other_hotkey),
+            U256::from(6)
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&coldkey, &hotkey),
+            0
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&new_coldkey, &hotkey),
+            1_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&new_coldkey, &other_hotkey),
+            500
+        );
+        assert_eq!(SubtensorModule::get_total_stake_for_coldkey(&coldkey), 0);
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&new_coldkey),
+            1_500
+        );
+        assert_eq!(
+            pallet_subtensor::SubnetOwner::<Test>::get(netuid),
+            new_coldkey
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&new_coldkey), 10_000);
+        assert!(!pallet_subtensor::ColdkeySwapScheduled::<Test>::contains_key(coldkey));
+    });
+}
+
+#[test]
+fn test_execute_coldkey_swap_by_root_skips_delay() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+
+        assert_err!(
+            SubtensorModule::execute_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                coldkey
+            ),
+            Error::<Test>::NoColdkeySwapScheduled
+        );
+
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey, 10_000);
+        assert_ok!(SubtensorModule::schedule_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            new_coldkey
+        ));
+        assert_ok!(SubtensorModule::execute_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            coldkey
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&new_coldkey), 10_000);
+    });
+}
+
+#[test]
+fn test_coldkey_swap_arbitrated_by_council() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let rescue_coldkey = U256::from(3);
+
+        assert_err!(
+            SubtensorModule::force_cancel_coldkey_swap(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                coldkey
+            ),
+            Error::<Test>::NoColdkeySwapScheduled
+        );
+        assert_ok!(SubtensorModule::schedule_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            new_coldkey
+        ));
+        let execution_block: u64 = 1 + ColdkeySwapAnnouncementDelay::get();
+
+        // The destination is replaced, the execution block is kept.
+        assert_err!(
+            SubtensorModule::override_coldkey_swap_destination(
+                <<Test as Config>::RuntimeOrigin>::root(),
+                coldkey,
+                coldkey
+            ),
+            Error::<Test>::NewColdKeyIsSameWithOld
+        );
+        assert_ok!(SubtensorModule::override_coldkey_swap_destination(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            coldkey,
+            rescue_coldkey
+        ));
+        assert_eq!(
+            pallet_subtensor::ColdkeySwapScheduled::<Test>::get(coldkey),
+            Some((rescue_coldkey, execution_block))
+        );
+        System::assert_last_event(
+            pallet_subtensor::Event::ColdkeySwapDestinationOverridden(coldkey, rescue_coldkey)
+                .into(),
+        );
+
+        assert_ok!(SubtensorModule::force_cancel_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            coldkey
+        ));
+        assert!(!pallet_subtensor::ColdkeySwapScheduled::<Test>::contains_key(coldkey));
+        System::assert_last_event(
+            pallet_subtensor::Event::ColdkeySwapCancelled(coldkey, rescue_coldkey).into(),
+        );
+    });
+}
+
+#[test]
+fn test_schedule_coldkey_swap_pays_fee() {
+    new_test_ext(1).execute_with(|| {
+        let call = RuntimeCall::SubtensorModule(SubtensorCall::schedule_coldkey_swap {
+            new_coldkey: U256::from(2),
+        });
+        let dispatch_info = call.get_dispatch_info();
+        assert_eq!(dispatch_info.class, DispatchClass::Normal);
+        assert_eq!(dispatch_info.pays_fee, Pays::Yes);
+    });
+}
+
+#[test]
+fn test_execute_coldkey_swap_moves_rate_limits() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let coldkey = U256::from(1);
+        let new_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000);
+        pallet_subtensor::TotalHotkeyColdkeyStakesThisInterval::<Test>::insert(
+            coldkey,
+            hotkey,
+            (2, 7),
+        );
+        SubtensorModule::set_last_tx_block(&coldkey, 9);
+
+        assert_ok!(SubtensorModule::schedule_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+            new_coldkey
+        ));
+        assert_ok!(SubtensorModule::execute_coldkey_swap(
+            <<Test as Config>::RuntimeOrigin>::root(),
+            coldkey
+        ));
+
+        assert_eq!(
+            pallet_subtensor::TotalHotkeyColdkeyStakesThisInterval::<Test>::get(
+                new_coldkey,
+                hotkey
+            ),
+            (2, 7)
+        );
+        assert!(!pallet_subtensor::TotalHotkeyColdkeyStakesThisInterval::<
+            Test,
+        >::contains_key(coldkey, hotkey));
+        assert_eq!(SubtensorModule::get_last_tx_block(&new_coldkey), 9);
+        assert!(!pallet_subtensor::LastTxBlock::<Test>::contains_key(
+            coldkey
+        ));
+        assert_eq!(
+            pallet_subtensor::OwnedHotkeys::<Test>::get(new_coldkey),
+            vec![hotkey]
+        );
+        assert_eq!(
+            pallet_subtensor::StakingHotkeys::<Test>::get(new_coldkey),
+            vec![hotkey]
+        );
+        assert!(!pallet_subtensor::OwnedHotkeys::<Test>::contains_key(
+            coldkey
+        ));
+        assert!(!pallet_subtensor::StakingHotkeys::<Test>::contains_key(
+            coldkey
+        ));
+    });
+}
+
+#[test]
+fn test_deregister_frees_uid() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let burn_cost: u64 = 1000;
+        let coldkey_account_id = U256::from(667);
+        let hotkey_a = U256::from(1);
+        let hotkey_b = U256::from(2);
+        let hotkey_c = U256::from(3);
+        SubtensorModule::set_burn(netuid, burn_cost);
+        add_network(netuid, tempo, 0);
+        SubtensorModule::set_max_allowed_uids(netuid, 2);
+        SubtensorModule::set_deregistration_refund_ratio(netuid, u16::MAX / 2);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey_account_id, 10000);
+        for hotkey in [hotkey_a, hotkey_b] {
+            assert_ok!(SubtensorModule::burned_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                netuid,
+                hotkey
+            ));
+        }
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_a),
+            netuid,
+            2,
+            1676056785,
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+        let issuance: u64 = SubtensorModule::get_total_issuance();
+
+        assert_ok!(SubtensorModule::deregister(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_a),
+            netuid
+        ));
+
+        // Half of the burn is refunded to the coldkey and issued back.
+        let refund: u64 = burn_cost * (u16::MAX / 2) as u64 / u16::MAX as u64;
+        System::assert_last_event(Event::NeuronDeregistered(netuid, 0, hotkey_a, refund).into());
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey_account_id),
+            10000 - 2 * burn_cost + refund
+        );
+        assert_eq!(SubtensorModule::get_total_issuance(), issuance + refund);
+
+        // The uid is freed and the axon cleared, the slot stays in the subnetwork.
+        assert!(!SubtensorModule::is_hotkey_registered_on_network(
+            netuid, &hotkey_a
+        ));
+        assert!(!SubtensorModule::is_uid_exist_on_network(netuid, 0));
+        assert!(!SubtensorModule::has_axon_info(netuid, &hotkey_a));
+        assert!(!SubtensorModule::get_active_for_uid(netuid, 0));
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+        assert_eq!(
+            SubtensorModule::deregister(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_a),
+                netuid
+            ),
+            Err(Error::<Test>::HotKeyNotRegisteredInSubNet.into())
+        );
+
+        // The subnetwork is full, the freed uid is filled instead of pruning a neuron.
+        assert_ok!(SubtensorModule::burned_register(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            netuid,
+            hotkey_c
+        ));
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey_c),
+            Ok(0)
+        );
+        assert_eq!(
+            SubtensorModule::get_uid_for_net_and_hotkey(netuid, &hotkey_b),
+            Ok(1)
+        );
+        assert_eq!(SubtensorModule::get_subnetwork_n(netuid), 2);
+    });
+}
+
+#[test]
+fn test_deregister_without_burn_is_not_refunded() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey_account_id = U256::from(1);
+        let coldkey_account_id = U256::from(667);
+        add_network(netuid, 13, 0);
+        SubtensorModule::set_deregistration_refund_ratio(netuid, u16::MAX);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 0);
+        let balance = SubtensorModule::get_coldkey_balance(&coldkey_account_id);
+
+        assert_eq!(
+            SubtensorModule::deregister(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                SubtensorModule::get_root_netuid()
+            ),
+            Err(Error::<Test>::DeregistrationNotPermittedOnRootSubnet.into())
+        );
+        assert_ok!(SubtensorModule::deregister(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid
+        ));
+        System::assert_last_event(
+            Event::NeuronDeregistered(netuid, 0, hotkey_account_id, 0).into(),
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey_account_id),
+            balance
+        );
+    });
+}
@@ -1,5 +1,6 @@
 use crate::mock::*;
-use frame_support::{assert_err, assert_ok};
+use codec::{Compact, Decode, Encode};
+use frame_support::{assert_err, assert_ok, BoundedVec};
 use frame_system::Config;
 use frame_system::{EventRecord, Phase};
 use pallet_subtensor::migration;
@@ -751,10 +752,19 @@ fn test_weights_after_network_pruning() {
         // We expect subnet 1 to be deregistered as it is oldest and has lowest emissions
         assert_eq!(latest_weights[0][1], 21845);
 
+        SubtensorModule::set_network_dissolve_period(2);
         assert_ok!(SubtensorModule::register_network(
             <<Test as Config>::RuntimeOrigin>::signed(cold)
         ));
 
+        // Subnet 1 is dissolving and is only replaced once its dissolve period has passed.
+        assert!(SubtensorModule::is_network_dissolving(1));
+        assert_eq!(SubtensorModule::get_subnet_owner(1), U256::from(0));
+        assert_eq!(SubtensorModule::get_root_weights()[0][1], 21845);
+        step_block(2);
+        assert!(!SubtensorModule::is_network_dissolving(1));
+        assert_eq!(SubtensorModule::get_subnet_owner(1), cold);
+
         // Subnet should not exist, as it would replace a previous subnet.
         assert!(!SubtensorModule::if_subnet_exist(i + 1));
 
@@ -774,6 +784,60 @@ fn test_weights_after_network_pruning() {
     });
 }
 
+#[test]
+fn test_pruned_network_is_dissolved_before_replacement() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        SubtensorModule::set_network_immunity_period(3);
+        SubtensorModule::set_network_rate_limit(0);
+        SubtensorModule::set_network_dissolve_period(5);
+        SubtensorModule::set_max_subnets(1);
+
+        let old_owner: U256 = U256::from(1);
+        let new_owner: U256 = U256::from(2);
+        SubtensorModule::add_balance_to_coldkey_account(&old_owner, 1_000_000_000_000_000);
+        SubtensorModule::add_balance_to_coldkey_account(&new_owner, 1_000_000_000_000_000);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(old_owner)
+        ));
+        let old_lock: u64 = SubtensorModule::get_subnet_locked_balance(1);
+        step_block(3);
+
+        // Registering past the subnet limit announces the dissolution of the subnet to prune.
+        let new_owner_balance: u64 = SubtensorModule::get_coldkey_balance(&new_owner);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(new_owner)
+        ));
+        let new_lock: u64 = new_owner_balance - SubtensorModule::get_coldkey_balance(&new_owner);
+        assert!(SubtensorModule::is_network_dissolving(1));
+        assert_eq!(SubtensorModule::get_subnet_owner(1), old_owner);
+        assert_eq!(
+            pallet_subtensor::PendingNetworkRegistrations::<Test>::get(1),
+            Some((new_owner, new_lock))
+        );
+
+        // Emission queued for the dissolving subnet is neither drained nor carried over.
+        pallet_subtensor::PendingEmission::<Test>::insert(1, 1_000);
+        pallet_subtensor::LoadedEmission::<Test>::insert(1, vec![(U256::from(10), 100, 100)]);
+        step_block(1);
+        assert!(SubtensorModule::get_loaded_emission_tuples(1).is_some());
+
+        // Once the dissolve period has passed the subnet is replaced.
+        let old_owner_balance: u64 = SubtensorModule::get_coldkey_balance(&old_owner);
+        step_block(4);
+        assert!(!SubtensorModule::is_network_dissolving(1));
+        assert_eq!(SubtensorModule::get_subnet_owner(1), new_owner);
+        assert_eq!(SubtensorModule::get_subnet_locked_balance(1), new_lock);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&old_owner),
+            old_owner_balance + old_lock
+        );
+        assert!(pallet_subtensor::PendingNetworkRegistrations::<Test>::get(1).is_none());
+        assert_eq!(SubtensorModule::get_pending_emission(1), 0);
+        assert!(SubtensorModule::get_loaded_emission_tuples(1).is_none());
+    });
+}
+
 /// This test checks the halving mechanism of the emission schedule.
 /// Run this test using the following command:
 /// `cargo test --package pallet-subtensor --test root test_issance_bounds`
@@ -794,6 +858,60 @@ fn test_issuance_bounds() {
     })
 }
 
+#[test]
+fn test_pruned_network_is_dissolved_before_replacement() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        SubtensorModule::set_network_immunity_period(3);
+        SubtensorModule::set_network_rate_limit(0);
+        SubtensorModule::set_network_dissolve_period(5);
+        SubtensorModule::set_max_subnets(1);
+
+        let old_owner: U256 = U256::from(1);
+        let new_owner: U256 = U256::from(2);
+        SubtensorModule::add_balance_to_coldkey_account(&old_owner, 1_000_000_000_000_000);
+        SubtensorModule::add_balance_to_coldkey_account(&new_owner, 1_000_000_000_000_000);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(old_owner)
+        ));
+        let old_lock: u64 = SubtensorModule::get_subnet_locked_balance(1);
+        step_block(3);
+
+        // Registering past the subnet limit announces the dissolution of the subnet to prune.
+        let new_owner_balance: u64 = SubtensorModule::get_coldkey_balance(&new_owner);
+        assert_ok!(SubtensorModule::register_network(
+            <<Test as Config>::RuntimeOrigin>::signed(new_owner)
+        ));
+        let new_lock: u64 = new_owner_balance - SubtensorModule::get_coldkey_balance(&new_owner);
+        assert!(SubtensorModule::is_network_dissolving(1));
+        assert_eq!(SubtensorModule::get_subnet_owner(1), old_owner);
+        assert_eq!(
+            pallet_subtensor::PendingNetworkRegistrations::<Test>::get(1),
+            Some((new_owner, new_lock))
+        );
+
+        // Emission queued for the dissolving subnet is neither drained nor carried over.
+        pallet_subtensor::PendingEmission::<Test>::insert(1, 1_000);
+        pallet_subtensor::LoadedEmission::<Test>::insert(1, vec![(U256::from(10), 100, 100)]);
+        step_block(1);
+        assert!(SubtensorModule::get_loaded_emission_tuples(1).is_some());
+
+        // Once the dissolve period has passed the subnet is replaced.
+        let old_owner_balance: u64 = SubtensorModule::get_coldkey_balance(&old_owner);
+        step_block(4);
+        assert!(!SubtensorModule::is_network_dissolving(1));
+        assert_eq!(SubtensorModule::get_subnet_owner(1), new_owner);
+        assert_eq!(SubtensorModule::get_subnet_locked_balance(1), new_lock);
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&old_owner),
+            old_owner_balance + old_lock
+        );
+        assert!(pallet_subtensor::PendingNetworkRegistrations::<Test>::get(1).is_none());
+        assert_eq!(SubtensorModule::get_pending_emission(1), 0);
+        assert!(SubtensorModule::get_loaded_emission_tuples(1).is_none());
+    });
+}
+
 /// This test checks the halving mechanism of the emission schedule.
 /// Run this test using the following command:
 /// `cargo test --package pallet-subtensor --test root test_halving`
@@ -905,12 +1023,63 @@ fn test_dissolve_network_ok() {
         let owner_coldkey = SubtensorModule::get_subnet_owner(netuid);
         register_ok_neuron(netuid, hotkey, owner_coldkey, 3);
 
+        SubtensorModule::set_network_dissolve_period(2);
         assert!(SubtensorModule::if_subnet_exist(netuid));
         assert_ok!(SubtensorModule::dissolve_network(
             RuntimeOrigin::signed(owner_coldkey),
             netuid
         ));
-        assert!(!SubtensorModule::if_subnet_exist(netuid))
+        // The subnet is dissolving until the end of the dissolve period.
+        assert!(SubtensorModule::if_subnet_exist(netuid));
+        assert!(SubtensorModule::is_network_dissolving(netuid));
+        assert_err!(
+            SubtensorModule::dissolve_network(RuntimeOrigin::signed(owner_coldkey), netuid),
+            Error::<Test>::SubnetDissolving
+        );
+        step_block(1);
+        assert!(SubtensorModule::if_subnet_exist(netuid));
+        step_block(1);
+        assert!(!SubtensorModule::if_subnet_exist(netuid));
+        assert!(!SubtensorModule::is_network_dissolving(netuid));
+    });
+}
+
+#[test]
+fn test_dissolutions_are_queued_at_their_removal_block() {
+    new_test_ext(1).execute_with(|| {
+        let netuids: [u16; 2] = [30, 31];
+        for netuid in netuids {
+            add_network(netuid, 0, 0);
+        }
+        let owner_coldkey = SubtensorModule::get_subnet_owner(netuids[0]);
+
+        SubtensorModule::set_network_dissolve_period(2);
+        assert_ok!(SubtensorModule::dissolve_network(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuids[0]
+        ));
+        SubtensorModule::set_network_dissolve_period(4);
+        assert_ok!(SubtensorModule::dissolve_network(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuids[1]
+        ));
+        assert_eq!(
+            pallet_subtensor::DissolutionsAt::<Test>::get(3),
+            vec![netuids[0]]
+        );
+        assert_eq!(
+            pallet_subtensor::DissolutionsAt::<Test>::get(5),
+            vec![netuids[1]]
+        );
+
+        // Each block only removes the subnets queued for it.
+        step_block(2);
+        assert!(!SubtensorModule::if_subnet_exist(netuids[0]));
+        assert!(SubtensorModule::is_network_dissolving(netuids[1]));
+        assert!(!pallet_subtensor::DissolutionsAt::<Test>::contains_key(3));
+        step_block(2);
+        assert!(!SubtensorModule::if_subnet_exist(netuids[1]));
+        assert!(!pallet_subtensor::DissolutionsAt::<Test>::contains_key(5));
     });
 }
 
@@ -925,6 +1094,7 @@ fn test_dissolve_network_refund_coldkey_ok() {
         let owner_coldkey = SubtensorModule::get_subnet_owner(netuid);
         register_ok_neuron(netuid, hotkey, owner_coldkey, 3);
 
+        SubtensorModule::set_network_dissolve_period(1);
         SubtensorModule::set_subnet_locked_balance(netuid, subnet_locked_balance);
         let coldkey_balance = SubtensorModule::get_coldkey_balance(&owner_coldkey);
 
@@ -933,7 +1103,20 @@ fn test_dissolve_network_refund_coldkey_ok() {
             RuntimeOrigin::signed(owner_coldkey),
             netuid
         ));
+        // The lock is only refunded once the subnet is removed.
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&owner_coldkey),
+            coldkey_balance
+        );
+        step_block(SubtensorModule::get_network_dissolve_period() as u16);
         assert!(!SubtensorModule::if_subnet_exist(netuid));
+        System::assert_has_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::NetworkLockRefunded(
+                netuid,
+                owner_coldkey,
+                subnet_locked_balance,
+            ),
+        ));
 
         let coldkey_new_balance = SubtensorModule::get_coldkey_balance(&owner_coldkey);
 
@@ -972,3 +1155,219 @@ fn test_dissolve_network_does_not_exist_err() {
         );
     });
 }
+
+// Mirrors the layout of RootEpochInfo, whose fields are private.
+#[derive(Decode)]
+#[allow(dead_code)]
+struct RootEpochInfoView {
+    block_emission: Compact<u64>,
+    netuids: Vec<Compact<u16>>,
+    hotkeys: Vec<(Compact<u16>, U256)>,
+    stake: Vec<Compact<u16>>,
+    weights: Vec<Vec<Compact<u16>>>,
+    ranks: Vec<Compact<u16>>,
+    trust: Vec<Compact<u16>>,
+    consensus: Vec<Compact<u16>>,
+    emission: Vec<Compact<u64>>,
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_root_epoch_info_does_not_write() {
+    new_test_ext(1).execute_with(|| {
+        migration::migrate_create_root_network::<Test>();
+        let root_netuid: u16 = 0;
+        SubtensorModule::set_max_registrations_per_block(root_netuid, 2);
+        SubtensorModule::set_target_registrations_per_interval(root_netuid, 2);
+
+        // No root validators yet.
+        assert!(SubtensorModule::get_root_epoch_info().is_none());
+
+        for i in 0..2 {
+            let hotkey = U256::from(i);
+            let coldkey = U256::from(i + 456);
+            SubtensorModule::add_balance_to_coldkey_account(&coldkey, 1_000_000_000_000_000);
+            assert_ok!(SubtensorModule::root_register(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+            ));
+            assert_ok!(SubtensorModule::add_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey),
+                hotkey,
+                1000
+            ));
+            assert_ok!(SubtensorModule::register_network(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey)
+            ));
+        }
+        for i in 0..2 {
+            assert_ok!(SubtensorModule::set_root_weights(
+                <<Test as Config>::RuntimeOrigin>::signed(U256::from(i + 456)),
+                root_netuid,
+                U256::from(i),
+                vec![i as u16 + 1],
+                vec![1],
+                0,
+            ));
+        }
+
+        SubtensorModule::set_tempo(root_netuid, 1);
+        let root_before = sp_io::storage::root(sp_runtime::StateVersion::V1);
+        let info = SubtensorModule::get_root_epoch_info().unwrap();
+        assert_eq!(
+            sp_io::storage::root(sp_runtime::StateVersion::V1),
+            root_before
+        );
+
+        // The explainer reports the emission values the root epoch then sets.
+        let info = RootEpochInfoView::decode(&mut &info.encode()[..]).unwrap();
+        assert_eq!(info.netuids.len(), info.emission.len());
+        assert_ok!(SubtensorModule::root_epoch(1_000_000_000));
+        for (netuid, emission) in info.netuids.iter().zip(info.emission.iter()) {
+            assert_eq!(
+                SubtensorModule::get_subnet_emission_value(netuid.0),
+                emission.0
+            );
+        }
+    });
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn test_set_emission_split() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner = U256::from(1);
+        add_network(netuid, 10, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner);
+        SubtensorModule::set_max_emission_split_share(u16::MAX / 2);
+
+        assert_err!(
+            SubtensorModule::set_emission_split(
+                RuntimeOrigin::signed(U256::from(2)),
+                netuid,
+                0,
+                0,
+                vec![].try_into().unwrap()
+            ),
+            Error::<Test>::NotSubnetOwner
+        );
+        assert_err!(
+            SubtensorModule::set_emission_split(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                u16::MAX / 4,
+                u16::MAX / 4,
+                vec![(U256::from(3), 1)].try_into().unwrap()
+            ),
+            Error::<Test>::EmissionSplitShareTooHigh
+        );
+        let too_many: Vec<(U256, u16)> = (0..5).map(|i| (U256::from(10 + i), 1)).collect();
+        assert!(
+            BoundedVec::<(U256, u16), MaxEmissionSplitRecipients>::try_from(too_many.clone())
+                .is_err()
+        );
+        assert_err!(
+            SubtensorModule::do_set_emission_split(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                0,
+                0,
+                too_many
+            ),
+            Error::<Test>::TooManyEmissionSplitRecipients
+        );
+        assert_err!(
+            SubtensorModule::set_emission_split(
+                RuntimeOrigin::signed(owner),
+                netuid,
+                0,
+                0,
+                vec![(U256::from(3), 1), (U256::from(3), 1)]
+                    .try_into()
+                    .unwrap()
+            ),
+            Error::<Test>::DuplicateEmissionSplitRecipient
+        );
+
+        // Without an emission split the global subnet owner cut applies.
+        assert_eq!(
+            SubtensorModule::get_emission_split(netuid).owner_cut,
+            SubtensorModule::get_subnet_owner_cut()
+        );
+        assert_ok!(SubtensorModule::set_emission_split(
+            RuntimeOrigin::signed(owner),
+            netuid,
+            100,
+            200,
+            vec![(U256::from(3), 300)].try_into().unwrap()
+        ));
+        assert_eq!(
+            SubtensorModule::get_emission_split(netuid),
+            pallet_subtensor::EmissionSplit {
+                owner_cut: 100,
+                burn: 200,
+                recipients: vec![(U256::from(3), 300)],
+            }
+        );
+    });
+}
+
+#[test]
+fn test_max_emission_split_share_follows_subnet_owner_cut() {
+    new_test_ext(1).execute_with(|| {
+        // Until governance sets a maximum, the subnet owner cut is the maximum.
+        SubtensorModule::set_subnet_owner_cut(u16::MAX / 3);
+        assert_eq!(
+            SubtensorModule::get_max_emission_split_share(),
+            u16::MAX / 3
+        );
+        SubtensorModule::set_subnet_owner_cut(u16::MAX / 5);
+        assert_eq!(
+            SubtensorModule::get_max_emission_split_share(),
+            u16::MAX / 5
+        );
+
+        SubtensorModule::set_max_emission_split_share(u16::MAX / 2);
+        SubtensorModule::set_subnet_owner_cut(u16::MAX / 4);
+        assert_eq!(
+            SubtensorModule::get_max_emission_split_share(),
+            u16::MAX / 2
+        );
+    });
+}
+
+#[test]
+fn test_dissolving_network_blocks_registration_and_emission() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let owner_coldkey = U256::from(2);
+        let hotkey = U256::from(3);
+        add_network(netuid, 10, 0);
+        pallet_subtensor::SubnetOwner::<Test>::insert(netuid, owner_coldkey);
+        SubtensorModule::set_emission_values(&[netuid], vec![1_000_000]).unwrap();
+        SubtensorModule::set_burn(netuid, 1);
+        SubtensorModule::add_balance_to_coldkey_account(&owner_coldkey, 10_000);
+
+        assert_ok!(SubtensorModule::dissolve_network(
+            RuntimeOrigin::signed(owner_coldkey),
+            netuid
+        ));
+        System::assert_last_event(RuntimeEvent::SubtensorModule(
+            pallet_subtensor::Event::NetworkDissolutionAnnounced(
+                netuid,
+                SubtensorModule::get_current_block_as_u64()
+                    + SubtensorModule::get_network_dissolve_period(),
+            ),
+        ));
+
+        assert_err!(
+            SubtensorModule::burned_register(RuntimeOrigin::signed(owner_coldkey), netuid, hotkey),
+            Error::<Test>::SubnetDissolving
+        );
+        assert!(!SubtensorModule::checked_allowed_register(netuid));
+
+        SubtensorModule::generate_emission(0);
+        assert_eq!(SubtensorModule::get_pending_emission(netuid), 0);
+    });
+}
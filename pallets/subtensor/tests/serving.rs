@@ -5,7 +5,7 @@ use frame_support::{
     dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays},
 };
 use frame_system::Config;
-use pallet_subtensor::Error;
+use pallet_subtensor::{AxonAddress, AxonEndpoint, AxonServeOutcome, Error, Event, IpClass};
 use sp_core::U256;
 
 mod test {
@@ -550,3 +550,593 @@ fn test_serving_is_invalid_ipv6_address() {
         ));
     });
 }
+
+#[test]
+fn test_serving_classify_ipv4_address() {
+    new_test_ext(1).execute_with(|| {
+        let cases: [((u8, u8, u8, u8), IpClass); 48] = [
+            ((0, 0, 0, 0), IpClass::Reserved),
+            ((0, 255, 255, 255), IpClass::Reserved),
+            ((1, 0, 0, 0), IpClass::Global),
+            ((8, 8, 8, 8), IpClass::Global),
+            ((9, 255, 255, 255), IpClass::Global),
+            ((10, 0, 0, 0), IpClass::Private),
+            ((10, 255, 255, 255), IpClass::Private),
+            ((11, 0, 0, 0), IpClass::Global),
+            ((100, 63, 255, 255), IpClass::Global),
+            ((100, 64, 0, 0), IpClass::Private),
+            ((100, 127, 255, 255), IpClass::Private),
+            ((100, 128, 0, 0), IpClass::Global),
+            ((126, 255, 255, 255), IpClass::Global),
+            ((127, 0, 0, 1), IpClass::Private),
+            ((127, 255, 255, 255), IpClass::Private),
+            ((169, 253, 255, 255), IpClass::Global),
+            ((169, 254, 0, 0), IpClass::Private),
+            ((169, 254, 255, 255), IpClass::Private),
+            ((169, 255, 0, 0), IpClass::Global),
+            ((172, 15, 255, 255), IpClass::Global),
+            ((172, 16, 0, 0), IpClass::Private),
+            ((172, 31, 255, 255), IpClass::Private),
+            ((172, 32, 0, 0), IpClass::Global),
+            ((192, 0, 0, 0), IpClass::Reserved),
+            ((192, 0, 0, 8), IpClass::Reserved),
+            ((192, 0, 0, 9), IpClass::Global),
+            ((192, 0, 0, 10), IpClass::Global),
+            ((192, 0, 0, 255), IpClass::Reserved),
+            ((192, 0, 1, 0), IpClass::Global),
+            ((192, 0, 2, 0), IpClass::Reserved),
+            ((192, 0, 2, 255), IpClass::Reserved),
+            ((192, 0, 3, 0), IpClass::Global),
+            ((192, 88, 99, 1), IpClass::Reserved),
+            ((192, 167, 255, 255), IpClass::Global),
+            ((192, 168, 0, 0), IpClass::Private),
+            ((192, 168, 255, 255), IpClass::Private),
+            ((192, 169, 0, 0), IpClass::Global),
+            ((198, 17, 255, 255), IpClass::Global),
+            ((198, 18, 0, 0), IpClass::Reserved),
+            ((198, 19, 255, 255), IpClass::Reserved),
+            ((198, 20, 0, 0), IpClass::Global),
+            ((198, 51, 100, 1), IpClass::Reserved),
+            ((203, 0, 113, 1), IpClass::Reserved),
+            ((223, 255, 255, 255), IpClass::Global),
+            ((224, 0, 0, 1), IpClass::Reserved),
+            ((239, 255, 255, 255), IpClass::Reserved),
+            ((240, 0, 0, 0), IpClass::Reserved),
+            ((255, 255, 255, 255), IpClass::Reserved),
+        ];
+        for ((a, b, c, d), class) in cases {
+            assert_eq!(
+                SubtensorModule::classify_ip_address(4, test::ipv4(a, b, c, d)),
+                Some(class),
+                "{}.{}.{}.{}",
+                a,
+                b,
+                c,
+                d
+            );
+        }
+        assert_eq!(
+            SubtensorModule::classify_ip_address(4, u32::MAX as u128 + 1),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_serving_classify_ipv6_address() {
+    new_test_ext(1).execute_with(|| {
+        let cases: [([u16; 8], IpClass); 40] = [
+            ([0, 0, 0, 0, 0, 0, 0, 0], IpClass::Reserved),
+            ([0, 0, 0, 0, 0, 0, 0, 1], IpClass::Private),
+            ([0, 0, 0, 0, 0, 0, 0, 2], IpClass::Global),
+            ([0, 0, 0, 0, 0, 0xffff, 0x0808, 0x0808], IpClass::Reserved),
+            ([0x64, 0xff9b, 0, 0, 0, 0, 0x0808, 0x0808], IpClass::Global),
+            ([0x64, 0xff9b, 1, 0, 0, 0, 0, 1], IpClass::Private),
+            ([0x64, 0xff9b, 2, 0, 0, 0, 0, 1], IpClass::Global),
+            ([0x100, 0, 0, 0, 0, 0, 0, 1], IpClass::Reserved),
+            ([0x100, 0, 0, 1, 0, 0, 0, 0], IpClass::Global),
+            ([0x2001, 0, 0, 0, 0, 0, 0, 1], IpClass::Reserved),
+            ([0x2001, 1, 0, 0, 0, 0, 0, 1], IpClass::Global),
+            ([0x2001, 1, 0, 0, 0, 0, 0, 2], IpClass::Global),
+            ([0x2001, 1, 0, 0, 0, 0, 0, 3], IpClass::Global),
+            ([0x2001, 1, 0, 0, 0, 0, 0, 4], IpClass::Reserved),
+            ([0x2001, 2, 0, 0, 0, 0, 0, 1], IpClass::Reserved),
+            ([0x2001, 3, 0, 0, 0, 0, 0, 1], IpClass::Global),
+            ([0x2001, 4, 0x112, 0, 0, 0, 0, 1], IpClass::Global),
+            ([0x2001, 4, 0x113, 0, 0, 0, 0, 1], IpClass::Reserved),
+            ([0x2001, 0x10, 0, 0, 0, 0, 0, 1], IpClass::Reserved),
+            ([0x2001, 0x20, 0, 0, 0, 0, 0, 1], IpClass::Global),
+            ([0x2001, 0x2f, 0, 0, 0, 0, 0, 1], IpClass::Global),
+            ([0x2001, 0x30, 0, 0, 0, 0, 0, 1], IpClass::Global),
+            ([0x2001, 0x3f, 0, 0, 0, 0, 0, 1], IpClass::Global),
+            ([0x2001, 0x40, 0, 0, 0, 0, 0, 1], IpClass::Reserved),
+            ([0x2001, 0x1ff, 0xffff, 0, 0, 0, 0, 1], IpClass::Reserved),
+            ([0x2001, 0x200, 0, 0, 0, 0, 0, 1], IpClass::Global),
+            ([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1], IpClass::Reserved),
+            ([0x2001, 0xdb9, 0, 0, 0, 0, 0, 1], IpClass::Global),
+            ([0x2002, 0, 0, 0, 0, 0, 0, 1], IpClass::Reserved),
+            ([0x2606, 0x4700, 0, 0, 0, 0, 0, 0x1111], IpClass::Global),
+            ([0x3fff, 0, 0, 0, 0, 0, 0, 1], IpClass::Reserved),
+            ([0x3fff, 0x0fff, 0, 0, 0, 0, 0, 1], IpClass::Reserved),
+            ([0x3fff, 0x1000, 0, 0, 0, 0, 0, 1], IpClass::Global),
+            ([0x5f00, 0, 0, 0, 0, 0, 0, 1], IpClass::Reserved),
+            ([0xfbff, 0xffff, 0, 0, 0, 0, 0, 1], IpClass::Global),
+            ([0xfc00, 0, 0, 0, 0, 0, 0, 1], IpClass::Private),
+            ([0xfdff, 0xffff, 0, 0, 0, 0, 0, 1], IpClass::Private),
+            ([0xfe80, 0, 0, 0, 0, 0, 0, 1], IpClass::Private),
+            ([0xfec0, 0, 0, 0, 0, 0, 0, 1], IpClass::Global),
+            ([0xff02, 0, 0, 0, 0, 0, 0, 1], IpClass::Reserved),
+        ];
+        for ([a, b, c, d, e, f, g, h], class) in cases {
+            assert_eq!(
+                SubtensorModule::classify_ip_address(6, test::ipv6(a, b, c, d, e, f, g, h)),
+                Some(class),
+                "{:x}:{:x}:{:x}:{:x}:{:x}:{:x}:{:x}:{:x}",
+                a,
+                b,
+                c,
+                d,
+                e,
+                f,
+                g,
+                h
+            );
+        }
+        assert_eq!(SubtensorModule::classify_ip_address(5, 0), None);
+    });
+}
+
+#[test]
+fn test_serving_allow_private_ips() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let version: u32 = 2;
+        let port: u16 = 128;
+        let protocol: u8 = 0;
+        let placeholder1: u8 = 0;
+        let placeholder2: u8 = 0;
+        add_network(netuid, tempo, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        SubtensorModule::set_serving_rate_limit(netuid, 0);
+
+        let private: [(u8, u128); 4] = [
+            (4, test::ipv4(10, 0, 0, 1)),
+            (4, test::ipv4(192, 168, 1, 1)),
+            (4, test::ipv4(127, 0, 0, 1)),
+            (6, test::ipv6(0xfd00, 0, 0, 0, 0, 0, 0, 1)),
+        ];
+        let reserved: [(u8, u128); 3] = [
+            (4, test::ipv4(203, 0, 113, 1)),
+            (4, test::ipv4(224, 0, 0, 1)),
+            (6, test::ipv6(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+        ];
+        let serve = |ip_type: u8, ip: u128| {
+            SubtensorModule::serve_axon(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                netuid,
+                version,
+                ip,
+                port,
+                ip_type,
+                protocol,
+                placeholder1,
+                placeholder2,
+            )
+        };
+
+        for (ip_type, ip) in private.into_iter().chain(reserved) {
+            assert_eq!(
+                serve(ip_type, ip),
+                Err(Error::<Test>::InvalidIpAddress.into())
+            );
+        }
+
+        SubtensorModule::set_allow_private_ips(netuid, true);
+        for (ip_type, ip) in private {
+            assert_ok!(serve(ip_type, ip));
+            let neuron = SubtensorModule::get_axon_info(netuid, &hotkey_account_id);
+            assert_eq!(neuron.ip, ip);
+            assert_eq!(neuron.ip_type, ip_type);
+        }
+        for (ip_type, ip) in reserved {
+            assert_eq!(
+                serve(ip_type, ip),
+                Err(Error::<Test>::InvalidIpAddress.into())
+            );
+        }
+
+        // Private ranges stay rejected on subnets that do not allow them.
+        let other_netuid: u16 = 2;
+        add_network(other_netuid, tempo, 0);
+        register_ok_neuron(other_netuid, hotkey_account_id, U256::from(66), 100000);
+        assert_eq!(
+            SubtensorModule::serve_prometheus(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                other_netuid,
+                version,
+                test::ipv4(10, 0, 0, 1),
+                port,
+                4,
+            ),
+            Err(Error::<Test>::InvalidIpAddress.into())
+        );
+    });
+}
+
+fn endpoint(address: AxonAddress, port: u16) -> AxonEndpoint {
+    AxonEndpoint {
+        address,
+        port,
+        protocol: 0,
+        cert_digest: None,
+    }
+}
+
+fn dns(hostname: &str) -> AxonAddress {
+    AxonAddress::Dns(hostname.as_bytes().to_vec().try_into().unwrap())
+}
+
+#[test]
+fn test_serving_axon_v2_ok() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid: u16 = 1;
+        let tempo: u16 = 13;
+        let version: u32 = 2;
+        add_network(netuid, tempo, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        run_to_block(1);
+
+        let endpoints = vec![
+            AxonEndpoint {
+                cert_digest: Some([7; 32]),
+                ..endpoint(dns("miner.example.com"), 443)
+            },
+            endpoint(
+                AxonAddress::Ipv6(test::ipv6(0x2606, 0x4700, 0, 0, 0, 0, 0, 0x1111)),
+                8091,
+            ),
+            AxonEndpoint {
+                protocol: 1,
+                ..endpoint(
+                    AxonAddress::Ipv4(test::ipv4(8, 8, 8, 8).try_into().unwrap()),
+                    8092,
+                )
+            },
+        ];
+        assert_ok!(SubtensorModule::serve_axon_v2(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            version,
+            endpoints.clone().try_into().unwrap()
+        ));
+
+        let axon_info_v2 = SubtensorModule::get_axon_info_v2(netuid, &hotkey_account_id);
+        assert_eq!(axon_info_v2.block, 1);
+        assert_eq!(axon_info_v2.version, version);
+        assert_eq!(axon_info_v2.endpoints.into_inner(), endpoints);
+
+        // The first ip endpoint is served as the axon of the first version.
+        let axon_info = SubtensorModule::get_axon_info(netuid, &hotkey_account_id);
+        assert_eq!(axon_info.block, 1);
+        assert_eq!(axon_info.version, version);
+        assert_eq!(
+            axon_info.ip,
+            test::ipv6(0x2606, 0x4700, 0, 0, 0, 0, 0, 0x1111)
+        );
+        assert_eq!(axon_info.ip_type, 6);
+        assert_eq!(axon_info.port, 8091);
+        assert_eq!(axon_info.protocol, 0);
+    });
+}
+
+#[test]
+fn test_serving_axon_v1_serves_v2() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        run_to_block(1);
+
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            2,
+            test::ipv4(8, 8, 4, 4),
+            128,
+            4,
+            1,
+            0,
+            0
+        ));
+
+        let axon_info_v2 = SubtensorModule::get_axon_info_v2(netuid, &hotkey_account_id);
+        assert_eq!(axon_info_v2.block, 1);
+        assert_eq!(axon_info_v2.version, 2);
+        assert_eq!(
+            axon_info_v2.endpoints.into_inner(),
+            vec![AxonEndpoint {
+                protocol: 1,
+                ..endpoint(
+                    AxonAddress::Ipv4(test::ipv4(8, 8, 4, 4).try_into().unwrap()),
+                    128
+                )
+            }]
+        );
+    });
+}
+
+#[test]
+fn test_serving_axon_v2_invalid_endpoints() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        run_to_block(1);
+
+        let global = endpoint(AxonAddress::Ipv4(0x0808_0808), 8091);
+        let cases: [(Vec<AxonEndpoint>, Error<Test>); 7] = [
+            (vec![], Error::<Test>::NoAxonEndpoints),
+            (
+                vec![global.clone(), endpoint(dns("miner.example.com"), 0)],
+                Error::<Test>::InvalidPort,
+            ),
+            (
+                vec![
+                    global.clone(),
+                    endpoint(AxonAddress::Ipv4(0x0a00_0001), 8091),
+                ],
+                Error::<Test>::InvalidIpAddress,
+            ),
+            (
+                vec![endpoint(
+                    AxonAddress::Ipv6(test::ipv6(0xfd00, 0, 0, 0, 0, 0, 0, 1)),
+                    8091,
+                )],
+                Error::<Test>::InvalidIpAddress,
+            ),
+            (
+                vec![global.clone(), endpoint(dns("miner..example.com"), 8091)],
+                Error::<Test>::InvalidHostname,
+            ),
+            (
+                vec![endpoint(dns("miner.localhost"), 8091)],
+                Error::<Test>::InvalidHostname,
+            ),
+            (
+                vec![endpoint(dns("miner.test"), 8091)],
+                Error::<Test>::InvalidHostname,
+            ),
+        ];
+        for (endpoints, error) in cases {
+            assert_eq!(
+                SubtensorModule::serve_axon_v2(
+                    <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                    netuid,
+                    2,
+                    endpoints.try_into().unwrap()
+                ),
+                Err(error.into())
+            );
+        }
+        assert!(!SubtensorModule::has_axon_info(netuid, &hotkey_account_id));
+
+        // Private addresses and names are served on subnets allowing them.
+        SubtensorModule::set_allow_private_ips(netuid, true);
+        assert_ok!(SubtensorModule::serve_axon_v2(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuid,
+            2,
+            vec![
+                endpoint(dns("miner.localhost"), 8091),
+                endpoint(AxonAddress::Ipv4(0x0a00_0001), 8091)
+            ]
+            .try_into()
+            .unwrap()
+        ));
+    });
+}
+
+#[test]
+fn test_serving_axon_v2_rate_limit_exceeded() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let netuid: u16 = 1;
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, U256::from(66), 0);
+        SubtensorModule::set_serving_rate_limit(netuid, 2);
+        run_to_block(1);
+
+        let serve = || {
+            SubtensorModule::serve_axon_v2(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                netuid,
+                2,
+                vec![endpoint(dns("miner.example.com"), 8091)]
+                    .try_into()
+                    .unwrap(),
+            )
+        };
+        assert_ok!(serve());
+        // An axon served with only DNS names has an unset ip in the first version.
+        assert_eq!(
+            SubtensorModule::get_axon_info(netuid, &hotkey_account_id).ip_type,
+            0
+        );
+        run_to_block(2);
+        assert_eq!(serve(), Err(Error::<Test>::ServingRateLimitExceeded.into()));
+        run_to_block(3);
+        assert_ok!(serve());
+    });
+}
+
+#[test]
+fn test_serving_classify_hostname() {
+    new_test_ext(1).execute_with(|| {
+        let cases: [(&str, Option<IpClass>); 20] = [
+            ("example.com", Some(IpClass::Global)),
+            ("miner-1.subnet.example.org", Some(IpClass::Global)),
+            ("EXAMPLE.COM", Some(IpClass::Global)),
+            ("xn--bcher-kva.example", Some(IpClass::Reserved)),
+            ("1.2.3.com", Some(IpClass::Global)),
+            ("miner", Some(IpClass::Private)),
+            ("localhost", Some(IpClass::Private)),
+            ("miner.LOCALHOST", Some(IpClass::Private)),
+            ("printer.local", Some(IpClass::Private)),
+            ("miner.internal", Some(IpClass::Private)),
+            ("miner.test", Some(IpClass::Reserved)),
+            ("miner.invalid", Some(IpClass::Reserved)),
+            ("abcdef.onion", Some(IpClass::Reserved)),
+            ("", None),
+            ("1.2.3.4", None),
+            ("example.com.", None),
+            (".example.com", None),
+            ("-miner.example.com", None),
+            ("miner-.example.com", None),
+            ("miner_1.example.com", None),
+        ];
+        for (hostname, class) in cases {
+            assert_eq!(
+                SubtensorModule::classify_hostname(hostname.as_bytes()),
+                class,
+                "{}",
+                hostname
+            );
+        }
+        let label: String = "a".repeat(63);
+        assert_eq!(
+            SubtensorModule::classify_hostname(format!("{}.com", label).as_bytes()),
+            Some(IpClass::Global)
+        );
+        assert_eq!(
+            SubtensorModule::classify_hostname(format!("{}a.com", label).as_bytes()),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_serving_axon_multi() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        let coldkey_account_id = U256::from(66);
+        let netuids: [u16; 3] = [1, 2, 3];
+        for (nonce, netuid) in netuids.iter().enumerate() {
+            add_network(*netuid, 13, 0);
+            register_ok_neuron(
+                *netuid,
+                hotkey_account_id,
+                coldkey_account_id,
+                nonce as u64 * 100_000,
+            );
+        }
+        run_to_block(1);
+
+        // Subnets 1 and 2 allow the private endpoint, subnet 2 was served on this block and is
+        // rate limited.
+        SubtensorModule::set_serving_rate_limit(2, 2);
+        assert_ok!(SubtensorModule::serve_axon(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            2,
+            1,
+            test::ipv4(8, 8, 4, 4),
+            128,
+            4,
+            0,
+            0,
+            0
+        ));
+        SubtensorModule::set_allow_private_ips(1, true);
+        SubtensorModule::set_allow_private_ips(2, true);
+
+        let endpoints = vec![endpoint(
+            AxonAddress::Ipv4(test::ipv4(10, 0, 0, 1).try_into().unwrap()),
+            8091,
+        )];
+        assert_ok!(SubtensorModule::serve_axon_multi(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            netuids.to_vec().try_into().unwrap(),
+            2,
+            endpoints.clone().try_into().unwrap()
+        ));
+        System::assert_last_event(
+            Event::AxonServedMulti(
+                hotkey_account_id,
+                vec![
+                    (1, AxonServeOutcome::Served),
+                    (
+                        2,
+                        AxonServeOutcome::Failed(Error::<Test>::ServingRateLimitExceeded.into()),
+                    ),
+                    (
+                        3,
+                        AxonServeOutcome::Failed(Error::<Test>::InvalidIpAddress.into()),
+                    ),
+                ],
+            )
+            .into(),
+        );
+
+        assert_eq!(
+            SubtensorModule::get_axon_info_v2(1, &hotkey_account_id)
+                .endpoints
+                .into_inner(),
+            endpoints
+        );
+        assert_eq!(
+            SubtensorModule::get_axon_info(2, &hotkey_account_id).version,
+            1
+        );
+        assert!(!SubtensorModule::has_axon_info(3, &hotkey_account_id));
+    });
+}
+
+#[test]
+fn test_serving_axon_multi_membership() {
+    new_test_ext(1).execute_with(|| {
+        let hotkey_account_id = U256::from(1);
+        add_network(1, 13, 0);
+        add_network(2, 13, 0);
+        register_ok_neuron(1, hotkey_account_id, U256::from(66), 0);
+        run_to_block(1);
+
+        let serve = |netuids: Vec<u16>| {
+            SubtensorModule::serve_axon_multi(
+                <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+                netuids.try_into().unwrap(),
+                2,
+                vec![endpoint(dns("miner.example.com"), 8091)]
+                    .try_into()
+                    .unwrap(),
+            )
+        };
+        assert_eq!(
+            serve(vec![1, 2]),
+            Err(Error::<Test>::HotKeyNotRegisteredInSubNet.into())
+        );
+        assert_eq!(
+            serve(vec![1, 1]),
+            Err(Error::<Test>::DuplicateServeAxonNetuid.into())
+        );
+        // Nothing is served when the call fails.
+        assert!(!SubtensorModule::has_axon_info(1, &hotkey_account_id));
+
+        assert_ok!(serve(vec![1]));
+        assert!(SubtensorModule::has_axon_info(1, &hotkey_account_id));
+
+        // The call fails when the axon is served on none of the subnets.
+        assert_eq!(
+            serve(vec![]),
+            Err(Error::<Test>::AxonNotServedOnAnySubnet.into())
+        );
+        SubtensorModule::set_serving_rate_limit(1, 2);
+        assert_eq!(
+            serve(vec![1]),
+            Err(Error::<Test>::AxonNotServedOnAnySubnet.into())
+        );
+    });
+}
@@ -1,4 +1,9 @@
-use frame_support::{assert_err, assert_noop, assert_ok, traits::Currency};
+use codec::{Decode, Encode};
+use frame_support::{
+    assert_err, assert_noop, assert_ok,
+    traits::{Currency, Get},
+    BoundedVec,
+};
 use frame_system::Config;
 mod mock;
 use frame_support::dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, Pays};
@@ -810,6 +815,251 @@ fn test_remove_stake_total_issuance_no_change() {
     });
 }
 
+/***********************************************************
+    staking::withdraw_unbonded() tests
+************************************************************/
+#[test]
+fn test_remove_stake_queues_unbonding_chunk() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let amount = 10000;
+        let netuid: u16 = 1;
+        let unbonding_period: u64 = 100;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 0);
+        SubtensorModule::set_unbonding_period(netuid, unbonding_period);
+        SubtensorModule::increase_stake_on_hotkey_account(&hotkey_account_id, amount);
+
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+            amount
+        ));
+
+        // The stake left the hotkey but has not been credited yet.
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkey_account_id),
+            0
+        );
+        assert_eq!(SubtensorModule::get_total_stake(), 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey_account_id), 0);
+        assert_eq!(
+            Unbonding::<Test>::get(coldkey_account_id, hotkey_account_id),
+            vec![(amount, 1 + unbonding_period)]
+        );
+        assert_eq!(
+            SubtensorModule::get_total_unbonding_for_coldkey_hotkey(
+                &coldkey_account_id,
+                &hotkey_account_id
+            ),
+            amount
+        );
+    });
+}
+
+#[test]
+fn test_withdraw_unbonded_before_unlock_fails() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let amount = 10000;
+        let netuid: u16 = 1;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 0);
+        SubtensorModule::set_unbonding_period(netuid, 100);
+        SubtensorModule::increase_stake_on_hotkey_account(&hotkey_account_id, amount);
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+            amount
+        ));
+
+        System::set_block_number(100);
+        assert_noop!(
+            SubtensorModule::withdraw_unbonded(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey_account_id
+            ),
+            Error::<Test>::NoUnbondedStakeToWithdraw
+        );
+    });
+}
+
+#[test]
+fn test_withdraw_unbonded_ok() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let netuid: u16 = 1;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 0);
+        SubtensorModule::set_unbonding_period(netuid, 100);
+        SubtensorModule::increase_stake_on_hotkey_account(&hotkey_account_id, 10000);
+
+        // Two chunks with different unlock blocks.
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+            3000
+        ));
+        System::set_block_number(50);
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+            2000
+        ));
+
+        // Only the first chunk has matured.
+        System::set_block_number(101);
+        assert_ok!(SubtensorModule::withdraw_unbonded(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey_account_id),
+            3000
+        );
+        assert_eq!(
+            Unbonding::<Test>::get(coldkey_account_id, hotkey_account_id),
+            vec![(2000, 150)]
+        );
+
+        // The second chunk matures and the queue is cleared.
+        System::set_block_number(150);
+        assert_ok!(SubtensorModule::withdraw_unbonded(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id
+        ));
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&coldkey_account_id),
+            5000
+        );
+        assert!(!Unbonding::<Test>::contains_key(
+            coldkey_account_id,
+            hotkey_account_id
+        ));
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkey_account_id),
+            5000
+        );
+    });
+}
+
+#[test]
+fn test_remove_stake_too_many_unbonding_chunks() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let netuid: u16 = 1;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 0);
+        SubtensorModule::set_unbonding_period(netuid, 100);
+        SubtensorModule::set_target_stakes_per_interval(1000);
+        SubtensorModule::increase_stake_on_hotkey_account(&hotkey_account_id, 10000);
+
+        for _ in 0..<Test as pallet_subtensor::Config>::MaxUnbondingChunks::get() {
+            assert_ok!(SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey_account_id,
+                1
+            ));
+        }
+        assert_noop!(
+            SubtensorModule::remove_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+                hotkey_account_id,
+                1
+            ),
+            Error::<Test>::TooManyUnbondingChunks
+        );
+    });
+}
+
+#[test]
+fn test_remove_stake_keeps_bonded_unbonding_period() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let unbonding_period: u64 = 100;
+
+        add_network(1, 13, 0);
+        add_network(2, 13, 0);
+        register_ok_neuron(1, hotkey_account_id, coldkey_account_id, 0);
+        register_ok_neuron(2, hotkey_account_id, coldkey_account_id, 100000);
+        SubtensorModule::set_unbonding_period(1, unbonding_period);
+        SubtensorModule::add_balance_to_coldkey_account(&coldkey_account_id, 10000);
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+            10000
+        ));
+
+        // Leaving the subnet with the unbonding period does not make the stake liquid.
+        assert_ok!(SubtensorModule::deregister(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            1
+        ));
+        assert_eq!(
+            SubtensorModule::get_unbonding_period_for_hotkey(&hotkey_account_id),
+            0
+        );
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+            4000
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey_account_id), 0);
+        assert_eq!(
+            Unbonding::<Test>::get(coldkey_account_id, hotkey_account_id),
+            vec![(4000, 1 + unbonding_period)]
+        );
+
+        // Neither does leaving every subnet, the stake returned on deregistration is queued.
+        assert_ok!(SubtensorModule::deregister(
+            <<Test as Config>::RuntimeOrigin>::signed(hotkey_account_id),
+            2
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&coldkey_account_id), 0);
+        assert_eq!(
+            Unbonding::<Test>::get(coldkey_account_id, hotkey_account_id),
+            vec![(4000, 1 + unbonding_period), (6000, 1 + unbonding_period)]
+        );
+        assert!(!BondedUnbondingPeriod::<Test>::contains_key(
+            coldkey_account_id,
+            hotkey_account_id
+        ));
+    });
+}
+
+#[test]
+fn test_get_unbonding_info_for_coldkey() {
+    new_test_ext(1).execute_with(|| {
+        let coldkey_account_id = U256::from(4343);
+        let hotkey_account_id = U256::from(4968585);
+        let netuid: u16 = 1;
+
+        add_network(netuid, 13, 0);
+        register_ok_neuron(netuid, hotkey_account_id, coldkey_account_id, 0);
+        SubtensorModule::set_unbonding_period(netuid, 100);
+        SubtensorModule::increase_stake_on_hotkey_account(&hotkey_account_id, 10000);
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(coldkey_account_id),
+            hotkey_account_id,
+            4000
+        ));
+
+        let coldkey_vec = coldkey_account_id.encode();
+        let unbonding_info = SubtensorModule::get_unbonding_info_for_coldkey(coldkey_vec);
+        assert_eq!(unbonding_info.len(), 1);
+        assert!(SubtensorModule::get_unbonding_info_for_coldkey(vec![0u8; 31]).is_empty());
+    });
+}
+
 /***********************************************************
     staking::get_coldkey_balance() tests
 ************************************************************/
@@ -3125,3 +3375,619 @@ fn test_rate_limits_enforced_on_increase_take() {
         assert_eq!(SubtensorModule::get_hotkey_take(&hotkey0), u16::MAX / 8);
     });
 }
+
+/************************************************************
+    staking::move_stake() tests
+************************************************************/
+
+/// Sets up two delegate hotkeys on the same subnet and stakes `nominated` from `nominator` on the first one.
+fn setup_move_stake(nominator: U256, nominated: u64) -> (U256, U256) {
+    let netuid: u16 = 1;
+    let (coldkey1, hotkey1) = (U256::from(1), U256::from(2));
+    let (coldkey2, hotkey2) = (U256::from(3), U256::from(4));
+
+    add_network(netuid, 0, 0);
+    register_ok_neuron(netuid, hotkey1, coldkey1, 0);
+    register_ok_neuron(netuid, hotkey2, coldkey2, 100_000);
+    assert_ok!(SubtensorModule::become_delegate(
+        <<Test as Config>::RuntimeOrigin>::signed(coldkey1),
+        hotkey1
+    ));
+    assert_ok!(SubtensorModule::become_delegate(
+        <<Test as Config>::RuntimeOrigin>::signed(coldkey2),
+        hotkey2
+    ));
+
+    SubtensorModule::set_target_stakes_per_interval(10);
+    SubtensorModule::add_balance_to_coldkey_account(&nominator, nominated);
+    assert_ok!(SubtensorModule::add_stake(
+        <<Test as Config>::RuntimeOrigin>::signed(nominator),
+        hotkey1,
+        nominated
+    ));
+
+    (hotkey1, hotkey2)
+}
+
+#[test]
+fn test_move_stake_ok() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(10);
+        let (hotkey1, hotkey2) = setup_move_stake(nominator, 100_000);
+        let total_stake = SubtensorModule::get_total_stake();
+
+        assert_ok!(SubtensorModule::move_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey1,
+            hotkey2,
+            40_000
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey1),
+            60_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey2),
+            40_000
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkey1),
+            60_000
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_hotkey(&hotkey2),
+            40_000
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&nominator),
+            100_000
+        );
+        assert_eq!(SubtensorModule::get_total_stake(), total_stake);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&nominator), 0);
+        System::assert_last_event(Event::StakeMoved(nominator, hotkey1, hotkey2, 40_000).into());
+    });
+}
+
+#[test]
+fn test_move_stake_same_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(10);
+        let (hotkey1, _) = setup_move_stake(nominator, 100_000);
+
+        assert_noop!(
+            SubtensorModule::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                hotkey1,
+                hotkey1,
+                40_000
+            ),
+            Error::<Test>::SameHotkeyStakeMove
+        );
+    });
+}
+
+#[test]
+fn test_move_stake_not_enough_stake() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(10);
+        let (hotkey1, hotkey2) = setup_move_stake(nominator, 100_000);
+
+        assert_noop!(
+            SubtensorModule::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                hotkey1,
+                hotkey2,
+                100_001
+            ),
+            Error::<Test>::NotEnoughStakeToWithdraw
+        );
+        assert_noop!(
+            SubtensorModule::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                hotkey1,
+                hotkey2,
+                0
+            ),
+            Error::<Test>::StakeToWithdrawIsZero
+        );
+    });
+}
+
+#[test]
+fn test_move_stake_below_minimum_threshold() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(10);
+        let (hotkey1, hotkey2) = setup_move_stake(nominator, 100_000);
+        SubtensorModule::set_nominator_min_required_stake(50_000);
+
+        // The destination nomination would be below the threshold.
+        assert_noop!(
+            SubtensorModule::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                hotkey1,
+                hotkey2,
+                40_000
+            ),
+            Error::<Test>::NomStakeBelowMinimumThreshold
+        );
+
+        // The origin nomination would be left below the threshold.
+        assert_noop!(
+            SubtensorModule::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                hotkey1,
+                hotkey2,
+                60_000
+            ),
+            Error::<Test>::NomStakeBelowMinimumThreshold
+        );
+
+        // Moving the whole nomination is allowed.
+        assert_ok!(SubtensorModule::move_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey1,
+            hotkey2,
+            100_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey2),
+            100_000
+        );
+    });
+}
+
+#[test]
+fn test_move_stake_to_non_delegate() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(10);
+        let (hotkey1, _) = setup_move_stake(nominator, 100_000);
+        let hotkey3 = U256::from(6);
+        register_ok_neuron(1, hotkey3, U256::from(5), 200_000);
+
+        assert_noop!(
+            SubtensorModule::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                hotkey1,
+                hotkey3,
+                40_000
+            ),
+            Error::<Test>::HotKeyNotDelegateAndSignerNotOwnHotKey
+        );
+    });
+}
+
+#[test]
+fn test_move_stake_keeps_origin_unbonding_period() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(10);
+        let (hotkey1, _) = setup_move_stake(nominator, 100_000);
+        let hotkey3 = U256::from(6);
+        add_network(2, 0, 0);
+        register_ok_neuron(2, hotkey3, nominator, 200_000);
+        SubtensorModule::set_unbonding_period(1, 100);
+
+        // The destination hotkey has no unbonding period of its own.
+        assert_ok!(SubtensorModule::move_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey1,
+            hotkey3,
+            40_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_unbonding_period_for_hotkey(&hotkey3),
+            0
+        );
+        assert_ok!(SubtensorModule::remove_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey3,
+            40_000
+        ));
+        assert_eq!(SubtensorModule::get_coldkey_balance(&nominator), 0);
+        assert_eq!(
+            Unbonding::<Test>::get(nominator, hotkey3),
+            vec![(40_000, 101)]
+        );
+    });
+}
+
+#[test]
+fn test_move_stake_origin_rate_limit() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(10);
+        let (hotkey1, hotkey2) = setup_move_stake(nominator, 100_000);
+
+        // Staking onto hotkey1 used up the interval of the origin hotkey.
+        SubtensorModule::set_target_stakes_per_interval(1);
+        assert_noop!(
+            SubtensorModule::move_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                hotkey1,
+                hotkey2,
+                40_000
+            ),
+            Error::<Test>::UnstakeRateLimitExceeded
+        );
+
+        // The move counts against both hotkeys.
+        SubtensorModule::set_target_stakes_per_interval(2);
+        assert_ok!(SubtensorModule::move_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey1,
+            hotkey2,
+            40_000
+        ));
+        assert_eq!(
+            SubtensorModule::get_stakes_this_interval_for_coldkey_hotkey(&nominator, &hotkey1),
+            2
+        );
+        assert_eq!(
+            SubtensorModule::get_stakes_this_interval_for_coldkey_hotkey(&nominator, &hotkey2),
+            1
+        );
+    });
+}
+
+/************************************************************
+    staking::rebalance_stake() tests
+************************************************************/
+
+type RebalanceTargets =
+    BoundedVec<(U256, u64), <Test as pallet_subtensor::Config>::MaxRebalanceTargets>;
+
+fn bounded(targets: Vec<(U256, u64)>) -> RebalanceTargets {
+    targets.try_into().unwrap()
+}
+
+#[test]
+fn test_rebalance_stake_ok() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(10);
+        let (hotkey1, hotkey2) = setup_move_stake(nominator, 100_000);
+        SubtensorModule::add_balance_to_coldkey_account(&nominator, 20_000);
+
+        // The decrease on hotkey1 funds most of the increase on hotkey2.
+        assert_ok!(SubtensorModule::rebalance_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            bounded(vec![(hotkey2, 70_000), (hotkey1, 50_000)])
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey1),
+            50_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey2),
+            70_000
+        );
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&nominator),
+            120_000
+        );
+        assert_eq!(SubtensorModule::get_coldkey_balance(&nominator), 0);
+        System::assert_last_event(
+            Event::StakeRebalanced(
+                nominator,
+                vec![
+                    (hotkey2, StakeRebalanceOutcome::Increased(70_000)),
+                    (hotkey1, StakeRebalanceOutcome::Decreased(50_000)),
+                ],
+            )
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_rebalance_stake_reports_failed_entries() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(10);
+        let (hotkey1, hotkey2) = setup_move_stake(nominator, 100_000);
+        let hotkey3 = U256::from(6);
+        register_ok_neuron(1, hotkey3, U256::from(5), 200_000);
+
+        // hotkey3 is not a delegate, the other entries are still applied.
+        assert_ok!(SubtensorModule::rebalance_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            bounded(vec![
+                (hotkey1, 60_000),
+                (hotkey2, 40_000),
+                (hotkey3, 10_000)
+            ])
+        ));
+
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey1),
+            60_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey2),
+            40_000
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey3),
+            0
+        );
+        System::assert_last_event(
+            Event::StakeRebalanced(
+                nominator,
+                vec![
+                    (hotkey1, StakeRebalanceOutcome::Decreased(40_000)),
+                    (hotkey2, StakeRebalanceOutcome::Increased(40_000)),
+                    (
+                        hotkey3,
+                        StakeRebalanceOutcome::Failed(
+                            Error::<Test>::HotKeyNotDelegateAndSignerNotOwnHotKey.into(),
+                        ),
+                    ),
+                ],
+            )
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_rebalance_stake_invalid_targets() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(10);
+        let (hotkey1, hotkey2) = setup_move_stake(nominator, 100_000);
+
+        assert_noop!(
+            SubtensorModule::rebalance_stake(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                bounded(vec![(hotkey1, 50_000), (hotkey2, 10_000), (hotkey1, 0)])
+            ),
+            Error::<Test>::DuplicateRebalanceTarget
+        );
+
+        // More targets than allowed do not decode.
+        let max_targets: u32 = <Test as pallet_subtensor::Config>::MaxRebalanceTargets::get();
+        let targets: Vec<(U256, u64)> = (0..=max_targets)
+            .map(|i| (U256::from(1000 + i), 0))
+            .collect();
+        assert!(RebalanceTargets::decode(&mut &targets.encode()[..]).is_err());
+    });
+}
+
+#[test]
+fn test_rebalance_stake_unbonding_does_not_fund_increases() {
+    new_test_ext(1).execute_with(|| {
+        let nominator = U256::from(10);
+        let (hotkey1, hotkey2) = setup_move_stake(nominator, 100_000);
+        SubtensorModule::set_unbonding_period(1, 100);
+
+        // The decrease is queued for unbonding, the coldkey has no free balance for the increase.
+        assert_ok!(SubtensorModule::rebalance_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            bounded(vec![(hotkey1, 50_000), (hotkey2, 50_000)])
+        ));
+        assert_eq!(
+            Unbonding::<Test>::get(nominator, hotkey1),
+            vec![(50_000, 101)]
+        );
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey2),
+            0
+        );
+        System::assert_last_event(
+            Event::StakeRebalanced(
+                nominator,
+                vec![
+                    (hotkey1, StakeRebalanceOutcome::Decreased(50_000)),
+                    (
+                        hotkey2,
+                        StakeRebalanceOutcome::Failed(
+                            Error::<Test>::NotEnoughBalanceToStake.into(),
+                        ),
+                    ),
+                ],
+            )
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_reward_destination() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let owner = U256::from(2);
+        let nominator = U256::from(3);
+        let payee = U256::from(4);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, owner, 2341312);
+        assert_ok!(SubtensorModule::become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            hotkey
+        ));
+        assert_eq!(
+            SubtensorModule::set_reward_destination(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                hotkey,
+                RewardDestination::Free
+            ),
+            Err(Error::<Test>::NoStakeOnHotkey.into())
+        );
+        SubtensorModule::add_balance_to_coldkey_account(&nominator, 10_000);
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey,
+            10_000
+        ));
+
+        assert_eq!(
+            SubtensorModule::set_reward_destination(
+                <<Test as Config>::RuntimeOrigin>::signed(nominator),
+                U256::from(99),
+                RewardDestination::Free
+            ),
+            Err(Error::<Test>::HotKeyAccountNotExists.into())
+        );
+
+        // Compound by default.
+        SubtensorModule::emit_inflation_through_hotkey_account(&hotkey, 0, 1_000);
+        let compounded: u64 =
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey) - 10_000;
+        assert!(compounded > 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&nominator), 0);
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&nominator),
+            10_000 + compounded
+        );
+
+        // Paid to the free balance of the nominator.
+        assert_ok!(SubtensorModule::set_reward_destination(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey,
+            RewardDestination::Free
+        ));
+        let stake_before = SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey);
+        SubtensorModule::emit_inflation_through_hotkey_account(&hotkey, 0, 1_000);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey),
+            stake_before
+        );
+        assert!(SubtensorModule::get_coldkey_balance(&nominator) > 0);
+
+        // Paid to the free balance of another account.
+        assert_ok!(SubtensorModule::set_reward_destination(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey,
+            RewardDestination::Account(payee)
+        ));
+        let balance_before = SubtensorModule::get_coldkey_balance(&nominator);
+        SubtensorModule::emit_inflation_through_hotkey_account(&hotkey, 0, 1_000);
+        assert_eq!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey),
+            stake_before
+        );
+        assert_eq!(
+            SubtensorModule::get_coldkey_balance(&nominator),
+            balance_before
+        );
+        assert!(SubtensorModule::get_coldkey_balance(&payee) > 0);
+
+        // Back to compounding.
+        assert_ok!(SubtensorModule::set_reward_destination(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey,
+            RewardDestination::Compound
+        ));
+        SubtensorModule::emit_inflation_through_hotkey_account(&hotkey, 0, 1_000);
+        assert!(
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey) > stake_before
+        );
+    });
+}
+
+#[test]
+fn test_reward_destination_below_existential_deposit_compounds() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let owner = U256::from(2);
+        let nominator = U256::from(3);
+        let payee = U256::from(4);
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, owner, 2341312);
+        assert_ok!(SubtensorModule::become_delegate(
+            <<Test as Config>::RuntimeOrigin>::signed(owner),
+            hotkey
+        ));
+        SubtensorModule::add_balance_to_coldkey_account(&nominator, 10_000);
+        assert_ok!(SubtensorModule::add_stake(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey,
+            10_000
+        ));
+        assert_ok!(SubtensorModule::set_reward_destination(
+            <<Test as Config>::RuntimeOrigin>::signed(nominator),
+            hotkey,
+            RewardDestination::Account(payee)
+        ));
+
+        // A reward that cannot create the payee account is compounded rather than lost.
+        ExistentialDeposit::set(&1_000_000);
+        let total_stake_before = SubtensorModule::get_total_stake_for_coldkey(&nominator);
+        SubtensorModule::emit_inflation_through_hotkey_account(&hotkey, 0, 1_000);
+        let compounded: u64 =
+            SubtensorModule::get_stake_for_coldkey_and_hotkey(&nominator, &hotkey) - 10_000;
+        assert!(compounded > 0);
+        assert_eq!(SubtensorModule::get_coldkey_balance(&payee), 0);
+        assert_eq!(
+            SubtensorModule::get_total_stake_for_coldkey(&nominator),
+            total_stake_before + compounded
+        );
+    });
+}
+
+#[test]
+fn test_delegate_return_estimate() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey = U256::from(1);
+        let coldkey = U256::from(2);
+        add_network(netuid, 9, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 0);
+        SubtensorModule::delegate_hotkey(&hotkey, u16::MAX / 10);
+        SubtensorModule::increase_stake_on_coldkey_hotkey_account(&coldkey, &hotkey, 1_000_000);
+
+        // Without any emission nothing is projected.
+        assert_eq!(SubtensorModule::get_delegate_daily_returns(&hotkey), (0, 0));
+
+        // Two retained epochs emitting 1000 and 3000, half of it as validator emission.
+        SubtensorModule::set_epoch_snapshot_retention(netuid, 2);
+        for (slot, emission) in [1_000, 3_000].into_iter().enumerate() {
+            EpochSnapshots::<Test>::insert(
+                netuid,
+                slot as u16,
+                EpochSnapshot {
+                    block: slot as u64,
+                    hotkeys: vec![Some(hotkey)],
+                    stake: vec![1_000_000],
+                    rank: vec![0],
+                    trust: vec![0],
+                    consensus: vec![0],
+                    validator_trust: vec![0],
+                    incentive: vec![u16::MAX / 2],
+                    dividends: vec![u16::MAX / 2],
+                    emission: vec![emission],
+                    validator_permit: vec![true],
+                },
+            );
+        }
+        EpochSnapshotsTaken::<Test>::insert(netuid, 2);
+
+        // 7200 blocks per day at 12 second blocks and an epoch every tempo + 1 = 10 blocks.
+        let (daily_emission, return_per_1000) =
+            SubtensorModule::get_delegate_daily_returns(&hotkey);
+        assert_eq!(daily_emission, 2_000 * 720);
+        // The validator emission minus the take, per 1000 of the 1_000_000 staked.
+        let daily_validator_emission: u64 = 1_000 * 720;
+        let take: u64 = SubtensorModule::calculate_delegate_proportional_take(
+            &hotkey,
+            daily_validator_emission,
+        );
+        assert_eq!(
+            return_per_1000,
+            (daily_validator_emission - take) * 1000 / 1_000_000
+        );
+
+        // At tempo 360 a day holds 7200 / 361 epochs, which is not rounded down to 19.
+        SubtensorModule::set_tempo(netuid, 360);
+        let (daily_emission, return_per_1000) =
+            SubtensorModule::get_delegate_daily_returns(&hotkey);
+        assert_eq!(daily_emission, 2_000 * 7_200 / 361);
+        let daily_validator_emission: u64 = 1_000 * 7_200 / 361;
+        let take: u64 = SubtensorModule::calculate_delegate_proportional_take(
+            &hotkey,
+            daily_validator_emission,
+        );
+        assert_eq!(
+            return_per_1000,
+            (daily_validator_emission - take) * 1000 / 1_000_000
+        );
+    });
+}
@@ -1,4 +1,5 @@
 mod mock;
+use codec::Encode;
 use frame_support::{
     assert_err, assert_ok,
     dispatch::{DispatchClass, DispatchInfo, DispatchResult, GetDispatchInfo, Pays},
@@ -6,13 +7,15 @@ use frame_support::{
 };
 use mock::*;
 use pallet_subtensor::{Error, Owner};
-use sp_core::{H256, U256};
+use sp_core::{Get, H256, U256};
 use sp_runtime::{
     traits::{BlakeTwo256, DispatchInfoOf, Hash, SignedExtension},
     DispatchError,
 };
 use substrate_fixed::types::I32F32;
 
+type TimelockKeys = pallet_subtensor::timelock::DeterministicTimelock<Test>;
+
 /***************************
   pub fn set_weights() tests
 *****************************/
@@ -1461,7 +1464,7 @@ fn test_commit_reveal_interval() {
             version_key,
         ));
 
-        // Testing that if you miss the next tempo you cannot reveal it.
+        // Testing that if you miss the next tempo the commit expires and you cannot reveal it.
         assert_ok!(SubtensorModule::commit_weights(
             RuntimeOrigin::signed(hotkey),
             netuid,
@@ -1477,7 +1480,7 @@ fn test_commit_reveal_interval() {
                 salt.clone(),
                 version_key,
             ),
-            Error::<Test>::InvalidRevealCommitTempo
+            Error::<Test>::NoWeightsCommitFound
         );
 
         // Testing when you commit but do not reveal until later intervals
@@ -1839,6 +1842,533 @@ fn test_commit_reveal_bad_salt_fail() {
     });
 }
 
+#[test]
+fn test_commit_reveal_multiple_pending_commits() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let uids: Vec<u16> = vec![0, 1];
+        let weight_values: Vec<u16> = vec![10, 10];
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        let hotkey: U256 = U256::from(1);
+        let commit_hash = |version_key: u64| -> H256 {
+            BlakeTwo256::hash_of(&(
+                hotkey,
+                netuid,
+                uids.clone(),
+                weight_values.clone(),
+                salt.clone(),
+                version_key,
+            ))
+        };
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 100000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 0, true);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        // Two different commits can be pending in the same interval.
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash(0)
+        ));
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash(1)
+        ));
+        assert_eq!(
+            pallet_subtensor::WeightCommits::<Test>::get(netuid, hotkey).map(|c| c.len()),
+            Some(2)
+        );
+
+        // The pending commits are listed with their reveal windows.
+        let pending = SubtensorModule::get_pending_weight_commits(netuid, hotkey.encode());
+        assert_eq!(pending.len(), 2);
+        assert_eq!(SubtensorModule::get_reveal_block_range(netuid, 1), (5, 10));
+
+        step_block(5);
+
+        // Commits are revealed by hash, in any order.
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids.clone(),
+            weight_values.clone(),
+            salt.clone(),
+            1,
+        ));
+        assert_ok!(SubtensorModule::reveal_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            uids.clone(),
+            weight_values.clone(),
+            salt.clone(),
+            0,
+        ));
+        assert_eq!(
+            pallet_subtensor::WeightCommits::<Test>::get(netuid, hotkey),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_commit_weights_queue_full() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 100000);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        let max_pending: u32 = <Test as pallet_subtensor::Config>::MaxPendingWeightCommits::get();
+        for i in 0..max_pending {
+            assert_ok!(SubtensorModule::commit_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                H256::from_low_u64_be(i as u64)
+            ));
+        }
+        assert_err!(
+            SubtensorModule::commit_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                H256::from_low_u64_be(max_pending as u64)
+            ),
+            Error::<Test>::TooManyPendingWeightCommits
+        );
+    });
+}
+
+#[test]
+fn test_commit_weights_expire() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        let commit_hash: H256 = H256::from_low_u64_be(1);
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, U256::from(1), U256::from(2), 100000);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        // Committed at block 1, the reveal window is [5, 10) and the expiry is checked at block 11.
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            commit_hash
+        ));
+        assert_eq!(
+            pallet_subtensor::WeightCommitExpiries::<Test>::get(11),
+            vec![(netuid, hotkey, 1)]
+        );
+
+        step_block(9);
+        assert!(pallet_subtensor::WeightCommits::<Test>::contains_key(
+            netuid, hotkey
+        ));
+
+        step_block(1);
+        assert!(!pallet_subtensor::WeightCommits::<Test>::contains_key(
+            netuid, hotkey
+        ));
+        assert!(!pallet_subtensor::WeightCommitExpiries::<Test>::contains_key(11));
+        System::assert_has_event(
+            pallet_subtensor::Event::CommitExpired(netuid, hotkey, commit_hash).into(),
+        );
+    });
+}
+
+#[test]
+fn test_commit_weights_expiry_is_bounded_to_the_block() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            H256::from_low_u64_be(1)
+        ));
+
+        // Nothing is queued for this block, only the queue itself is read.
+        let db_weight = <Test as frame_system::Config>::DbWeight::get();
+        assert_eq!(
+            SubtensorModule::expire_weight_commits(10),
+            db_weight.reads_writes(1, 1)
+        );
+        assert_eq!(
+            SubtensorModule::expire_weight_commits(11),
+            db_weight.reads_writes(1, 1) + db_weight.reads_writes(2, 1)
+        );
+        assert!(!pallet_subtensor::WeightCommits::<Test>::contains_key(
+            netuid, hotkey
+        ));
+    });
+}
+
+#[test]
+fn test_commit_weights_drops_expired_commits_of_the_hotkey() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        let max_pending: u32 = <Test as pallet_subtensor::Config>::MaxPendingWeightCommits::get();
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+        for i in 0..max_pending {
+            assert_ok!(SubtensorModule::commit_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                H256::from_low_u64_be(i as u64)
+            ));
+        }
+
+        // Past the reveal window the full queue makes room for a new commit.
+        System::set_block_number(10);
+        assert_ok!(SubtensorModule::commit_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            H256::from_low_u64_be(max_pending as u64)
+        ));
+        assert_eq!(
+            pallet_subtensor::WeightCommits::<Test>::get(netuid, hotkey),
+            Some(vec![(H256::from_low_u64_be(max_pending as u64), 10)])
+        );
+    });
+}
+
+#[test]
+fn test_commit_timelocked_weights_applied() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        let round: u64 = 3;
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, U256::from(3), U256::from(4), 300000);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        SubtensorModule::set_weights_set_rate_limit(netuid, 0);
+        SubtensorModule::set_validator_permit_for_uid(netuid, 1, true);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        let payload: Vec<u8> = (vec![0u16, 1u16], vec![10u16, 10u16], 0u64).encode();
+        let ciphertext: Vec<u8> = TimelockKeys::encrypt(round, &payload);
+        assert_ne!(ciphertext, payload);
+
+        assert_ok!(SubtensorModule::commit_timelocked_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            ciphertext.try_into().unwrap(),
+            round
+        ));
+        assert_eq!(
+            pallet_subtensor::PendingTimelockedWeightCommits::<Test>::get(netuid, hotkey),
+            vec![round]
+        );
+
+        // The key of the round is not published before block 3.
+        step_block(1);
+        assert!(SubtensorModule::get_weights(netuid)[1]
+            .iter()
+            .all(|weight| *weight == I32F32::from_num(0)));
+
+        step_block(1);
+        assert!(SubtensorModule::get_weights(netuid)[1]
+            .iter()
+            .all(|weight| *weight > I32F32::from_num(0)));
+        assert!(
+            !pallet_subtensor::PendingTimelockedWeightCommits::<Test>::contains_key(netuid, hotkey)
+        );
+        System::assert_has_event(
+            pallet_subtensor::Event::TimelockedWeightsApplied(netuid, hotkey, round).into(),
+        );
+    });
+}
+
+#[test]
+fn test_commit_timelocked_weights_invalid_round() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+
+        assert_err!(
+            SubtensorModule::commit_timelocked_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![].try_into().unwrap(),
+                3
+            ),
+            Error::<Test>::CommitRevealDisabled
+        );
+
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        // Committed at block 1, the reveal window is [5, 10).
+        for round in [0, 1, 10] {
+            assert_err!(
+                SubtensorModule::commit_timelocked_weights(
+                    RuntimeOrigin::signed(hotkey),
+                    netuid,
+                    vec![].try_into().unwrap(),
+                    round
+                ),
+                Error::<Test>::InvalidTimelockRound
+            );
+        }
+        assert_ok!(SubtensorModule::commit_timelocked_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![].try_into().unwrap(),
+            9
+        ));
+    });
+}
+
+#[test]
+fn test_commit_timelocked_weights_queue_full() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        let max_pending: u32 = <Test as pallet_subtensor::Config>::MaxPendingWeightCommits::get();
+        for _ in 0..max_pending {
+            assert_ok!(SubtensorModule::commit_timelocked_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![].try_into().unwrap(),
+                2
+            ));
+        }
+        assert_err!(
+            SubtensorModule::commit_timelocked_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![].try_into().unwrap(),
+                2
+            ),
+            Error::<Test>::TooManyPendingWeightCommits
+        );
+
+        // Every commit is consumed at its round, which frees the queue.
+        step_block(1);
+        assert!(
+            !pallet_subtensor::PendingTimelockedWeightCommits::<Test>::contains_key(netuid, hotkey)
+        );
+        assert_ok!(SubtensorModule::commit_timelocked_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![].try_into().unwrap(),
+            3
+        ));
+    });
+}
+
+#[test]
+fn test_commit_timelocked_weights_undecodable_payload() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        let round: u64 = 2;
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        assert_ok!(SubtensorModule::commit_timelocked_weights(
+            RuntimeOrigin::signed(hotkey),
+            netuid,
+            vec![0u8; 3].try_into().unwrap(),
+            round
+        ));
+
+        step_block(1);
+        assert!(SubtensorModule::get_weights(netuid)[0]
+            .iter()
+            .all(|weight| *weight == I32F32::from_num(0)));
+        System::assert_has_event(
+            pallet_subtensor::Event::TimelockedWeightsFailed(
+                netuid,
+                hotkey,
+                round,
+                Error::<Test>::TimelockDecryptionFailed.into(),
+            )
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn test_commit_timelocked_weights_requires_registration() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+
+        add_network(netuid, 0, 0);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        assert_err!(
+            SubtensorModule::commit_timelocked_weights(
+                RuntimeOrigin::signed(hotkey),
+                netuid,
+                vec![].try_into().unwrap(),
+                3
+            ),
+            Error::<Test>::HotKeyNotRegisteredInSubNet
+        );
+        assert!(
+            !pallet_subtensor::PendingTimelockedWeightCommits::<Test>::contains_key(netuid, hotkey)
+        );
+    });
+}
+
+#[test]
+fn test_commit_timelocked_weights_expire_with_the_commit_window() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkey: U256 = U256::from(1);
+        let round: u64 = 12;
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, U256::from(2), 100000);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        // Committed at block 1 the reveal window ends at block 10, before the round key.
+        pallet_subtensor::TimelockedWeightCommits::<Test>::insert(
+            9,
+            vec![(netuid, hotkey, round, 1, vec![])],
+        );
+        pallet_subtensor::PendingTimelockedWeightCommits::<Test>::insert(netuid, hotkey, vec![9]);
+
+        step_block(8);
+        assert!(pallet_subtensor::TimelockedWeightCommits::<Test>::iter()
+            .next()
+            .is_none());
+        assert!(
+            !pallet_subtensor::PendingTimelockedWeightCommits::<Test>::contains_key(netuid, hotkey)
+        );
+        System::assert_has_event(
+            pallet_subtensor::Event::TimelockedWeightsExpired(netuid, hotkey, round).into(),
+        );
+    });
+}
+
+#[test]
+fn test_timelocked_weight_commits_are_bounded_per_block() {
+    new_test_ext(1).execute_with(|| {
+        let netuid: u16 = 1;
+        let hotkeys: [U256; 2] = [U256::from(1), U256::from(3)];
+        let round: u64 = 2;
+
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkeys[0], U256::from(2), 100000);
+        register_ok_neuron(netuid, hotkeys[1], U256::from(4), 300000);
+        SubtensorModule::set_commit_reveal_weights_interval(netuid, 5);
+        SubtensorModule::set_commit_reveal_weights_enabled(netuid, true);
+
+        let max_pending: u32 = <Test as pallet_subtensor::Config>::MaxPendingWeightCommits::get();
+        for hotkey in hotkeys {
+            for _ in 0..max_pending {
+                assert_ok!(SubtensorModule::commit_timelocked_weights(
+                    RuntimeOrigin::signed(hotkey),
+                    netuid,
+                    vec![].try_into().unwrap(),
+                    round
+                ));
+            }
+        }
+
+        // Only the first commits up to the bound are applied, the rest move to the next block.
+        let max_per_block: u32 =
+            <Test as pallet_subtensor::Config>::MaxTimelockedCommitsPerBlock::get();
+        let carried: usize = (2 * max_pending - max_per_block) as usize;
+        step_block(1);
+        assert!(!pallet_subtensor::TimelockedWeightCommits::<Test>::contains_key(round));
+        assert_eq!(
+            pallet_subtensor::TimelockedWeightCommits::<Test>::get(round + 1).len(),
+            carried
+        );
+        assert!(
+            !pallet_subtensor::PendingTimelockedWeightCommits::<Test>::contains_key(
+                netuid, hotkeys[0]
+            )
+        );
+        assert_eq!(
+            pallet_subtensor::PendingTimelockedWeightCommits::<Test>::get(netuid, hotkeys[1]),
+            vec![round + 1; carried]
+        );
+
+        step_block(1);
+        assert!(pallet_subtensor::TimelockedWeightCommits::<Test>::iter()
+            .next()
+            .is_none());
+        assert!(
+            !pallet_subtensor::PendingTimelockedWeightCommits::<Test>::contains_key(
+                netuid, hotkeys[1]
+            )
+        );
+    });
+}
+
+// Test ensures that deregistering a neuron clears its weights and bonds rows.
+#[test]
+fn test_deregister_clears_weights() {
+    new_test_ext(0).execute_with(|| {
+        let hotkey = U256::from(55);
+        let coldkey = U256::from(66);
+        let netuid: u16 = 1;
+        let salt: Vec<u16> = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        add_network(netuid, 0, 0);
+        register_ok_neuron(netuid, hotkey, coldkey, 2143124);
+        assert_ok!(commit_reveal_set_weights(
+            hotkey,
+            netuid,
+            vec![0],
+            vec![1],
+            salt,
+            0
+        ));
+        assert!(SubtensorModule::get_weights(netuid)[0][0] > I32F32::from_num(0));
+
+        assert_ok!(SubtensorModule::deregister(
+            RuntimeOrigin::signed(hotkey),
+            netuid
+        ));
+        assert!(SubtensorModule::get_weights(netuid)[0]
+            .iter()
+            .all(|weight| *weight == I32F32::from_num(0)));
+        assert!(SubtensorModule::get_bonds(netuid)[0]
+            .iter()
+            .all(|bond| *bond == I32F32::from_num(0)));
+    });
+}
+
 fn commit_reveal_set_weights(
     hotkey: U256,
     netuid: u16,
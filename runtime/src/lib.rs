@@ -135,10 +135,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 152,
+    spec_version: 153,
     impl_version: 1,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     state_version: 1,
 };
 
@@ -566,12 +566,26 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
     fn filter(&self, c: &RuntimeCall) -> bool {
         match self {
             ProxyType::Any => true,
-            ProxyType::NonTransfer => !matches!(c, RuntimeCall::Balances(..)),
+            ProxyType::NonTransfer => !matches!(
+                c,
+                RuntimeCall::Balances(..)
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::schedule_coldkey_swap { .. }
+                    )
+            ),
             ProxyType::NonFungibile => !matches!(
                 c,
                 RuntimeCall::Balances(..)
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::remove_stake { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::withdraw_unbonded { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::move_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::rebalance_stake { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::schedule_coldkey_swap { .. }
+                    )
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::burned_register { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::root_register { .. })
             ),
@@ -598,6 +612,11 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
                 c,
                 RuntimeCall::SubtensorModule(pallet_subtensor::Call::add_stake { .. })
                     | RuntimeCall::SubtensorModule(pallet_subtensor::Call::remove_stake { .. })
+                    | RuntimeCall::SubtensorModule(
+                        pallet_subtensor::Call::withdraw_unbonded { .. }
+                    )
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::move_stake { .. })
+                    | RuntimeCall::SubtensorModule(pallet_subtensor::Call::rebalance_stake { .. })
             ),
             ProxyType::Registration => matches!(
                 c,
@@ -761,6 +780,21 @@ impl CanCommit<AccountId> for AllowCommitments {
     }
 }
 
+pub struct CommitmentsHotkeySwap;
+impl pallet_subtensor::OnHotkeySwapped<AccountId> for CommitmentsHotkeySwap {
+    fn on_hotkey_swapped(
+        old_hotkey: &AccountId,
+        new_hotkey: &AccountId,
+        netuids: &[u16],
+    ) -> Weight {
+        netuids.iter().fold(Weight::zero(), |weight, netuid| {
+            weight.saturating_add(Commitments::swap_commitment(
+                *netuid, old_hotkey, new_hotkey,
+            ))
+        })
+    }
+}
+
 impl pallet_commitments::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -817,6 +851,18 @@ parameter_types! {
     pub const SubtensorInitialNetworkLockReductionInterval: u64 = 14 * 7200;
     pub const SubtensorInitialNetworkRateLimit: u64 = 7200;
     pub const SubtensorInitialTargetStakesPerInterval: u16 = 1;
+    pub const SubtensorMaxUnbondingChunks: u32 = 32;
+    pub const SubtensorMaxRebalanceTargets: u32 = 64;
+    pub const SubtensorMaxPendingWeightCommits: u32 = 10;
+    // Room for the encrypted weights of a full subnet of 4096 uids.
+    pub const SubtensorMaxTimelockedCiphertextLength: u32 = 16_448;
+    pub const SubtensorMaxTimelockedCommitsPerBlock: u32 = 8;
+    pub const SubtensorColdkeySwapAnnouncementDelay: u64 = 5 * 7200; // 5 days
+    pub const SubtensorMaxEmissionLedgerEntries: u32 = 1024;
+    pub const SubtensorMaxEmissionSplitRecipients: u32 = 8;
+    pub const SubtensorMaxEpochSnapshotRetention: u16 = 32;
+    pub const SubtensorExpectedBlockTime: u64 = MILLISECS_PER_BLOCK;
+    pub const SubtensorMaxServeAxonNetuids: u32 = 64;
 }
 
 impl pallet_subtensor::Config for Runtime {
@@ -826,6 +872,9 @@ impl pallet_subtensor::Config for Runtime {
     type CouncilOrigin = EnsureMajoritySenate;
     type SenateMembers = ManageSenateMembers;
     type TriumvirateInterface = TriumvirateVotes;
+    type OnHotkeySwapped = CommitmentsHotkeySwap;
+    // No round keys are published on this chain yet, so timelocked commits are refused.
+    type TimelockKeySource = ();
 
     type InitialRho = SubtensorInitialRho;
     type InitialKappa = SubtensorInitialKappa;
@@ -868,6 +917,18 @@ impl pallet_subtensor::Config for Runtime {
     type InitialSubnetLimit = SubtensorInitialSubnetLimit;
     type InitialNetworkRateLimit = SubtensorInitialNetworkRateLimit;
     type InitialTargetStakesPerInterval = SubtensorInitialTargetStakesPerInterval;
+    type MaxUnbondingChunks = SubtensorMaxUnbondingChunks;
+    type MaxRebalanceTargets = SubtensorMaxRebalanceTargets;
+    type WeightInfo = pallet_subtensor::weight_info::SubstrateWeight<Runtime>;
+    type MaxPendingWeightCommits = SubtensorMaxPendingWeightCommits;
+    type MaxTimelockedCiphertextLength = SubtensorMaxTimelockedCiphertextLength;
+    type MaxTimelockedCommitsPerBlock = SubtensorMaxTimelockedCommitsPerBlock;
+    type ColdkeySwapAnnouncementDelay = SubtensorColdkeySwapAnnouncementDelay;
+    type MaxEmissionLedgerEntries = SubtensorMaxEmissionLedgerEntries;
+    type MaxEmissionSplitRecipients = SubtensorMaxEmissionSplitRecipients;
+    type MaxEpochSnapshotRetention = SubtensorMaxEpochSnapshotRetention;
+    type ExpectedBlockTime = SubtensorExpectedBlockTime;
+    type MaxServeAxonNetuids = SubtensorMaxServeAxonNetuids;
 }
 
 use sp_runtime::BoundedVec;
@@ -1142,6 +1203,58 @@ impl
     fn set_commit_reveal_weights_enabled(netuid: u16, enabled: bool) {
         SubtensorModule::set_commit_reveal_weights_enabled(netuid, enabled);
     }
+
+    fn set_unbonding_period(netuid: u16, unbonding_period: u64) {
+        SubtensorModule::set_unbonding_period(netuid, unbonding_period);
+    }
+
+    fn set_dense_epoch_threshold(threshold: u16) {
+        SubtensorModule::set_dense_epoch_threshold(threshold);
+    }
+
+    fn set_liquid_alpha_enabled(netuid: u16, enabled: bool) {
+        SubtensorModule::set_liquid_alpha_enabled(netuid, enabled);
+    }
+
+    fn set_alpha_values(netuid: u16, alpha_low: u16, alpha_high: u16) {
+        SubtensorModule::set_alpha_values(netuid, alpha_low, alpha_high);
+    }
+
+    fn get_alpha_values_bounds() -> (u16, u16) {
+        SubtensorModule::get_alpha_values_bounds()
+    }
+
+    fn set_alpha_values_bounds(min_alpha_low: u16, max_alpha_high: u16) {
+        SubtensorModule::set_alpha_values_bounds(min_alpha_low, max_alpha_high);
+    }
+
+    fn set_alpha_sigmoid_steepness(netuid: u16, steepness: u16) {
+        SubtensorModule::set_alpha_sigmoid_steepness(netuid, steepness);
+    }
+
+    fn set_max_emission_split_share(max_share: u16) {
+        SubtensorModule::set_max_emission_split_share(max_share);
+    }
+
+    fn set_network_dissolve_period(dissolve_period: u64) {
+        SubtensorModule::set_network_dissolve_period(dissolve_period);
+    }
+
+    fn get_max_epoch_snapshot_retention() -> u16 {
+        SubtensorModule::get_max_epoch_snapshot_retention()
+    }
+
+    fn set_epoch_snapshot_retention(netuid: u16, retention: u16) {
+        SubtensorModule::set_epoch_snapshot_retention(netuid, retention);
+    }
+
+    fn set_allow_private_ips(netuid: u16, allow_private_ips: bool) {
+        SubtensorModule::set_allow_private_ips(netuid, allow_private_ips);
+    }
+
+    fn set_deregistration_refund_ratio(netuid: u16, ratio: u16) {
+        SubtensorModule::set_deregistration_refund_ratio(netuid, ratio);
+    }
 }
 
 impl pallet_admin_utils::Config for Runtime {
@@ -1503,6 +1616,18 @@ impl_runtime_apis! {
         }
     }
 
+    impl subtensor_custom_rpc_runtime_api::DelegateReturnRuntimeApi<Block> for Runtime {
+        fn get_delegate_return_estimate(delegate_account_vec: Vec<u8>, epochs: u16) -> Vec<u8> {
+            let _result = SubtensorModule::get_delegate_return_estimate(delegate_account_vec, epochs);
+            if _result.is_some() {
+                let result = _result.expect("Could not get DelegateReturnEstimate");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+    }
+
     impl subtensor_custom_rpc_runtime_api::NeuronInfoRuntimeApi<Block> for Runtime {
         fn get_neurons_lite(netuid: u16) -> Vec<u8> {
             let result = SubtensorModule::get_neurons_lite(netuid);
@@ -1533,6 +1658,46 @@ impl_runtime_apis! {
                 vec![]
             }
         }
+
+        fn get_neurons_paged(netuid: u16, start_uid: u16, limit: u16, skip_fields: u8, filters: u8) -> Vec<u8> {
+            let result = SubtensorModule::get_neurons_paged(netuid, start_uid, limit, skip_fields, filters);
+            result.encode()
+        }
+
+        fn get_neurons_lite_paged(netuid: u16, start_uid: u16, limit: u16, skip_fields: u8, filters: u8) -> Vec<u8> {
+            let result = SubtensorModule::get_neurons_lite_paged(netuid, start_uid, limit, skip_fields, filters);
+            result.encode()
+        }
+
+        fn get_neurons_v2(netuid: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_neurons_v2(netuid);
+            result.encode()
+        }
+
+        fn get_neuron_v2(netuid: u16, uid: u16) -> Vec<u8> {
+            let _result = SubtensorModule::get_neuron_v2(netuid, uid);
+            if _result.is_some() {
+                let result = _result.expect("Could not get NeuronInfoV2");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
+
+        fn get_neurons_lite_v2(netuid: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_neurons_lite_v2(netuid);
+            result.encode()
+        }
+
+        fn get_neuron_lite_v2(netuid: u16, uid: u16) -> Vec<u8> {
+            let _result = SubtensorModule::get_neuron_lite_v2(netuid, uid);
+            if _result.is_some() {
+                let result = _result.expect("Could not get NeuronInfoLiteV2");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetInfoRuntimeApi<Block> for Runtime {
@@ -1560,6 +1725,16 @@ impl_runtime_apis! {
                 vec![]
             }
         }
+
+        fn get_metagraph(netuid: u16) -> Vec<u8> {
+            let _result = SubtensorModule::get_metagraph(netuid);
+            if _result.is_some() {
+                let result = _result.expect("Could not get Metagraph");
+                result.encode()
+            } else {
+                vec![]
+            }
+        }
         fn epoch(netuid: u16, rao_emission: u64) -> Vec<(Vec<u8>, u64, u64)> {
             SubtensorModule::epoch(netuid, rao_emission)
                 .into_iter()
@@ -1578,6 +1753,42 @@ impl_runtime_apis! {
             let result = SubtensorModule::get_stake_info_for_coldkeys( coldkey_account_vecs );
             result.encode()
         }
+
+        fn get_unbonding_info_for_coldkey( coldkey_account_vec: Vec<u8> ) -> Vec<u8> {
+            let result = SubtensorModule::get_unbonding_info_for_coldkey( coldkey_account_vec );
+            result.encode()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::WeightCommitInfoRuntimeApi<Block> for Runtime {
+        fn get_pending_weight_commits(netuid: u16, hotkey_account_vec: Vec<u8>) -> Vec<u8> {
+            let result = SubtensorModule::get_pending_weight_commits(netuid, hotkey_account_vec);
+            result.encode()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::EpochInfoRuntimeApi<Block> for Runtime {
+        fn get_epoch_dry_run(netuid: u16, rao_emission: u64) -> Vec<u8> {
+            let result = SubtensorModule::get_epoch_dry_run(netuid, rao_emission);
+            result.encode()
+        }
+
+        fn get_root_epoch_info() -> Vec<u8> {
+            let result = SubtensorModule::get_root_epoch_info();
+            result.encode()
+        }
+
+        fn get_metagraph_at_epoch(netuid: u16, epochs_ago: u16) -> Vec<u8> {
+            let result = SubtensorModule::get_metagraph_at_epoch(netuid, epochs_ago);
+            result.encode()
+        }
+    }
+
+    impl subtensor_custom_rpc_runtime_api::EmissionInfoRuntimeApi<Block> for Runtime {
+        fn get_emission_records_for_coldkey( coldkey_account_vec: Vec<u8>, from_block: u64, to_block: u64 ) -> Vec<u8> {
+            let result = SubtensorModule::get_emission_records_for_coldkey( coldkey_account_vec, from_block, to_block );
+            result.encode()
+        }
     }
 
     impl subtensor_custom_rpc_runtime_api::SubnetRegistrationRuntimeApi<Block> for Runtime {